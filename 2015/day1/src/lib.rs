@@ -0,0 +1,37 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<i32> {
+    Ok(input.trim().chars().map(step).sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut floor = 0;
+    for (pos, ins) in input.trim().chars().enumerate() {
+        floor += step(ins);
+        if floor == -1 {
+            return Ok(pos + 1);
+        }
+    }
+    Ok(0)
+}
+
+fn step(ins: char) -> i32 {
+    if ins == '(' { 1 } else { -1 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("(())").unwrap(), 0);
+        assert_eq!(part1("(((").unwrap(), 3);
+        assert_eq!(part1("))(((((").unwrap(), 3);
+        assert_eq!(part1("())").unwrap(), -1);
+        assert_eq!(part1(")())())").unwrap(), -3);
+
+        assert_eq!(part2(")").unwrap(), 1);
+        assert_eq!(part2("()())").unwrap(), 5);
+    }
+}
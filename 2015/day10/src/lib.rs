@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    look_say_length(input, 40)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    look_say_length(input, 50)
+}
+
+fn look_say_length(input: &str, rounds: usize) -> Result<usize> {
+    let mut sequence = input.trim().to_string();
+    if sequence.chars().any(|c| !c.is_ascii_digit()) {
+        return Err(anyhow::anyhow!("Invalid input {sequence}"));
+    }
+
+    for _ in 0..rounds {
+        sequence = look_say(&sequence);
+    }
+    Ok(sequence.len())
+}
+
+fn look_say(sequence: &str) -> String {
+    let mut translated = String::new();
+    let (mut begin, mut bc) = sequence.char_indices().next().unwrap();
+    for (end, ec) in sequence.char_indices() {
+        if bc != ec {
+            let prefix = (end - begin).to_string();
+            translated.push_str(&prefix);
+            translated.push(bc);
+            (begin, bc) = (end, ec);
+        }
+        if end == sequence.len() - 1 {
+            let prefix = (end - begin + 1).to_string();
+            translated.push_str(&prefix);
+            translated.push(bc);
+        }
+    }
+
+    translated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(look_say("1"), "11");
+        assert_eq!(look_say("11"), "21");
+        assert_eq!(look_say("21"), "1211");
+        assert_eq!(look_say("1211"), "111221");
+        assert_eq!(look_say("111221"), "312211");
+        assert_eq!(look_say_length("1", 5).unwrap(), 6);
+    }
+}
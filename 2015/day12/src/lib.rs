@@ -0,0 +1,45 @@
+use anyhow::Result;
+use serde_json::Value;
+
+pub fn part1(input: &str) -> Result<i64> {
+    let value: Value = serde_json::from_str(input)?;
+    Ok(count(&value, false))
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let value: Value = serde_json::from_str(input)?;
+    Ok(count(&value, true))
+}
+
+fn count(value: &Value, filter_red: bool) -> i64 {
+    match value {
+        Value::Number(n) => n.as_i64().unwrap_or(0),
+        Value::Array(a) => a.iter().map(|v| count(v, filter_red)).sum(),
+        Value::Object(o) if !filter_red || !o.values().any(|v| v.as_str() == Some("red")) => {
+            o.iter().map(|(_k, v)| count(v, filter_red)).sum()
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("[1,2,3]").unwrap(), 6);
+        assert_eq!(part1(r#"{"a":2,"b":4}"#).unwrap(), 6);
+        assert_eq!(part1("[[[3]]]").unwrap(), 3);
+        assert_eq!(part1(r#"{"a":{"b":4},"c":-1}"#).unwrap(), 3);
+        assert_eq!(part1(r#"{"a":[-1,1]}"#).unwrap(), 0);
+        assert_eq!(part1(r#"[-1,{"a":1}]"#).unwrap(), 0);
+        assert_eq!(part1("[]").unwrap(), 0);
+        assert_eq!(part1("{}").unwrap(), 0);
+
+        assert_eq!(part2("[1,2,3]").unwrap(), 6);
+        assert_eq!(part2(r#"[1,{"c":"red","b":2},3]"#).unwrap(), 4);
+        assert_eq!(part2(r#"{"d":"red","e":[1,2,3,4],"f":5}"#).unwrap(), 0);
+        assert_eq!(part2(r#"[1,"red",5]"#).unwrap(), 6);
+    }
+}
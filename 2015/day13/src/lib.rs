@@ -0,0 +1,99 @@
+use anyhow::Result;
+use std::cmp::max;
+use std::collections::HashMap;
+use std::ops::Neg;
+
+type Happiness = HashMap<String, HashMap<String, isize>>;
+
+pub fn part1(input: &str) -> Result<isize> {
+    let happiness = parse_input(input)?;
+    let mut seating: Vec<&str> = vec![];
+    Ok(arrange(&mut seating, &happiness))
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let mut happiness = parse_input(input)?;
+    let mut you_map = HashMap::new();
+    for (k, v) in &mut happiness {
+        v.insert("You".to_string(), 0);
+        you_map.insert(k.clone(), 0);
+    }
+    happiness.insert("You".to_string(), you_map);
+    let mut seating: Vec<&str> = vec![];
+    Ok(arrange(&mut seating, &happiness))
+}
+
+pub fn parse_input(input: &str) -> Result<Happiness> {
+    let mut happiness: Happiness = HashMap::new();
+
+    for line in input.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let from = tokens[0].to_string();
+        let mut to = tokens[10].to_string();
+        // Trim trailing period
+        to.pop();
+        let mut level = tokens[3].parse::<isize>()?;
+        if tokens[2] == "lose" {
+            level = level.neg()
+        }
+        happiness
+            .entry(from)
+            .or_default()
+            .entry(to)
+            .or_insert(level);
+    }
+
+    Ok(happiness)
+}
+
+fn arrange<'a>(seating: &mut Vec<&'a str>, happiness: &'a Happiness) -> isize {
+    if seating.len() == happiness.len() {
+        return score(seating, happiness);
+    }
+
+    let mut optimal = 0;
+    for from in happiness.keys() {
+        let from = from.as_str();
+        if !seating.contains(&from) {
+            seating.push(from);
+            optimal = max(optimal, arrange(seating, happiness));
+            seating.pop();
+        }
+    }
+    optimal
+}
+
+fn score(seating: &mut [&str], happiness: &Happiness) -> isize {
+    let score = seating
+        .windows(2)
+        .map(|people| happiness[people[0]][people[1]] + happiness[people[1]][people[0]])
+        .sum::<isize>();
+    let first = *seating.first().unwrap();
+    let last = *seating.last().unwrap();
+    score + happiness[last][first] + happiness[first][last]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 330);
+    }
+}
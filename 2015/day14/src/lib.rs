@@ -0,0 +1,81 @@
+use anyhow::Result;
+use std::cmp::min;
+
+pub const TOTAL_SECONDS: usize = 2503;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, TOTAL_SECONDS)
+}
+
+pub fn part1_with(input: &str, total_seconds: usize) -> Result<usize> {
+    let reindeer = parse_input(input)?;
+    Ok(reindeer
+        .iter()
+        .map(|r| r.distance(total_seconds))
+        .max()
+        .unwrap())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, TOTAL_SECONDS)
+}
+
+pub fn part2_with(input: &str, total_seconds: usize) -> Result<usize> {
+    let mut reindeer = parse_input(input)?;
+    for s in 1..=total_seconds {
+        let lead_dist = reindeer.iter().map(|r| r.distance(s)).max().unwrap();
+        reindeer
+            .iter_mut()
+            .filter(|r| r.distance(s) == lead_dist)
+            .for_each(|r| r.points += 1);
+    }
+    Ok(reindeer.iter().map(|r| r.points).max().unwrap())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Reindeer>> {
+    let mut reindeer = vec![];
+    for line in input.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        reindeer.push(Reindeer {
+            fly_speed: tokens[3].parse()?,
+            fly_duration: tokens[6].parse()?,
+            rest_duration: tokens[13].parse()?,
+            points: 0,
+        })
+    }
+    Ok(reindeer)
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct Reindeer {
+    fly_speed: usize,
+    fly_duration: usize,
+    rest_duration: usize,
+    points: usize,
+}
+
+impl Reindeer {
+    fn distance(&self, total_seconds: usize) -> usize {
+        let cycle_duration = self.fly_duration + self.rest_duration;
+        let full_cycles = total_seconds / cycle_duration;
+        let remaining_time = total_seconds % cycle_duration;
+        self.fly_speed * self.fly_duration * full_cycles
+            + min(remaining_time, self.fly_duration) * self.fly_speed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, 1000).unwrap(), 1120);
+        assert_eq!(part2_with(EXAMPLE, 1000).unwrap(), 689);
+    }
+}
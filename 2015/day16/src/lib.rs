@@ -0,0 +1,70 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let to_match = ticker_tape();
+    find_sue(input, |properties| {
+        properties.iter().all(|(prop, val)| to_match[prop] == *val)
+    })
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let to_match = ticker_tape();
+    find_sue(input, |properties| {
+        properties.iter().all(|(&prop, &val)| match prop {
+            "cats" | "trees" => to_match[prop] < val,
+            "pomeranians" | "goldfish" => to_match[prop] > val,
+            _ => to_match[prop] == properties[prop],
+        })
+    })
+}
+
+fn ticker_tape() -> HashMap<&'static str, usize> {
+    HashMap::from([
+        ("children", 3),
+        ("cats", 7),
+        ("samoyeds", 2),
+        ("pomeranians", 3),
+        ("akitas", 0),
+        ("vizslas", 0),
+        ("goldfish", 5),
+        ("trees", 3),
+        ("cars", 2),
+        ("perfumes", 1),
+    ])
+}
+
+fn find_sue(input: &str, matches: impl Fn(&HashMap<&str, usize>) -> bool) -> Result<usize> {
+    let prop_parser = Regex::new(r#"([a-z]+): ([0-9]+),?"#)?;
+    for (i, line) in input.lines().enumerate() {
+        let mut properties = HashMap::new();
+        for (_, [label, value]) in prop_parser.captures_iter(line).map(|c| c.extract()) {
+            properties.insert(label, value.parse::<usize>()?);
+        }
+
+        if matches(&properties) {
+            return Ok(i + 1);
+        }
+    }
+
+    None.context("No matching Sue")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // There's no worked example, so the Sues here just exercise each comparison
+    const SUES: &str = "\
+Sue 1: cats: 7, trees: 3, goldfish: 5
+Sue 2: children: 3, cats: 8, pomeranians: 2
+Sue 3: akitas: 0, trees: 4, cars: 2
+";
+
+    #[test]
+    fn test_find_sue() {
+        assert_eq!(part1(SUES).unwrap(), 1);
+        assert_eq!(part2(SUES).unwrap(), 2);
+    }
+}
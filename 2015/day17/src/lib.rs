@@ -0,0 +1,62 @@
+use anyhow::{Context, Result};
+
+pub const TO_STORE: usize = 150;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, TO_STORE)
+}
+
+pub fn part1_with(input: &str, to_store: usize) -> Result<usize> {
+    Ok(combinations(input, to_store)?.iter().sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, TO_STORE)
+}
+
+pub fn part2_with(input: &str, to_store: usize) -> Result<usize> {
+    combinations(input, to_store)?
+        .into_iter()
+        .find(|&i| i != 0)
+        .context("No combination of containers fits")
+}
+
+fn combinations(input: &str, to_store: usize) -> Result<Vec<usize>> {
+    let containers: Vec<usize> = input
+        .lines()
+        .map(|line| line.trim().parse())
+        .collect::<Result<_, _>>()?;
+
+    // i == Selection length; combinations[i] -> # of ways to build a selection of that length
+    let mut combinations = vec![0; containers.len() + 1];
+    // mask @ bit i == 1 -> choosing containers[i]
+    for mask in 1..=(1 << containers.len()) {
+        // Calculate what containers are selected with this mask
+        let (num_selected, sum) = containers
+            .iter()
+            .enumerate()
+            .filter(|(i, _c)| mask & (1 << *i) > 0)
+            .fold((0, 0), |(num_selected, sum), (_i, c)| {
+                (num_selected + 1, sum + *c)
+            });
+
+        if sum == to_store {
+            combinations[num_selected] += 1;
+        }
+    }
+
+    Ok(combinations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "20\n15\n10\n5\n5\n";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, 25).unwrap(), 4);
+        assert_eq!(part2_with(EXAMPLE, 25).unwrap(), 3);
+    }
+}
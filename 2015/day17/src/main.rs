@@ -1,41 +1,14 @@
 use anyhow::Result;
-use std::env;
-use std::io::BufRead;
+use aoc_common::Config;
 
 fn main() -> Result<()> {
-    let to_store: usize = env::args().nth(1).unwrap_or("150".to_string()).parse()?;
+    let config = Config::load(2015, 17)?;
+    let to_store = config.get_or("to_store", aoc2015_day17::TO_STORE)?;
 
-    let containers: Vec<usize> = {
-        let mut containers = Vec::new();
-        for line in std::io::stdin().lock().lines() {
-            containers.push(line?.parse()?);
-        }
-        containers
-    };
+    let input = aoc_common::read_input()?;
 
-    // i == Selection length; combinations[i] -> # of ways to build a selection of that length
-    let mut combinations = vec![0; containers.len() + 1];
-    // mask @ bit i == 1 -> choosing containers[i]
-    for mask in 1..=(1 << containers.len()) {
-        // Calculate what containers are selected with this mask
-        let (num_selected, sum) = containers
-            .iter()
-            .enumerate()
-            .filter(|(i, _c)| mask & (1 << *i) > 0)
-            .fold((0, 0), |(num_selected, sum), (_i, c)| {
-                (num_selected + 1, sum + *c)
-            });
-
-        if sum == to_store {
-            combinations[num_selected] += 1;
-        }
-    }
-
-    println!("Part 1: {}", combinations.iter().sum::<usize>());
-    println!(
-        "Part 2: {}",
-        combinations.iter().find(|&&i| i != 0).unwrap()
-    );
+    aoc_common::print_answer(1, aoc2015_day17::part1_with(&input, to_store)?);
+    aoc_common::print_answer(2, aoc2015_day17::part2_with(&input, to_store)?);
 
     Ok(())
 }
@@ -0,0 +1,60 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut paper = 0;
+    for (l, w, h) in parse_input(input)? {
+        let areas: Vec<_> = sides(l, w, h)
+            .iter()
+            .map(|dims| dims.iter().product::<usize>())
+            .collect();
+        paper += 2 * areas.iter().sum::<usize>() + areas.iter().min().unwrap();
+    }
+    Ok(paper)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut ribbon = 0;
+    for (l, w, h) in parse_input(input)? {
+        let perims: Vec<_> = sides(l, w, h)
+            .iter()
+            .map(|dims| 2 * dims.iter().sum::<usize>())
+            .collect();
+        ribbon += perims.iter().min().unwrap() + l * w * h;
+    }
+    Ok(ribbon)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(usize, usize, usize)>> {
+    input
+        .lines()
+        .map(|line| {
+            let dimensions: Vec<_> = line
+                .split('x')
+                .map(|d| d.parse::<usize>())
+                .collect::<Result<_, _>>()?;
+            if let &[l, w, h] = dimensions.as_slice() {
+                Ok((l, w, h))
+            } else {
+                Err(anyhow!("Need 3 dimensions but given {}", dimensions.len()))
+            }
+        })
+        .collect()
+}
+
+fn sides(l: usize, w: usize, h: usize) -> [[usize; 2]; 3] {
+    [[l, w], [w, h], [h, l]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("2x3x4").unwrap(), 58);
+        assert_eq!(part1("1x1x10").unwrap(), 43);
+
+        assert_eq!(part2("2x3x4").unwrap(), 34);
+        assert_eq!(part2("1x1x10").unwrap(), 14);
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use itertools::Itertools;
+
+pub fn part1(input: &str) -> Result<usize> {
+    first_qe(&parse_input(input)?, 3)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    first_qe(&parse_input(input)?, 4)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    Ok(input
+        .lines()
+        .map(|line| line.trim().parse())
+        .collect::<Result<_, _>>()?)
+}
+
+//  I've yet to find a general solution for this. The hints and solutions
+//  I've seen so far rely on the input being overly...nice. Probably because
+//  this is an NP-hard problem in general.
+//
+//  The "nice" property being any minimally sized group 1 leaves the
+//  remaining packages dividable into 2 or 3 other groups each of the
+//  desired size. And the first group you find has the minimum QE. How convenient!
+fn first_qe(packages: &[usize], groups: usize) -> Result<usize> {
+    let target = packages.iter().sum::<usize>() / groups;
+    for i in 0..packages.len() {
+        for comb in packages.iter().combinations(i) {
+            if comb.iter().copied().sum::<usize>() == target {
+                return Ok(comb.iter().copied().product::<usize>());
+            }
+        }
+    }
+    Err(anyhow::Error::msg(format!(
+        "No combination found for {} groups",
+        groups
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1\n2\n3\n4\n5\n7\n8\n9\n10\n11\n";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 99);
+        assert_eq!(part2(EXAMPLE).unwrap(), 44);
+    }
+}
@@ -0,0 +1,49 @@
+use regex::Regex;
+
+pub fn part1(input: &str) -> anyhow::Result<usize> {
+    let target = parse_input(input)?;
+    let mut rc = (1, 1);
+    // Save max row so we know where to wrap to
+    let mut rmax = 1;
+    let mut code: usize = 20151125;
+    loop {
+        if rc == target {
+            return Ok(code);
+        }
+        rc.0 -= 1;
+        rc.1 += 1;
+        // Wrap around
+        if rc.0 == 0 {
+            rmax += 1;
+            rc = (rmax, 1);
+        }
+        code = (code * 252533) % 33554393;
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(usize, usize), anyhow::Error> {
+    let regex = Regex::new(r#"(\d+)\D+(\d+)"#)?;
+    let line = input
+        .lines()
+        .next()
+        .ok_or(anyhow::anyhow!("No input line"))?;
+    let caps = regex
+        .captures(line)
+        .ok_or(anyhow::anyhow!("Invalid input"))?;
+    let row = caps[1].parse::<usize>()?;
+    let col = caps[2].parse::<usize>()?;
+    Ok((row, col))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("row 1, column 1").unwrap(), 20151125);
+        assert_eq!(part1("row 4, column 2").unwrap(), 32451966);
+        assert_eq!(part1("row 1, column 6").unwrap(), 33511524);
+        assert_eq!(part1("row 6, column 6").unwrap(), 27995004);
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+
+type Coords = (isize, isize);
+
+pub fn part1(input: &str) -> Result<usize> {
+    houses_delivered(input, false)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    houses_delivered(input, true)
+}
+
+fn houses_delivered(instructions: &str, split: bool) -> Result<usize> {
+    let mut santa: Coords = (0, 0);
+    let mut robot: Coords = (0, 0);
+
+    let mut santa_deliver = true;
+
+    let mut houses = HashSet::new();
+    houses.insert(santa);
+
+    for ins in instructions.trim().chars() {
+        let to_move = if !split || santa_deliver {
+            &mut santa
+        } else {
+            &mut robot
+        };
+        match ins {
+            '^' => to_move.0 -= 1,
+            'v' => to_move.0 += 1,
+            '>' => to_move.1 += 1,
+            '<' => to_move.1 -= 1,
+            _ => return Err(anyhow!(format!("Invalid input char {ins}"))),
+        }
+        houses.insert(santa);
+        houses.insert(robot);
+        santa_deliver = !santa_deliver;
+    }
+
+    Ok(houses.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(">").unwrap(), 2);
+        assert_eq!(part1("^>v<").unwrap(), 4);
+        assert_eq!(part1("^v^v^v^v^v").unwrap(), 2);
+
+        assert_eq!(part2("^v").unwrap(), 3);
+        assert_eq!(part2("^>v<").unwrap(), 3);
+        assert_eq!(part2("^v^v^v^v^v").unwrap(), 11);
+    }
+}
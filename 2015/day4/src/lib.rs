@@ -0,0 +1,33 @@
+use anyhow::{Context, Result};
+
+// In the samples given, we appear to need at least n+1 digits where n is # of 0s in prefix.
+pub fn part1(input: &str) -> Result<usize> {
+    find_secret(input.trim(), 5).context("Part 1 not found")
+}
+
+// This is a bit slow...
+pub fn part2(input: &str) -> Result<usize> {
+    find_secret(input.trim(), 6).context("Part 2 not found")
+}
+
+fn find_secret(input: &str, prefix_size: usize) -> Option<usize> {
+    let prefix = "0".repeat(prefix_size);
+    let start = 1 << prefix_size;
+    (start..).into_iter().find(|i| {
+        let key = format!("{input}{i}");
+        let digest = md5::compute(key.as_bytes());
+        let hex = format!("{:x}", &digest);
+        hex.starts_with(&prefix)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(find_secret("abcdef", 5), Some(609043));
+        assert_eq!(find_secret("pqrstuv", 5), Some(1048970));
+    }
+}
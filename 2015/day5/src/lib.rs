@@ -0,0 +1,119 @@
+use anyhow::Result;
+
+static VOWELS: &[char] = &['a', 'e', 'i', 'o', 'u'];
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_nice(input, Part1::default()))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(count_nice(input, Part2::default()))
+}
+
+fn count_nice(input: &str, mut filter: impl NiceFilter) -> usize {
+    input.lines().for_each(|line| filter.filter(line));
+    filter.nice_count()
+}
+
+trait NiceFilter {
+    fn filter(&mut self, string: &str);
+    fn nice_count(&self) -> usize;
+}
+
+#[derive(Default)]
+struct Part1 {
+    nice_count: usize,
+}
+
+impl NiceFilter for Part1 {
+    fn filter(&mut self, string: &str) {
+        if string.chars().count() < 2 {
+            return;
+        }
+
+        let mut vowel_count = 0;
+        let mut double_letter = false;
+
+        let mut prev = string.chars().next().unwrap();
+        if VOWELS.contains(&prev) {
+            vowel_count += 1;
+        }
+        for c in string.chars().skip(1) {
+            match (prev, c) {
+                ('a', 'b') | ('c', 'd') | ('p', 'q') | ('x', 'y') => return,
+                (p, n) if p == n => double_letter = true,
+                _ => {}
+            }
+
+            if VOWELS.contains(&c) {
+                vowel_count += 1;
+            }
+
+            prev = c;
+        }
+
+        if vowel_count >= 3 && double_letter {
+            self.nice_count += 1;
+        }
+    }
+
+    fn nice_count(&self) -> usize {
+        self.nice_count
+    }
+}
+
+#[derive(Default)]
+struct Part2 {
+    nice_count: usize,
+}
+
+impl NiceFilter for Part2 {
+    fn filter(&mut self, string: &str) {
+        let chars: Vec<_> = string.chars().collect();
+        if chars.len() < 4 {
+            return;
+        }
+
+        let mut has_pair = false;
+        for i in 0..chars.len() - 3 {
+            for j in i + 2..chars.len() - 1 {
+                if chars[i..=i + 1] == chars[j..=j + 1] {
+                    has_pair = true;
+                    break;
+                }
+            }
+        }
+
+        let is_nice = has_pair
+            && chars
+                .windows(3)
+                .find(|chunk| chunk[0] == chunk[2])
+                .is_some();
+        if is_nice {
+            self.nice_count += 1;
+        }
+    }
+
+    fn nice_count(&self) -> usize {
+        self.nice_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("ugknbfddgicrmopn").unwrap(), 1);
+        assert_eq!(part1("aaa").unwrap(), 1);
+        assert_eq!(part1("jchzalrnumimnmhp").unwrap(), 0);
+        assert_eq!(part1("haegwjzuvuyypxyu").unwrap(), 0);
+        assert_eq!(part1("dvszwmarrgswjxmb").unwrap(), 0);
+
+        assert_eq!(part2("qjhvhtzxzqqjkmpb").unwrap(), 1);
+        assert_eq!(part2("xxyxx").unwrap(), 1);
+        assert_eq!(part2("uurcxstgmygtbstg").unwrap(), 0);
+        assert_eq!(part2("ieodomkazucvgmuy").unwrap(), 0);
+    }
+}
@@ -0,0 +1,220 @@
+use anyhow::{Context, Error, Result};
+use std::collections::HashMap;
+use std::ops::{BitAnd, BitOr};
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<u16> {
+    let mut circuit = Circuit::new(parse_input(input)?);
+    circuit.solve();
+    circuit.get(&"a".to_string()).context("'a' not found")
+}
+
+pub fn part2(input: &str) -> Result<u16> {
+    let a_val = part1(input)?;
+
+    let mut circuit = Circuit::new(parse_input(input)?);
+    circuit.set(&"b".to_string(), a_val);
+    circuit.solve();
+    circuit.get(&"a".to_string()).context("'a' not found")
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Gate>> {
+    let mut to_solve: Vec<Gate> = Vec::new();
+
+    for line in input.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() == 3 {
+            to_solve.push(Gate::new(
+                Operation::Assign(tokens[0].parse()?),
+                tokens[2].to_string(),
+            ));
+        } else if tokens.len() == 4 {
+            to_solve.push(Gate::new(
+                Operation::Not(tokens[1].to_string()),
+                tokens[3].to_string(),
+            ));
+        } else {
+            let operation = match tokens[1] {
+                "OR" => Operation::Or(tokens[0].parse()?, tokens[2].parse()?),
+                "AND" => Operation::And(tokens[0].parse()?, tokens[2].parse()?),
+                "LSHIFT" => Operation::LShift(tokens[0].to_string(), tokens[2].parse()?),
+                "RSHIFT" => Operation::RShift(tokens[0].to_string(), tokens[2].parse()?),
+                _ => return Err(anyhow::anyhow!("Unknown operation {}", &tokens[1])),
+            };
+            to_solve.push(Gate::new(operation, tokens[4].to_string()));
+        }
+    }
+
+    Ok(to_solve)
+}
+
+#[derive(Debug)]
+struct Circuit {
+    gates: Vec<Gate>,
+    known: HashMap<Label, u16>,
+}
+
+impl Circuit {
+    fn new(gates: Vec<Gate>) -> Self {
+        Self {
+            gates,
+            known: HashMap::new(),
+        }
+    }
+
+    fn solve(&mut self) {
+        while !self.gates.iter().all(|g| g.solved) {
+            // Retain only unsolved gates
+            self.gates
+                .iter_mut()
+                .filter(|g| !g.solved)
+                .for_each(|gate| {
+                    if let Some(output) = gate.operation.solve(&self.known) {
+                        self.known.insert(gate.output.clone(), output);
+                        gate.solved = true;
+                    }
+                });
+        }
+    }
+
+    fn get(&self, label: &Label) -> Option<u16> {
+        self.known.get(label).cloned()
+    }
+
+    fn set(&mut self, label: &Label, value: u16) {
+        for gate in &mut self.gates {
+            if gate.output == *label {
+                gate.solved = true;
+                self.known.insert(label.clone(), value);
+                break;
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Gate {
+    operation: Operation,
+    output: Label,
+    solved: bool,
+}
+
+type Label = String;
+
+impl Gate {
+    fn new(operation: Operation, output: Label) -> Self {
+        Self {
+            operation,
+            output,
+            solved: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    Assign(Operand),
+    And(Operand, Operand),
+    Or(Operand, Operand),
+    LShift(Label, u16),
+    RShift(Label, u16),
+    Not(Label),
+}
+
+impl Operation {
+    fn solve(&self, known: &HashMap<Label, u16>) -> Option<u16> {
+        let needed = self.operands();
+        if needed.is_empty() || needed.iter().all(|n| known.contains_key(*n)) {
+            let output = match self {
+                Operation::Assign(op) => op.value(known).unwrap(),
+                Operation::And(op1, op2) => op1.value(known)?.bitand(op2.value(known)?),
+                Operation::Or(op1, op2) => op1.value(known)?.bitor(op2.value(known)?),
+                Operation::LShift(l, ls) => known[l] << ls,
+                Operation::RShift(l, rs) => known[l] >> rs,
+                Operation::Not(l) => !known[l],
+            };
+            return Some(output);
+        }
+        None
+    }
+
+    fn operands(&self) -> Vec<&Label> {
+        match self {
+            Operation::Assign(op) => op.input_labels(),
+            Operation::And(op1, op2) | Operation::Or(op1, op2) => {
+                let mut v = op1.input_labels();
+                v.extend(op2.input_labels());
+                v
+            }
+            Operation::LShift(l, _) | Operation::RShift(l, _) => vec![l],
+            Operation::Not(l) => vec![l],
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operand {
+    Label(Label),
+    Value(u16),
+}
+
+impl Operand {
+    fn input_labels(&self) -> Vec<&Label> {
+        match self {
+            Operand::Label(l) => vec![&l],
+            Operand::Value(_) => vec![],
+        }
+    }
+
+    fn value(&self, known: &HashMap<Label, u16>) -> Option<u16> {
+        match self {
+            Operand::Label(l) => known.get(l).copied(),
+            Operand::Value(v) => Some(*v),
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.parse::<u16>() {
+            Ok(val) => Ok(Self::Value(val)),
+            Err(_) => Ok(Self::Label(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i
+";
+
+    #[test]
+    fn test_examples() {
+        let mut circuit = Circuit::new(parse_input(EXAMPLE).unwrap());
+        circuit.solve();
+        for (wire, value) in [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ] {
+            assert_eq!(circuit.get(&wire.to_string()), Some(value));
+        }
+    }
+}
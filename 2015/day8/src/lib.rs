@@ -0,0 +1,53 @@
+use anyhow::Result;
+use regex::Regex;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let decode_re = Regex::new(r#"\\("|\\|x[0-9a-fA-F]{2})"#)?;
+
+    let mut literal_chars: usize = 0;
+    let mut mem_chars: usize = 0;
+    for line in input.lines() {
+        let trimmed = line.trim();
+        literal_chars += trimmed.len();
+
+        let mem_result = decode_re.replace_all(trimmed, "x");
+        // -2 for enclosing ""
+        mem_chars += mem_result.len() - 2;
+    }
+
+    Ok(literal_chars - mem_chars)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let encode_re = Regex::new(r#"(["\\])"#)?;
+
+    let mut literal_chars: usize = 0;
+    let mut encode_chars: usize = 0;
+    for line in input.lines() {
+        let trimmed = line.trim();
+        literal_chars += trimmed.len();
+
+        let encoded_result = encode_re.replace_all(trimmed, r#"\x"#);
+        // +2 for enclosing ""
+        encode_chars += encoded_result.len() + 2;
+    }
+
+    Ok(encode_chars - literal_chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27"
+"#;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 12);
+        assert_eq!(part2(EXAMPLE).unwrap(), 19);
+    }
+}
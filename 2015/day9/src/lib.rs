@@ -0,0 +1,60 @@
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+type City = String;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(route_distances(input)?.into_iter().min().unwrap())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(route_distances(input)?.into_iter().max().unwrap())
+}
+
+fn route_distances(input: &str) -> Result<Vec<usize>> {
+    let mut all_cities = HashSet::new();
+    let mut distance_map: HashMap<City, HashMap<City, usize>> = HashMap::new();
+    for line in input.lines() {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() != 5 {
+            return Err(anyhow::anyhow!("Invalid input: {line}"));
+        }
+        let from = tokens[0];
+        let to = tokens[2];
+        let distance = tokens[4].parse::<usize>()?;
+        all_cities.insert(from.to_string());
+        all_cities.insert(to.to_string());
+        distance_map
+            .entry(from.to_string())
+            .or_default()
+            .insert(to.to_string(), distance);
+        distance_map
+            .entry(to.to_string())
+            .or_default()
+            .insert(from.to_string(), distance);
+    }
+
+    Ok(all_cities
+        .iter()
+        .permutations(all_cities.len())
+        .map(|perm| perm.windows(2).map(|w| distance_map[w[0]][w[1]]).sum())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 605);
+        assert_eq!(part2(EXAMPLE).unwrap(), 982);
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
+
+pub const SEARCH_CHIPS: [usize; 2] = [17, 61];
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, SEARCH_CHIPS)
+}
+
+pub fn part1_with(input: &str, search_chips: [usize; 2]) -> Result<usize> {
+    run_factory(input, search_chips)?
+        .0
+        .context("No bot compared the search chips")
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let outputs = run_factory(input, SEARCH_CHIPS)?.1;
+    Ok((0..=2).map(|i| outputs[&i]).product())
+}
+
+// -> (bot comparing the search chips, output bins)
+fn run_factory(
+    input: &str,
+    search_chips: [usize; 2],
+) -> Result<(Option<usize>, HashMap<usize, usize>)> {
+    let mut bots: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut outputs: HashMap<usize, usize> = HashMap::new();
+    let mut move_instructions: Vec<(usize, String, usize, String, usize)> = Vec::new();
+    let val_regex = Regex::new(r"^value (\d+) goes to bot (\d+)$")?;
+    let move_regex =
+        Regex::new(r"^bot (\d+) gives low to (bot|output) (\d+) and high to (bot|output) (\d+)$")?;
+    let mut comparing_bot = None;
+
+    for line in input.lines() {
+        if let Some((_full, [val, bot])) = val_regex.captures(line).map(|c| c.extract()) {
+            let (val, bot): (usize, usize) = (val.parse()?, bot.parse()?);
+            bots.entry(bot)
+                .and_modify(|vals| {
+                    vals.push(val);
+                    vals.sort();
+                })
+                .or_insert(vec![val]);
+        } else if let Some((_full, [bot, low, low_val, high, high_val])) =
+            move_regex.captures(line).map(|c| c.extract())
+        {
+            move_instructions.push((
+                bot.parse()?,
+                low.to_string(),
+                low_val.parse()?,
+                high.to_string(),
+                high_val.parse()?,
+            ));
+        } else {
+            return Err(anyhow::anyhow!("Can't parse line: {}", line));
+        }
+    }
+
+    while bots.values().any(|chips| chips.len() == 2) {
+        for (bot_num, low_type, low_dest, high_type, high_dest) in &move_instructions {
+            let bot = bots.entry(*bot_num).or_default();
+            if bot.len() != 2 {
+                continue;
+            }
+
+            if search_chips.iter().all(|chip| bot.contains(chip)) {
+                comparing_bot = Some(*bot_num);
+            }
+
+            let high_val = bot.pop().unwrap();
+            let low_val = bot.pop().unwrap();
+            if low_type == "bot" {
+                let b = bots.entry(*low_dest).or_default();
+                b.push(low_val);
+                b.sort();
+            } else {
+                outputs.insert(*low_dest, low_val);
+            };
+            if high_type == "bot" {
+                let b = bots.entry(*high_dest).or_default();
+                b.push(high_val);
+                b.sort();
+            } else {
+                outputs.insert(*high_dest, high_val);
+            };
+        }
+    }
+
+    Ok((comparing_bot, outputs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+value 5 goes to bot 2
+bot 2 gives low to bot 1 and high to bot 0
+value 3 goes to bot 1
+bot 1 gives low to output 1 and high to bot 0
+bot 0 gives low to output 2 and high to output 0
+value 2 goes to bot 2
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, [2, 5]).unwrap(), 2);
+        assert_eq!(part2(EXAMPLE).unwrap(), 5 * 2 * 3);
+    }
+}
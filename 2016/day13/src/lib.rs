@@ -0,0 +1,126 @@
+use anyhow::{Error, Result, anyhow};
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+pub const GOAL: Coordinate = (31, 39);
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, GOAL)
+}
+
+pub fn part1_with(input: &str, goal: Coordinate) -> Result<usize> {
+    Ok(min_steps_bfs(goal, parse_input(input)?)?.0)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, GOAL)
+}
+
+pub fn part2_with(input: &str, goal: Coordinate) -> Result<usize> {
+    let visited = min_steps_bfs(goal, parse_input(input)?)?.1;
+    Ok(visited.iter().filter(|v| v.steps <= 50).count())
+}
+
+pub fn parse_input(input: &str) -> Result<usize> {
+    Ok(input
+        .lines()
+        .next()
+        .ok_or_else(|| Error::msg("No input provided"))?
+        .trim()
+        .parse()?)
+}
+
+fn min_steps_bfs(goal: Coordinate, favorite: usize) -> Result<(usize, Visited)> {
+    let mut queue = VecDeque::new();
+    queue.push_back(Node::default());
+
+    let mut visited: Visited = HashSet::new();
+
+    while let Some(current) = queue.pop_front() {
+        if current.coord == goal {
+            return Ok((current.steps, visited));
+        }
+
+        if let Some(v) = visited.get(&current)
+            && v.steps <= current.steps
+        {
+            continue;
+        }
+        visited.insert(current.clone());
+
+        current
+            .neighbors(favorite)
+            .into_iter()
+            .for_each(|n| queue.push_back(n));
+    }
+
+    Err(anyhow!(
+        "No paths found for goal {:?} using favorite {}",
+        goal,
+        favorite
+    ))
+}
+
+pub type Coordinate = (usize, usize);
+type Visited = HashSet<Node>;
+
+#[derive(Clone, Debug, Eq)]
+struct Node {
+    coord: Coordinate,
+    steps: usize,
+}
+
+const ORIENTATIONS: [&(isize, isize); 4] = [&(-1, 0), &(0, 1), &(1, 0), &(0, -1)];
+
+impl Node {
+    fn new(coord: Coordinate, steps: usize) -> Self {
+        Self { coord, steps }
+    }
+
+    fn neighbors(&self, favorite: usize) -> Vec<Node> {
+        ORIENTATIONS
+            .iter()
+            .filter_map(|&&(dx, dy)| {
+                if let (Some(x), Some(y)) = (
+                    self.coord.0.checked_add_signed(dx),
+                    self.coord.1.checked_add_signed(dy),
+                ) {
+                    let num = (x * x) + (3 * x) + (2 * x * y) + (y) + (y * y) + favorite;
+                    let num_ones = num.count_ones();
+                    if num_ones.is_multiple_of(2) {
+                        return Some(Node::new((x, y), self.steps + 1));
+                    }
+                }
+                None
+            })
+            .collect()
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self::new((1, 1), 0)
+    }
+}
+
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coord.hash(state);
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.coord == other.coord
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with("10", (7, 4)).unwrap(), 11);
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::{Error, Result};
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut discs = parse_input(input)?;
+    Ok(find_time(&mut discs))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut discs = parse_input(input)?;
+    discs.push(Disc::new(11, 0));
+    Ok(find_time(&mut discs))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Disc>> {
+    input.lines().map(Disc::from_str).collect()
+}
+
+// Disc 1 should be 1s / rotation away from 0. Disc 2 should be 2. And so on.
+// Basically keep rotating until all the discs line up (runs in ~ 1/4 of a second on my machine).
+fn find_time(discs: &mut [Disc]) -> usize {
+    for t in 0.. {
+        if discs.iter().enumerate().all(|(i, d)| d.hit_0_in(i + 1)) {
+            return t;
+        }
+
+        discs.iter_mut().for_each(|d| d.rotate())
+    }
+
+    0
+}
+
+#[derive(Debug)]
+pub struct Disc {
+    positions: usize,
+    position: usize,
+}
+
+impl Disc {
+    fn new(positions: usize, position: usize) -> Self {
+        Self {
+            positions,
+            position,
+        }
+    }
+
+    // If we rotated n times, would we hit 0?
+    fn hit_0_in(&self, n: usize) -> bool {
+        (self.position + n).is_multiple_of(self.positions)
+    }
+
+    fn rotate(&mut self) {
+        self.position = (self.position + 1) % self.positions
+    }
+}
+
+impl FromStr for Disc {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens = s.split_whitespace().collect::<Vec<_>>();
+        let positions = tokens[3].parse()?;
+        let pos_tokens = tokens[11].split('.').collect::<Vec<_>>();
+        let position = pos_tokens[0].parse()?;
+        Ok(Self::new(positions, position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Disc #1 has 5 positions; at time=0, it is at position 4.
+Disc #2 has 2 positions; at time=0, it is at position 1.
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 5);
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::Result;
+use itertools::Itertools;
+
+pub fn part1(input: &str) -> Result<String> {
+    disk_checksum(input, 272)
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    disk_checksum(input, 35651584)
+}
+
+pub fn disk_checksum(input: &str, length: usize) -> Result<String> {
+    let data = dragon_to_length(input.trim(), length)?;
+    Ok(checksum(&data))
+}
+
+// b = !(rev(a)); new a = a0b
+fn dragon_to_length(data: &str, length: usize) -> Result<String> {
+    let mut data = data.to_string();
+    while data.chars().count() < length {
+        let b = data.chars().rev().map(|c| c.negate()).collect::<String>();
+        data.push('0');
+        data.push_str(&b);
+    }
+    Ok(data.chars().take(length).collect())
+}
+
+fn checksum(data: &str) -> String {
+    let mut data = data.to_string();
+    loop {
+        let checksum: String = data
+            .chars()
+            .tuples()
+            .map(|pair: (char, char)| pair.pair())
+            .collect();
+
+        if checksum.chars().count() % 2 == 1 {
+            return checksum;
+        }
+
+        data = checksum;
+    }
+}
+
+trait Negate {
+    fn negate(&self) -> char;
+}
+
+impl Negate for char {
+    fn negate(&self) -> char {
+        if *self == '0' { '1' } else { '0' }
+    }
+}
+
+trait Pair {
+    fn pair(&self) -> char;
+}
+impl Pair for (char, char) {
+    fn pair(&self) -> char {
+        if self.0 == self.1 { '1' } else { '0' }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(dragon_to_length("1", 3).unwrap(), "100");
+        assert_eq!(dragon_to_length("0", 3).unwrap(), "001");
+        assert_eq!(dragon_to_length("11111", 11).unwrap(), "11111000000");
+        assert_eq!(
+            dragon_to_length("111100001010", 25).unwrap(),
+            "1111000010100101011110000"
+        );
+        assert_eq!(checksum("110010110100"), "100");
+        assert_eq!(disk_checksum("10000", 20).unwrap(), "01100");
+    }
+}
@@ -1,66 +1,18 @@
-use anyhow::{Result, anyhow};
-use itertools::Itertools;
-use std::{env, io};
+use anyhow::Result;
+use aoc_common::Config;
 
 fn main() -> Result<()> {
-    let mut initial = String::new();
-    io::stdin().read_line(&mut initial)?;
-    let initial = initial.trim();
+    let config = Config::load(2016, 16)?;
+    let input = aoc_common::read_input()?;
 
-    let length = env::args()
-        .nth(1)
-        .ok_or(anyhow!("No length provided"))?
-        .parse::<usize>()?;
-
-    let data = dragon_to_length(initial, length)?;
-    println!("Checksum: {}", checksum(&data));
-
-    Ok(())
-}
-
-// b = !(rev(a)); new a = a0b
-fn dragon_to_length(data: &str, length: usize) -> Result<String> {
-    let mut data = data.to_string();
-    while data.chars().count() < length {
-        let b = data.chars().rev().map(|c| c.negate()).collect::<String>();
-        data.push('0');
-        data.push_str(&b);
-    }
-    Ok(data.chars().take(length).collect())
-}
-
-fn checksum(data: &str) -> String {
-    let mut data = data.to_string();
-    loop {
-        let checksum: String = data
-            .chars()
-            .tuples()
-            .map(|pair: (char, char)| pair.pair())
-            .collect();
-
-        if checksum.chars().count() % 2 == 1 {
-            return checksum;
-        }
-
-        data = checksum;
+    if let Some(length) = config.get_or("length", None)? {
+        let checksum = aoc2016_day16::disk_checksum(&input, length)?;
+        println!("Checksum: {}", checksum);
+        return Ok(());
     }
-}
 
-trait Negate {
-    fn negate(&self) -> char;
-}
+    aoc_common::print_answer(1, aoc2016_day16::part1(&input)?);
+    aoc_common::print_answer(2, aoc2016_day16::part2(&input)?);
 
-impl Negate for char {
-    fn negate(&self) -> char {
-        if *self == '0' { '1' } else { '0' }
-    }
-}
-
-trait Pair {
-    fn pair(&self) -> char;
-}
-impl Pair for (char, char) {
-    fn pair(&self) -> char {
-        if self.0 == self.1 { '1' } else { '0' }
-    }
+    Ok(())
 }
@@ -0,0 +1,61 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<usize> {
+    safe_tiles(input, 40)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    safe_tiles(input, 400000)
+}
+
+pub fn safe_tiles(input: &str, count: usize) -> Result<usize> {
+    let mut row: Vec<_> = input
+        .lines()
+        .next()
+        .ok_or_else(|| anyhow!("No row provided"))?
+        .trim()
+        .chars()
+        .map(|c| c == '.')
+        .collect();
+
+    let mut safe_count = row.safe_count();
+    let right_bound = row.len() - 1;
+    for _ in 0..count - 1 {
+        let mut left = true;
+        for i in 0..row.len() {
+            let right = if i < right_bound { row[i + 1] } else { true };
+            let center = row[i];
+            #[allow(clippy::nonminimal_bool)]
+            let is_trap = (!left && !center && right)
+                || (!center && !right && left)
+                || (center && right && !left)
+                || (center && left && !right);
+            left = row[i];
+            row[i] = !is_trap;
+        }
+        safe_count += row.safe_count();
+    }
+
+    Ok(safe_count)
+}
+
+trait SafeCount {
+    fn safe_count(&self) -> usize;
+}
+
+impl SafeCount for Vec<bool> {
+    fn safe_count(&self) -> usize {
+        self.iter().filter(|&&tile| tile).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(safe_tiles("..^^.", 3).unwrap(), 6);
+        assert_eq!(safe_tiles(".^^.^.^^^^", 10).unwrap(), 38);
+    }
+}
@@ -1,52 +1,18 @@
-use anyhow::{Result, anyhow};
-use std::io::BufRead;
-use std::{env, io};
+use anyhow::Result;
+use aoc_common::Config;
 
 fn main() -> Result<()> {
-    let mut row: Vec<_> = io::stdin()
-        .lock()
-        .lines()
-        .next()
-        .ok_or_else(|| anyhow!("No row provided"))??
-        .trim()
-        .chars()
-        .map(|c| c == '.')
-        .collect();
+    let config = Config::load(2016, 18)?;
+    let input = aoc_common::read_input()?;
 
-    let count = env::args()
-        .nth(1)
-        .unwrap_or("40".to_string())
-        .parse::<usize>()?;
-
-    let mut safe_count = row.safe_count();
-    let right_bound = row.len() - 1;
-    for _ in 0..count - 1 {
-        let mut left = true;
-        for i in 0..row.len() {
-            let right = if i < right_bound { row[i + 1] } else { true };
-            let center = row[i];
-            #[allow(clippy::nonminimal_bool)]
-            let is_trap = (!left && !center && right)
-                || (!center && !right && left)
-                || (center && right && !left)
-                || (center && left && !right);
-            left = row[i];
-            row[i] = !is_trap;
-        }
-        safe_count += row.safe_count();
+    if let Some(rows) = config.get_or("rows", None)? {
+        let safe_count = aoc2016_day18::safe_tiles(&input, rows)?;
+        println!("Safe count: {}", safe_count);
+        return Ok(());
     }
 
-    println!("Safe count: {safe_count}");
+    aoc_common::print_answer(1, aoc2016_day18::part1(&input)?);
+    aoc_common::print_answer(2, aoc2016_day18::part2(&input)?);
 
     Ok(())
 }
-
-trait SafeCount {
-    fn safe_count(&self) -> usize;
-}
-
-impl SafeCount for Vec<bool> {
-    fn safe_count(&self) -> usize {
-        self.iter().filter(|&&tile| tile).count()
-    }
-}
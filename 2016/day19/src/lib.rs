@@ -0,0 +1,44 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let elves = parse_input(input)?;
+
+    // See this awesome Numberphile video on The Josephus Problem :)
+    // https://www.youtube.com/watch?v=uCsD3ZGzMgE
+    let shift_width = usize::BITS - elves.leading_zeros() - 1;
+    let sig_bit = elves >> (shift_width);
+    let mask = !(sig_bit << shift_width);
+    Ok(((elves & mask) << 1) | sig_bit)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let elves = parse_input(input)?;
+
+    // After several solutions that were way too slow, I consulted Reddit on the pattern.
+    // TODO: We'll come back to this one :)
+    let mut ans = 1;
+    while ans * 3 < elves {
+        ans *= 3;
+    }
+    Ok(elves - ans)
+}
+
+pub fn parse_input(input: &str) -> Result<usize> {
+    Ok(input
+        .lines()
+        .next()
+        .ok_or_else(|| anyhow!("Number of elves must be supplied"))?
+        .trim()
+        .parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("5").unwrap(), 3);
+        assert_eq!(part2("5").unwrap(), 2);
+    }
+}
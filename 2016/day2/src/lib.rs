@@ -0,0 +1,117 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<String> {
+    bathroom_code(input, Keypad::default())
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    let keypad = Keypad {
+        layout: Layout::Diamond,
+        ..Keypad::default()
+    };
+    bathroom_code(input, keypad)
+}
+
+fn bathroom_code(input: &str, mut keypad: Keypad) -> Result<String> {
+    for line in input.lines() {
+        for c in line.trim().chars() {
+            keypad.advance(&c);
+        }
+        keypad.add_to_code()?;
+    }
+    Ok(keypad.code)
+}
+
+enum Layout {
+    // 1 2 3
+    // 4 5 6
+    // 7 8 9
+    Square,
+    //     1
+    //   2 3 4
+    // 5 6 7 8 9
+    //   A B C
+    //     D
+    Diamond,
+}
+
+struct Keypad {
+    value: u8,
+    code: String,
+    layout: Layout,
+}
+
+impl Default for Keypad {
+    fn default() -> Self {
+        Self {
+            value: 5,
+            code: String::new(),
+            layout: Layout::Square,
+        }
+    }
+}
+
+impl Keypad {
+    fn advance(&mut self, c: &char) {
+        match self.layout {
+            Layout::Square => match c {
+                'U' if self.value > 3 => self.value -= 3,
+                'D' if self.value < 7 => self.value += 3,
+                'L' if ![1, 4, 7].contains(&self.value) => self.value -= 1,
+                'R' if ![3, 6, 9].contains(&self.value) => self.value += 1,
+                _ => {}
+            },
+            Layout::Diamond => match c {
+                'U' => {
+                    if self.value == 3 {
+                        self.value = 1;
+                    } else if [6, 7, 8].contains(&self.value) {
+                        self.value -= 4;
+                    } else if [65, 66, 67].contains(&self.value) {
+                        self.value = self.value - 65 + 6;
+                    } else if self.value == 68 {
+                        self.value = 66;
+                    }
+                }
+                'D' => {
+                    if self.value == 1 {
+                        self.value = 3;
+                    } else if [2, 3, 4].contains(&self.value) {
+                        self.value += 4;
+                    } else if [6, 7, 8].contains(&self.value) {
+                        self.value = self.value - 6 + 65;
+                    } else if self.value == 66 {
+                        self.value = 68;
+                    }
+                }
+                'L' if ![1, 2, 5, 65, 68].contains(&self.value) => self.value -= 1,
+                'R' if ![1, 4, 9, 67, 68].contains(&self.value) => self.value += 1,
+                _ => {}
+            },
+        }
+    }
+
+    fn add_to_code(&mut self) -> Result<()> {
+        if self.value >= 65 {
+            self.code.push(self.value as char);
+        } else {
+            self.code.push(
+                char::from_digit(self.value as u32, 10).ok_or(anyhow::anyhow!("invalid input"))?,
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ULL\nRRDDD\nLURDL\nUUUUD\n";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), "1985");
+        assert_eq!(part2(EXAMPLE).unwrap(), "5DB3");
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<u32> {
+    Ok(valid_ranges(input)?.first().unwrap().0)
+}
+
+pub fn part2(input: &str) -> Result<u32> {
+    Ok(valid_ranges(input)?
+        .iter()
+        .map(|range| range.1 - range.0 + 1)
+        .sum())
+}
+
+fn valid_ranges(input: &str) -> Result<Vec<(u32, u32)>> {
+    let deny_ranges = input
+        .lines()
+        .map(|line| {
+            let tokens: Vec<_> = line.split("-").collect();
+            Ok((tokens[0].parse::<u32>()?, tokens[1].parse::<u32>()?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(deny_ranges
+        .iter()
+        .fold(vec![(0u32, u32::MAX)], |valid, deny| {
+            valid.into_iter().flat_map(|v| v.split(deny)).collect()
+        }))
+}
+
+trait Split {
+    fn split(self, deny: &Self) -> Vec<Self>
+    where
+        Self: Sized;
+}
+
+impl Split for (u32, u32) {
+    // Handle these cases respectively:
+    // * Middle split: |---xxxx---| -> |---|X|---|
+    // * Left split: xxxx---| -> X|---|
+    // * Right split: |---xxxx -> |---|X
+    // * Encompassing
+    // * No split (no overlap)
+    fn split(self, deny: &Self) -> Vec<Self> {
+        if self.0 < deny.0 && self.1 > deny.1 {
+            vec![(self.0, deny.0 - 1), (deny.1 + 1, self.1)]
+        } else if deny.0 <= self.0 && deny.1 >= self.0 && deny.1 < self.1 {
+            vec![(deny.1 + 1, self.1)]
+        } else if deny.0 > self.0 && deny.0 <= self.1 && deny.1 >= self.1 {
+            vec![(self.0, deny.0 - 1)]
+        } else if deny.0 <= self.0 && deny.1 >= self.1 {
+            vec![]
+        } else {
+            vec![self]
+        }
+    }
+}
+
+// I've done a problem somewhat recently where I really struggled to nail this kind of split logic.
+// correct. So I was REALLY paranoid this time ;). Maybe this logic could be cleaner but this works for now...
+#[cfg(test)]
+mod tests {
+    use crate::{Split, part1};
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("5-8\n0-2\n4-7").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_splits() {
+        // Middle
+        run((0, 9), (5, 8), vec![(0, 4), (9, 9)]);
+        run((1, 9), (4, 7), vec![(1, 3), (8, 9)]);
+        // Left
+        run((0, 9), (0, 5), vec![(6, 9)]);
+        run((1, 3), (1, 2), vec![(3, 3)]);
+        // Right
+        run((0, 9), (5, 9), vec![(0, 4)]);
+        run((2, 5), (3, 7), vec![(2, 2)]);
+        // Bigger/encompassing
+        run((2, 5), (0, 9), vec![]);
+        run((1, 1), (0, 9), vec![]);
+        run((1, 1), (1, 1), vec![]);
+        // No split (no overlap) right
+        run((1, 5), (8, 9), vec![(1, 5)]);
+        // No split (no overlap) left
+        run((8, 9), (1, 5), vec![(8, 9)]);
+    }
+
+    fn run(valid: (u32, u32), deny: (u32, u32), expected: Vec<(u32, u32)>) {
+        let res = valid.split(&deny);
+        assert_eq!(&expected, &res);
+    }
+}
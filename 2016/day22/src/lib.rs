@@ -0,0 +1,199 @@
+use anyhow::{Result, anyhow};
+use regex::Regex;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_grid(input)?.viable_pairs().len())
+}
+
+// Print the grid and solve part 2 by hand. My input looked like this:
+// S..............................
+// ...............................
+// ..X............................
+// ..X............................
+// ..X......................._....   <-- Empty spot in this row
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// ..X............................
+// G.X............................
+//
+// An optimal solution is moving the empty (_) space up around the wall and down above G. Then
+// shuffling it and G (5 moves) to bring G up 32 spaces up to S.
+// 3 Up
+// 26 Left
+// 31 Down
+// 32 * 5 Up
+// total of 220 moves
+pub fn part2(input: &str) -> Result<String> {
+    let grid = parse_grid(input)?;
+    let pairs = grid.viable_pairs();
+
+    let mut rendered = String::new();
+    let goal = grid.nodes.last().unwrap().first().unwrap().coords;
+    for row in grid.nodes.iter() {
+        rendered.push('\n');
+        for node in row.iter() {
+            if node.coords == (0, 0) {
+                rendered.push('S');
+            } else if node.coords == goal {
+                rendered.push('G');
+            } else if node.used == 0 {
+                rendered.push('_');
+            } else if pairs.contains(&node.coords) {
+                rendered.push('.');
+            } else {
+                // Node's data is too big to move anywhere else.
+                rendered.push('X');
+            }
+        }
+    }
+
+    Ok(rendered)
+}
+
+#[derive(Debug)]
+struct Grid {
+    nodes: Vec<Vec<StorageNode>>,
+}
+
+impl Grid {
+    fn viable_pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for n1 in self.nodes.iter().flat_map(|row| row.iter()) {
+            for n2 in self.nodes.iter().flat_map(|row| row.iter()) {
+                if n1 == n2 {
+                    continue;
+                }
+
+                if !n1.is_empty() && n2.can_fit(n1) {
+                    pairs.push(n1.coords);
+                }
+            }
+        }
+        pairs
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+struct StorageNode {
+    coords: (usize, usize),
+    size: usize,
+    used: usize,
+    has_goal_data: bool,
+}
+
+impl StorageNode {
+    fn is_empty(&self) -> bool {
+        self.used == 0
+    }
+
+    fn available(&self) -> usize {
+        self.size - self.used
+    }
+
+    fn can_fit(&self, other: &Self) -> bool {
+        other.used <= self.available()
+    }
+}
+
+fn parse_grid(input: &str) -> Result<Grid> {
+    // Convert this to rows of columns structure (NOTE: these are parsed in order)
+    let mut nodes = Vec::new();
+    let node_parser = NodeParser::new()?;
+    for line in input.lines().skip(2) {
+        let node = node_parser.parse(line)?;
+        if nodes.len() < node.coords.0 + 1 {
+            nodes.push(Vec::new());
+        }
+        nodes[node.coords.0].push(node);
+    }
+    Ok(Grid { nodes })
+}
+
+struct NodeParser {
+    node_matcher: Regex,
+}
+
+impl NodeParser {
+    fn new() -> Result<Self> {
+        Ok(Self {
+            node_matcher: Regex::new(r"/dev/grid/node-x(\d+)-y(\d+)")?,
+        })
+    }
+
+    fn parse(&self, s: &str) -> Result<StorageNode> {
+        let tokens: Vec<_> = s.split_ascii_whitespace().collect();
+
+        if !self.node_matcher.is_match(tokens[0]) {
+            return Err(anyhow!("Invalid node: {}", tokens[0]));
+        }
+
+        let (_full, [x, y]) = self
+            .node_matcher
+            .captures(tokens[0])
+            .map(|c| c.extract())
+            .unwrap();
+
+        Ok(StorageNode {
+            coords: (x.parse()?, y.parse()?),
+            size: self.strip_suffix(tokens[1]).parse()?,
+            used: self.strip_suffix(tokens[2]).parse()?,
+            has_goal_data: false,
+        })
+    }
+
+    fn strip_suffix<'a>(&self, s: &'a str) -> &'a str {
+        let mut chars = s.chars();
+        chars.next_back();
+        chars.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+root@ebhq-gridcenter# df -h
+Filesystem            Size  Used  Avail  Use%
+/dev/grid/node-x0-y0   10T    8T     2T   80%
+/dev/grid/node-x0-y1   11T    6T     5T   54%
+/dev/grid/node-x0-y2   32T   28T     4T   87%
+/dev/grid/node-x1-y0    9T    7T     2T   77%
+/dev/grid/node-x1-y1    8T    0T     8T    0%
+/dev/grid/node-x1-y2   11T    7T     4T   63%
+/dev/grid/node-x2-y0   10T    6T     4T   60%
+/dev/grid/node-x2-y1    9T    8T     1T   88%
+/dev/grid/node-x2-y2    9T    6T     3T   66%
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 7);
+        assert_eq!(part2(EXAMPLE).unwrap(), "\nS.X\n._.\nG..");
+    }
+}
@@ -0,0 +1,75 @@
+use anyhow::{Context, Result, anyhow};
+use itertools::Itertools;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_triangles(input)?.0.count)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(count_triangles(input)?.1.count)
+}
+
+// -> (triangles counted by row, triangles counted by column)
+fn count_triangles(input: &str) -> Result<(TriangleCounter, TriangleCounter)> {
+    let mut valid_horiz = TriangleCounter::default();
+
+    let mut valid_vert = TriangleCounter::default();
+
+    for chunk in &input.lines().chunks(3) {
+        let mut rows = [[0; 3]; 3];
+        for (i, line) in chunk.enumerate() {
+            let sides: [usize; 3] = line
+                .split_whitespace()
+                .map(|s| s.parse::<usize>().context("Failed to parse side"))
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
+                .map_err(|_| anyhow!("Failed to parse 3 sides"))?;
+
+            valid_horiz.count(&sides);
+
+            rows[i] = sides;
+        }
+
+        #[allow(clippy::needless_range_loop)]
+        for col in 0..3 {
+            let column = [rows[0][col], rows[1][col], rows[2][col]];
+            valid_vert.count(&column);
+        }
+    }
+
+    Ok((valid_horiz, valid_vert))
+}
+
+#[derive(Default)]
+struct TriangleCounter {
+    count: usize,
+}
+
+impl TriangleCounter {
+    fn count(&mut self, [a, b, c]: &[usize; 3]) {
+        if a + b > *c && a + c > *b && b + c > *a {
+            self.count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+101 301 501
+102 302 502
+103 303 503
+201 401 601
+202 402 602
+203 403 603
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("5 10 25").unwrap(), 0);
+        assert_eq!(part1(EXAMPLE).unwrap(), 3);
+        assert_eq!(part2(EXAMPLE).unwrap(), 6);
+    }
+}
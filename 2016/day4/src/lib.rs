@@ -0,0 +1,102 @@
+use anyhow::Result;
+use regex::Regex;
+use std::cmp::Ordering;
+
+const BASE: usize = 'a' as usize;
+
+#[derive(Copy, Clone, Debug, Default)]
+struct Freq {
+    c: char,
+    count: usize,
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut sector_sum = 0;
+    for (name, sector_id, checksum) in parse_input(input)? {
+        if is_real(&name, &checksum) {
+            sector_sum += sector_id;
+        }
+    }
+    Ok(sector_sum)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut sector_found = 0;
+    for (name, sector_id, _checksum) in parse_input(input)? {
+        if decrypt(&name, sector_id) == "northpole object storage " {
+            sector_found = sector_id;
+        }
+    }
+    Ok(sector_found)
+}
+
+// -> (name, sector ID, checksum) per room
+pub fn parse_input(input: &str) -> Result<Vec<(String, usize, String)>> {
+    let regex = Regex::new(r"([a-z-]+)([0-9].+)\[([a-z].+)\]")?;
+    input
+        .lines()
+        .map(|line| {
+            let caps = regex
+                .captures(line)
+                .ok_or_else(|| anyhow::anyhow!("Invalid input"))?;
+            Ok((caps[1].to_string(), caps[2].parse()?, caps[3].to_string()))
+        })
+        .collect()
+}
+
+fn is_real(name: &str, checksum: &str) -> bool {
+    // common[i] = freq of i; i = offset from 'a'
+    let mut common: [Freq; 26] = [Freq::default(); 26];
+    for (i, c) in common.iter_mut().enumerate() {
+        c.c = (i + BASE) as u8 as char;
+    }
+
+    for c in name.chars() {
+        if c != '-' {
+            common[c as usize - BASE].count += 1;
+        }
+    }
+
+    common.sort_by(|a, b| match b.count.cmp(&a.count) {
+        Ordering::Equal => a.c.cmp(&b.c),
+        val => val,
+    });
+
+    let mut calc_checksum = String::new();
+    for c in common.iter().take(5) {
+        calc_checksum.push(c.c);
+    }
+
+    calc_checksum == checksum
+}
+
+fn decrypt(name: &str, sector_id: usize) -> String {
+    let mut decrypted = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c == '-' {
+            decrypted.push(' ');
+        } else {
+            let rotated = ((c as usize - BASE) + sector_id) % 26 + BASE;
+            decrypted.push(rotated as u8 as char);
+        }
+    }
+    decrypted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+aaaaa-bbb-z-y-x-123[abxyz]
+a-b-c-d-e-f-g-h-987[abcde]
+not-a-real-room-404[oarel]
+totally-real-room-200[decoy]
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 1514);
+        assert_eq!(decrypt("qzmt-zixmtkozy-ivhz-", 343), "very encrypted name ");
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use std::fmt::Write;
+
+pub fn part1(input: &str) -> Result<String> {
+    crack(input.trim(), false)
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    crack(input.trim(), true)
+}
+
+fn crack(door_id: &str, positional: bool) -> Result<String> {
+    let mut pass = ['_'; 8];
+    let mut p1_i = 0;
+    let mut index = 0;
+    let mut buf = String::with_capacity(door_id.len() + 10);
+    let hex_char = |n: u8| char::from_digit(n as u32, 16).unwrap();
+    while pass.contains(&'_') {
+        buf.clear();
+        write!(&mut buf, "{}{}", door_id, index)?;
+        index += 1;
+
+        let hash: [u8; 16] = md5::compute(buf.as_bytes()).into();
+        if hash[0] != 0x00 || hash[1] != 0x00 || hash[2] > 0x0F {
+            continue;
+        }
+        let sixth = hex_char(hash[2] & 0x0F);
+
+        if !positional {
+            pass[p1_i] = sixth;
+            p1_i += 1;
+            continue;
+        }
+
+        let position = sixth;
+        if let '0'..='7' = position {
+            let position = position.to_digit(10).unwrap() as usize;
+            if pass[position] == '_' {
+                let seventh = hex_char((hash[3] & 0xF0) >> 4);
+                pass[position] = seventh;
+            }
+        }
+    }
+
+    Ok(pass.iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("abc").unwrap(), "18f47a30");
+        assert_eq!(part2("abc").unwrap(), "05ace8e3");
+    }
+}
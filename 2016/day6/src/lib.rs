@@ -0,0 +1,71 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+type Frequencies = HashMap<usize, HashMap<char, usize>>;
+
+pub fn part1(input: &str) -> Result<String> {
+    Ok(error_correct(&frequencies(input), true))
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    Ok(error_correct(&frequencies(input), false))
+}
+
+fn frequencies(input: &str) -> Frequencies {
+    let mut frequencies: Frequencies = HashMap::new();
+    for line in input.lines() {
+        line.trim().chars().enumerate().for_each(|(i, c)| {
+            frequencies
+                .entry(i)
+                .or_default()
+                .entry(c)
+                .and_modify(|e| *e += 1)
+                .or_insert(1);
+        })
+    }
+    frequencies
+}
+
+fn error_correct(frequencies: &Frequencies, most_common: bool) -> String {
+    (0..frequencies.keys().count())
+        .map(|col| {
+            *frequencies
+                .get(&col)
+                .unwrap()
+                .iter()
+                .max_by(|(_k1, v1), (_k2, v2)| if most_common { v1.cmp(v2) } else { v2.cmp(v1) })
+                .unwrap()
+                .0
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+eedadn
+drvtee
+eandsr
+raavrd
+atevrs
+tsrnev
+sdttsa
+rasrtv
+nssdts
+ntnada
+svetve
+tesnvt
+vntsnd
+vrdear
+dvrsen
+enarar
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), "easter");
+        assert_eq!(part2(EXAMPLE).unwrap(), "advent");
+    }
+}
@@ -0,0 +1,129 @@
+use anyhow::{Result, anyhow};
+use std::fmt::Write;
+
+pub const WIDTH: usize = 50;
+pub const HEIGHT: usize = 6;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, WIDTH, HEIGHT)
+}
+
+pub fn part1_with(input: &str, width_limit: usize, height_limit: usize) -> Result<usize> {
+    Ok(run(input, width_limit, height_limit)?.lit_count())
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    part2_with(input, WIDTH, HEIGHT)
+}
+
+pub fn part2_with(input: &str, width_limit: usize, height_limit: usize) -> Result<String> {
+    Ok(run(input, width_limit, height_limit)?.render())
+}
+
+fn run(input: &str, width_limit: usize, height_limit: usize) -> Result<Screen> {
+    let mut screen = Screen::new(width_limit, height_limit);
+
+    for operation in input.lines() {
+        let tokens: Vec<_> = operation.split_whitespace().collect();
+        match tokens.as_slice() {
+            ["rect", dims] => {
+                let (w, h) = dims
+                    .split_once('x')
+                    .ok_or_else(|| anyhow!("Invalid rect dims: {}", dims))?;
+                screen.rect(h.parse()?, w.parse()?);
+            }
+            ["rotate", kind, rc, "by", shifts] => {
+                let idx = rc
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("Invalid rotate: {}", rc))?
+                    .1
+                    .parse::<usize>()?;
+                let shifts = shifts.parse::<usize>()?;
+                match *kind {
+                    "row" => screen.rotate_row(idx, shifts),
+                    "column" => screen.rotate_column(idx, shifts),
+                    _ => return Err(anyhow!("Unknown rotate kind: {}", kind)),
+                }
+            }
+            _ => return Err(anyhow!("Unknown operation: {}", operation)),
+        }
+    }
+
+    Ok(screen)
+}
+
+struct Screen {
+    pixels: Vec<Vec<bool>>,
+}
+
+impl Screen {
+    fn new(width_limit: usize, height_limit: usize) -> Self {
+        Self {
+            pixels: vec![vec![false; width_limit]; height_limit],
+        }
+    }
+
+    fn rect(&mut self, height: usize, width: usize) {
+        for i in 0..height {
+            for j in 0..width {
+                self.pixels[i][j] = true;
+            }
+        }
+    }
+
+    fn rotate_row(&mut self, row_num: usize, shifts: usize) {
+        let row = self.pixels[row_num].clone();
+        let width = row.len();
+        for (col, val) in row.into_iter().enumerate() {
+            self.pixels[row_num][(col + shifts) % width] = val;
+        }
+    }
+
+    fn rotate_column(&mut self, col_num: usize, shifts: usize) {
+        let height = self.pixels.len();
+        let mut column = vec![false; height];
+        for (row, val) in column.iter_mut().enumerate() {
+            *val = self.pixels[row][col_num];
+        }
+        for (row, val) in column.into_iter().enumerate() {
+            self.pixels[(row + shifts) % height][col_num] = val;
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut rendered = String::new();
+        for row in 0..self.pixels.len() {
+            writeln!(rendered).unwrap();
+            for col in 0..self.pixels[row].len() {
+                let char = if self.pixels[row][col] { '#' } else { '.' };
+                write!(rendered, "{}", char).unwrap();
+            }
+        }
+        rendered
+    }
+
+    fn lit_count(&self) -> usize {
+        self.pixels.iter().flatten().filter(|&&c| c).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, 7, 3).unwrap(), 6);
+        assert_eq!(
+            part2_with(EXAMPLE, 7, 3).unwrap(),
+            "\n.#..#.#\n#.#....\n.#....."
+        );
+    }
+}
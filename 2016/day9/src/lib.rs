@@ -0,0 +1,84 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(decompress_len(input, false))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(decompress_len(input, true))
+}
+
+fn decompress_len(text: &str, recurse: bool) -> usize {
+    let mut decompressed_len = 0;
+    let mut iter = text.chars().filter(|c| !c.is_whitespace());
+
+    while let Some(c) = iter.next() {
+        // Try to parse this as a (AxB) marker sequence
+        if c == '(' {
+            let mut try_marker = String::new();
+            let mut seen_close = false;
+            for c in iter.by_ref() {
+                if c == ')' {
+                    seen_close = true;
+                    break;
+                }
+                try_marker.push(c);
+            }
+            // We never encountered a ')': consider these normal characters
+            if !seen_close {
+                decompressed_len += try_marker.chars().count();
+                continue;
+            }
+
+            let seq_tokens = try_marker.split('x').collect::<Vec<_>>();
+            if let [left, right] = seq_tokens.as_slice() {
+                match (left.parse::<usize>(), right.parse::<usize>()) {
+                    (Ok(num_char), Ok(repeat)) => {
+                        let mut to_repeat = String::new();
+                        // We always have enough characters to repeat
+                        for _ in 0..num_char {
+                            to_repeat.push(iter.next().unwrap());
+                        }
+                        // Decompress length will be the same if repeated - so just compute once
+                        let len = if recurse {
+                            decompress_len(&to_repeat, true)
+                        } else {
+                            to_repeat.chars().count()
+                        };
+                        decompressed_len += len * repeat;
+                    }
+                    _ => {
+                        decompressed_len += try_marker.chars().count();
+                    }
+                }
+            }
+        } else {
+            decompressed_len += 1;
+        }
+    }
+
+    decompressed_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("ADVENT").unwrap(), 6);
+        assert_eq!(part1("A(1x5)BC").unwrap(), 7);
+        assert_eq!(part1("(3x3)XYZ").unwrap(), 9);
+        assert_eq!(part1("A(2x2)BCD(2x2)EFG").unwrap(), 11);
+        assert_eq!(part1("(6x1)(1x3)A").unwrap(), 6);
+        assert_eq!(part1("X(8x2)(3x3)ABCY").unwrap(), 18);
+
+        assert_eq!(part2("(3x3)XYZ").unwrap(), 9);
+        assert_eq!(part2("X(8x2)(3x3)ABCY").unwrap(), 20);
+        assert_eq!(part2("(27x12)(20x12)(13x14)(7x10)(1x12)A").unwrap(), 241920);
+        assert_eq!(
+            part2("(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN").unwrap(),
+            445
+        );
+    }
+}
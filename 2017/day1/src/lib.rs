@@ -0,0 +1,53 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<u32> {
+    Ok(captcha(&parse_digits(input)?, 1))
+}
+
+pub fn part2(input: &str) -> Result<u32> {
+    let digits = parse_digits(input)?;
+    Ok(captcha(&digits, digits.len() / 2))
+}
+
+fn captcha(digits: &[u32], step: usize) -> u32 {
+    let len = digits.len();
+    digits
+        .iter()
+        .enumerate()
+        .filter(|&(i, digit)| *digit == digits[(i + step) % len])
+        .map(|(_i, digit)| digit)
+        .sum()
+}
+
+fn parse_digits(input: &str) -> Result<Vec<u32>> {
+    input
+        .lines()
+        .next()
+        .ok_or_else(|| anyhow!("No digit line provided"))?
+        .trim()
+        .chars()
+        .map(|c| {
+            c.to_digit(10)
+                .ok_or_else(|| anyhow!("All digits must be [0-9]"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("1122").unwrap(), 3);
+        assert_eq!(part1("1111").unwrap(), 4);
+        assert_eq!(part1("1234").unwrap(), 0);
+        assert_eq!(part1("91212129").unwrap(), 9);
+
+        assert_eq!(part2("1212").unwrap(), 6);
+        assert_eq!(part2("1221").unwrap(), 0);
+        assert_eq!(part2("123425").unwrap(), 4);
+        assert_eq!(part2("123123").unwrap(), 12);
+        assert_eq!(part2("12131415").unwrap(), 4);
+    }
+}
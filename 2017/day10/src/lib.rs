@@ -0,0 +1,80 @@
+use anyhow::Result;
+
+pub const SIZE: usize = 256;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, SIZE)
+}
+
+pub fn part1_with(input: &str, size: usize) -> Result<usize> {
+    let lengths: Vec<_> = input
+        .split(",")
+        .map(|l| l.trim().parse().map_err(anyhow::Error::from))
+        .collect::<Result<_>>()?;
+    let mut list: Vec<_> = (0..size).collect();
+    KnotHasher::default().hash(&mut list, &lengths);
+    Ok(list.iter().take(2).product())
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    part2_with(input, SIZE)
+}
+
+pub fn part2_with(input: &str, size: usize) -> Result<String> {
+    let mut list: Vec<_> = (0..size).collect();
+    let mut lengths: Vec<_> = input.trim().chars().map(|c| c as usize).collect();
+    lengths.append(&mut vec![17, 31, 73, 47, 23]);
+    let mut hasher = KnotHasher::default();
+    for _ in 0..64 {
+        hasher.hash(&mut list, &lengths);
+    }
+    let reduced = KnotHasher::reduce(&list);
+    Ok(reduced.iter().map(|r| format!("{:02x}", r)).collect())
+}
+
+#[derive(Default)]
+struct KnotHasher {
+    current: usize,
+    skip: usize,
+}
+
+impl KnotHasher {
+    fn hash(&mut self, list: &mut [usize], lengths: &[usize]) {
+        for &length in lengths.iter() {
+            let rev: Vec<_> = (0..length)
+                .map(|i| list[(self.current + i) % list.len()])
+                .rev()
+                .collect();
+            (0..length).for_each(|i| list[(self.current + i) % list.len()] = rev[i]);
+            self.current += length + self.skip;
+            self.skip += 1;
+        }
+    }
+
+    fn reduce(list: &[usize]) -> Vec<usize> {
+        list.chunks(16)
+            .map(|block| block.iter().fold(0, |acc, &n| acc ^ n))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let mut list: Vec<_> = (0..5).collect();
+        KnotHasher::default().hash(&mut list, &[3, 4, 1, 5]);
+        assert_eq!(list, [3, 4, 2, 1, 0]);
+        assert_eq!(part1_with("3,4,1,5", 5).unwrap(), 12);
+
+        assert_eq!(part2("").unwrap(), "a2582a3a0e66e6e86e3812dcb672a272");
+        assert_eq!(
+            part2("AoC 2017").unwrap(),
+            "33efeb34ea91902bb2f59c9920caa6cd"
+        );
+        assert_eq!(part2("1,2,3").unwrap(), "3efbe78a8d82f29979031a4aa0b16a9d");
+        assert_eq!(part2("1,2,4").unwrap(), "63960835bcdc130f0b66d7ff4f6a5a8e");
+    }
+}
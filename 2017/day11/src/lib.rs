@@ -0,0 +1,87 @@
+use anyhow::{Result, anyhow};
+use std::ops::Add;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(walk(&parse_path(input)?).0.distance())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(walk(&parse_path(input)?).1)
+}
+
+// https://www.redblobgames.com/grids/hexagons/#distances
+//
+// -> (final position, furthest distance reached)
+fn walk(path: &[Coords]) -> (Coords, usize) {
+    path.iter()
+        .fold((Coords::default(), 0), |(pos, max), &dir| {
+            let new_pos = pos + dir;
+            let new_max = max.max(new_pos.distance());
+            (new_pos, new_max)
+        })
+}
+
+fn parse_path(input: &str) -> Result<Vec<Coords>> {
+    input.trim().split(',').map(str::parse).collect()
+}
+
+#[derive(Debug, Default, Copy, Clone)]
+struct Coords {
+    q: isize,
+    r: isize,
+    s: isize,
+}
+
+impl Coords {
+    const fn new(q: isize, r: isize, s: isize) -> Self {
+        Self { q, r, s }
+    }
+
+    fn distance(&self) -> usize {
+        ((self.q.abs() + self.r.abs() + self.s.abs()) / 2) as usize
+    }
+}
+
+impl Add for Coords {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            q: self.q + other.q,
+            r: self.r + other.r,
+            s: self.s + other.s,
+        }
+    }
+}
+
+impl FromStr for Coords {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "n" => Ok(Coords::new(0, -1, 1)),
+            "ne" => Ok(Coords::new(1, -1, 0)),
+            "se" => Ok(Coords::new(1, 0, -1)),
+            "s" => Ok(Coords::new(0, 1, -1)),
+            "sw" => Ok(Coords::new(-1, 1, 0)),
+            "nw" => Ok(Coords::new(-1, 0, 1)),
+            _ => Err(anyhow!("Unrecognized direction: {s}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("ne,ne,ne").unwrap(), 3);
+        assert_eq!(part1("ne,ne,sw,sw").unwrap(), 0);
+        assert_eq!(part1("ne,ne,s,s").unwrap(), 2);
+        assert_eq!(part1("se,sw,se,sw,sw").unwrap(), 3);
+
+        assert_eq!(part2("ne,ne,sw,sw").unwrap(), 2);
+    }
+}
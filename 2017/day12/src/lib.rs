@@ -0,0 +1,95 @@
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let components = parse_input(input)?.connected_components();
+    Ok(components
+        .iter()
+        .find(|c| c.contains(&0))
+        .ok_or(anyhow!("Component containing 0 not found"))?
+        .len())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.connected_components().len())
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    programs: HashMap<usize, HashSet<usize>>,
+}
+
+impl Graph {
+    fn connected_components(&self) -> Vec<HashSet<usize>> {
+        let mut groups = Vec::new();
+        let mut visited = HashSet::new();
+
+        for &start in self.programs.keys() {
+            if visited.contains(&start) {
+                continue;
+            }
+
+            let mut component = HashSet::new();
+            let mut stack = vec![start];
+
+            while let Some(node) = stack.pop() {
+                if !visited.insert(node) {
+                    continue;
+                }
+                component.insert(node);
+
+                if let Some(neighbors) = self.programs.get(&node) {
+                    for &neighbor in neighbors {
+                        if !visited.contains(&neighbor) {
+                            stack.push(neighbor);
+                        }
+                    }
+                }
+            }
+
+            groups.push(component);
+        }
+
+        groups
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Graph> {
+    let mut programs = HashMap::new();
+
+    for line in input.lines() {
+        let tokens: Vec<_> = line.split(" <-> ").collect();
+        let left: usize = tokens[0].parse()?;
+        let right: Vec<usize> = tokens[1]
+            .split(',')
+            .map(|r| r.trim().parse())
+            .collect::<Result<_, _>>()?;
+        for &r in &right {
+            programs.entry(left).or_insert_with(HashSet::new).insert(r);
+            programs.entry(r).or_insert_with(HashSet::new).insert(left);
+        }
+    }
+
+    Ok(Graph { programs })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 6);
+        assert_eq!(part2(EXAMPLE).unwrap(), 2);
+    }
+}
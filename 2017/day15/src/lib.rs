@@ -0,0 +1,97 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let [gen_a, gen_b] = parse_input(input)?;
+    Ok(count_matches(gen_a, gen_b, 40_000_000))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let [gen_a, gen_b] = parse_input(input)?;
+    Ok(count_matches(
+        gen_a.filter(|v| v % 4 == 0),
+        gen_b.filter(|v| v % 8 == 0),
+        5_000_000,
+    ))
+}
+
+fn count_matches(
+    a: impl Iterator<Item = usize>,
+    b: impl Iterator<Item = usize>,
+    take: usize,
+) -> usize {
+    const MASK: usize = 0xFFFF;
+    a.zip(b)
+        .take(take)
+        .filter(|&(a, b)| a & MASK == b & MASK)
+        .count()
+}
+
+pub fn parse_input(input: &str) -> Result<[Generator; 2]> {
+    fn parse_line(line: &str) -> Result<usize> {
+        Ok(line
+            .split_ascii_whitespace()
+            .last()
+            .ok_or_else(|| anyhow!("No starting value provided"))?
+            .parse()?)
+    }
+
+    let mut lines = input.lines();
+    let start_a = parse_line(lines.next().ok_or_else(|| anyhow!("Missing first line"))?)?;
+    let start_b = parse_line(lines.next().ok_or_else(|| anyhow!("Missing second line"))?)?;
+
+    Ok([
+        Generator::new(start_a, 16807),
+        Generator::new(start_b, 48271),
+    ])
+}
+
+#[derive(Clone, Debug)]
+pub struct Generator {
+    value: usize,
+    factor: usize,
+}
+
+impl Generator {
+    fn new(value: usize, factor: usize) -> Self {
+        Self { value, factor }
+    }
+}
+
+impl Iterator for Generator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.value = (self.value * self.factor) % 2147483647;
+        Some(self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Generator A starts with 65
+Generator B starts with 8921
+";
+
+    #[test]
+    fn test_examples() {
+        let [gen_a, gen_b] = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            gen_a.clone().take(5).collect::<Vec<_>>(),
+            [1092455, 1181022009, 245556042, 1744312007, 1352636452]
+        );
+        assert_eq!(
+            gen_b.clone().take(5).collect::<Vec<_>>(),
+            [430625591, 1233683848, 1431495498, 137874439, 285222916]
+        );
+        assert_eq!(count_matches(gen_a.clone(), gen_b.clone(), 5), 1);
+
+        // The first judged match with the picky generators is on the 1056th pair
+        let picky_a = gen_a.filter(|v| v % 4 == 0);
+        let picky_b = gen_b.filter(|v| v % 8 == 0);
+        assert_eq!(count_matches(picky_a.clone(), picky_b.clone(), 1055), 0);
+        assert_eq!(count_matches(picky_a, picky_b, 1056), 1);
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{Error, Result, anyhow, bail};
+use std::str::FromStr;
+
+pub const LAST_DANCER: char = 'p';
+
+pub fn part1(input: &str) -> Result<String> {
+    part1_with(input, LAST_DANCER)
+}
+
+pub fn part1_with(input: &str, end: char) -> Result<String> {
+    dance(input, end, 1)
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    part2_with(input, LAST_DANCER)
+}
+
+// Positions repeat after every 60 dances, 1_000_000_000 % 60 == 40
+pub fn part2_with(input: &str, end: char) -> Result<String> {
+    dance(input, end, 40)
+}
+
+fn dance(input: &str, end: char, times: usize) -> Result<String> {
+    let mut dancers: Vec<_> = ('a'..=end).collect();
+    let moves = parse_moves(input)?;
+
+    for _ in 0..times {
+        for m in &moves {
+            m.apply(&mut dancers);
+        }
+    }
+    Ok(dancers.iter().collect())
+}
+
+#[derive(Debug)]
+enum Move {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(char, char),
+}
+
+impl Move {
+    fn apply(&self, dancers: &mut [char]) {
+        match self {
+            Move::Spin(n) => dancers.rotate_right(*n),
+            Move::Exchange(p1, p2) => dancers.swap(*p1, *p2),
+            Move::Partner(p1, p2) => {
+                let pos1 = dancers.iter().position(|d| d == p1).unwrap();
+                let pos2 = dancers.iter().position(|d| d == p2).unwrap();
+                dancers.swap(pos1, pos2);
+            }
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let to_pos = |s: &str| -> Result<usize, Error> {
+            let d = s.parse::<usize>()?;
+            if d > 15 {
+                bail!("Invalid position: {s}")
+            } else {
+                Ok(d)
+            }
+        };
+        match s.chars().next().ok_or(anyhow!("Invalid move: {s}"))? {
+            's' => Ok(Move::Spin(to_pos(&s[1..])?)),
+            'x' => {
+                let parts: Vec<_> = s[1..].split("/").collect();
+                Ok(Move::Exchange(to_pos(parts[0])?, to_pos(parts[1])?))
+            }
+            'p' => {
+                let chars: Vec<_> = s.chars().collect();
+                Ok(Move::Partner(chars[1], chars[3]))
+            }
+            _ => bail!("Invalid move"),
+        }
+    }
+}
+
+fn parse_moves(input: &str) -> Result<Vec<Move>> {
+    input.trim().split(",").map(|m| m.parse()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with("s1,x3/4,pe/b", 'e').unwrap(), "baedc");
+        assert_eq!(dance("s1,x3/4,pe/b", 'e', 2).unwrap(), "ceadb");
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::{Context, Result};
+
+pub fn part1(input: &str) -> Result<u32> {
+    let (buffer, pos) = spin(parse_input(input)?);
+    let after = (pos + 1) % buffer.len();
+    Ok(buffer[after])
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let step = parse_input(input)?;
+    let (buffer, mut pos) = spin(step);
+
+    // At this point, we only need to track what's inserted into the second position (index 1)
+    // rather than the whole ~1.5GB of buffer contents for 50 million numbers.
+    let mut second = buffer[1] as usize;
+    for (buffer_size, i) in (buffer.len()..).zip(2018..=50_000_000) {
+        pos = (pos + step) % buffer_size + 1;
+        if pos == 1 {
+            second = i;
+        }
+    }
+    Ok(second)
+}
+
+// -> (buffer after 2017 insertions, current position)
+fn spin(step: usize) -> (Vec<u32>, usize) {
+    let mut pos = 0;
+    let mut buffer = Vec::with_capacity(2018);
+    buffer.push(0);
+    for i in 1u32..=2017 {
+        pos = (pos + step) % buffer.len() + 1;
+        buffer.insert(pos, i);
+    }
+    (buffer, pos)
+}
+
+pub fn parse_input(input: &str) -> Result<usize> {
+    input.trim().parse().context("Failed to parse step")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("3").unwrap(), 638);
+    }
+}
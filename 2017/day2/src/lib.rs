@@ -0,0 +1,55 @@
+use anyhow::{Error, Result};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_rows(input)?
+        .iter()
+        .map(|row| diff_checksum(row))
+        .sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_rows(input)?.iter().map(|row| div_checksum(row)).sum())
+}
+
+fn diff_checksum(row: &[usize]) -> usize {
+    let (min, max) = row
+        .iter()
+        .fold((usize::MAX, usize::MIN), |(min, max), &val| {
+            (min.min(val), max.max(val))
+        });
+    min.abs_diff(max)
+}
+
+fn div_checksum(row: &[usize]) -> usize {
+    for (i, &val1) in row.iter().enumerate() {
+        for &val2 in row.iter().skip(i + 1) {
+            let (small, large) = (val1.min(val2), val1.max(val2));
+            if large % small == 0 {
+                return large / small;
+            }
+        }
+    }
+    0
+}
+
+fn parse_rows(input: &str) -> Result<Vec<Vec<usize>>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|val| val.parse::<usize>().map_err(Error::from))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("5 1 9 5\n7 5 3\n2 4 6 8").unwrap(), 18);
+        assert_eq!(part2("5 9 2 8\n9 4 7 3\n3 8 6 5").unwrap(), 9);
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::{Error, Result, anyhow, bail};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut particles = parse_input(input)?;
+    simulate(&mut particles, false);
+    let (idx, _closest) = particles
+        .iter()
+        .enumerate()
+        .min_by(|(_i, p1), (_j, p2)| p1.dist_from_origin().cmp(&p2.dist_from_origin()))
+        .ok_or_else(|| anyhow!("Minimum dist not found"))?;
+    Ok(idx)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut particles = parse_input(input)?;
+    simulate(&mut particles, true);
+    Ok(particles.len())
+}
+
+fn simulate(particles: &mut Vec<Particle>, collisions: bool) {
+    for _ in 0..10_000 {
+        if collisions {
+            let mut positions = HashSet::new();
+            let mut dupes = HashSet::new();
+
+            for p in particles.iter() {
+                if !positions.insert(p.position) {
+                    dupes.insert(p.position);
+                }
+            }
+
+            particles.retain(|p| !dupes.contains(&p.position));
+        }
+
+        particles.iter_mut().for_each(Particle::update);
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Particle>> {
+    input
+        .lines()
+        .map(|l| l.parse::<Particle>())
+        .collect::<Result<Vec<_>, Error>>()
+}
+
+#[derive(Clone, Debug)]
+pub struct Particle {
+    position: (isize, isize, isize),
+    velocity: (isize, isize, isize),
+    acceleration: (isize, isize, isize),
+}
+
+impl Particle {
+    fn update(&mut self) {
+        self.velocity.0 += self.acceleration.0;
+        self.velocity.1 += self.acceleration.1;
+        self.velocity.2 += self.acceleration.2;
+
+        self.position.0 += self.velocity.0;
+        self.position.1 += self.velocity.1;
+        self.position.2 += self.velocity.2;
+    }
+
+    fn dist_from_origin(&self) -> usize {
+        self.position.0.unsigned_abs()
+            + self.position.1.unsigned_abs()
+            + self.position.2.unsigned_abs()
+    }
+}
+
+impl FromStr for Particle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.split_ascii_whitespace().collect();
+        Ok(Particle {
+            position: parse_vector(tokens[0])?,
+            velocity: parse_vector(tokens[1])?,
+            acceleration: parse_vector(tokens[2])?,
+        })
+    }
+}
+
+fn parse_vector(s: &str) -> Result<(isize, isize, isize)> {
+    let left = s.find('<').ok_or_else(|| anyhow!("< not found"))?;
+    let right = s.find('>').ok_or_else(|| anyhow!("> not found"))?;
+    let vals: Vec<_> = s[(left + 1)..right].split(',').collect();
+    if vals.len() != 3 {
+        bail!("Expected exactly 3 values but got {}", vals.len());
+    }
+    let parsed: Vec<isize> = vals
+        .iter()
+        .map(|v| v.trim().parse::<isize>().map_err(|e| anyhow!(e)))
+        .collect::<Result<_, _>>()?;
+    Ok((parsed[0], parsed[1], parsed[2]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let closest = "\
+p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
+p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>
+";
+        assert_eq!(part1(closest).unwrap(), 0);
+
+        let collisions = "\
+p=<-6,0,0>, v=<3,0,0>, a=<0,0,0>
+p=<-4,0,0>, v=<2,0,0>, a=<0,0,0>
+p=<-2,0,0>, v=<1,0,0>, a=<0,0,0>
+p=<3,0,0>, v=<-1,0,0>, a=<0,0,0>
+";
+        assert_eq!(part2(collisions).unwrap(), 1);
+    }
+}
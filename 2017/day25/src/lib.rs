@@ -0,0 +1,184 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.run_til_diag())
+}
+
+#[derive(Default, Debug)]
+pub struct TuringMachine {
+    tape: Vec<bool>,
+    state: char,
+    cursor: usize,
+    states: [State; 26],
+    diag_steps: usize,
+}
+
+const TAPE_LEN: usize = 20_000_000;
+
+impl TuringMachine {
+    /// A hack: place us in the middle of a tape big enough to never require resizing on writes.
+    fn new(begin_state: char, states: [State; 26], diag_steps: usize) -> Self {
+        Self {
+            state: begin_state,
+            tape: vec![false; TAPE_LEN],
+            cursor: TAPE_LEN / 2,
+            states,
+            diag_steps,
+        }
+    }
+
+    fn run_til_diag(&mut self) -> usize {
+        for _i in 0..self.diag_steps {
+            self.states[self.state as usize - 'A' as usize]
+                // I'm too lazy to refactor this but the clone is cheap
+                .clone()
+                .apply(self);
+        }
+        self.tape.iter().filter(|t| **t).count()
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug)]
+struct State {
+    zero_rule: Rule,
+    one_rule: Rule,
+}
+
+impl State {
+    fn apply(&self, machine: &mut TuringMachine) {
+        if machine.tape[machine.cursor] {
+            self.one_rule.apply(machine);
+        } else {
+            self.zero_rule.apply(machine);
+        }
+    }
+}
+
+#[derive(Copy, Clone, Default, Debug)]
+struct Rule {
+    val: bool,
+    movement: isize,
+    next: char,
+}
+
+impl Rule {
+    fn apply(&self, machine: &mut TuringMachine) {
+        machine.tape[machine.cursor] = self.val;
+
+        if self.movement == -1 {
+            machine.cursor -= 1
+        } else {
+            machine.cursor += 1
+        }
+
+        machine.state = self.next;
+    }
+}
+
+// Ignore this abhorrent parsing code, please...
+pub fn parse_input(input: &str) -> Result<TuringMachine> {
+    let sections = aoc_common::sections(input);
+    let (header, state_sections) = sections
+        .split_first()
+        .ok_or_else(|| anyhow!("Missing begin state"))?;
+
+    let mut lines = header.lines().map(str::trim);
+    let begin_state = parse_last_char(lines.next().ok_or_else(|| anyhow!("Missing begin state"))?)?;
+    let diag_line = lines
+        .next()
+        .ok_or_else(|| anyhow!("Missing diagnostic line"))?;
+    let diag_steps: usize = diag_line
+        .split_ascii_whitespace()
+        .nth(5)
+        .ok_or_else(|| anyhow!("Missing diagnostic steps"))?
+        .parse()?;
+
+    let mut states = [State::default(); 26];
+    for section in state_sections {
+        let mut lines = section.lines().map(str::trim);
+        let state = parse_last_char(lines.next().ok_or_else(|| anyhow!("Missing state"))?)?;
+        let zero_rule = parse_rule(&mut lines)?;
+        let one_rule = parse_rule(&mut lines)?;
+        let idx = state as usize - 'A' as usize;
+        states[idx].zero_rule = zero_rule;
+        states[idx].one_rule = one_rule;
+    }
+
+    Ok(TuringMachine::new(begin_state, states, diag_steps))
+}
+
+fn parse_rule<'a, I>(lines: &mut I) -> Result<Rule>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let _marker = lines.next().ok_or_else(|| anyhow!("Missing rule marker"))?;
+    let val = parse_last_char(lines.next().ok_or_else(|| anyhow!("Missing value line"))?)? == '1';
+    let movement = if parse_last_token(
+        lines
+            .next()
+            .ok_or_else(|| anyhow!("Missing movement line"))?,
+    )? == "left."
+    {
+        -1
+    } else {
+        1
+    };
+    let next = parse_last_char(
+        lines
+            .next()
+            .ok_or_else(|| anyhow!("Missing next state line"))?,
+    )?;
+    Ok(Rule {
+        val,
+        movement,
+        next,
+    })
+}
+
+/// Parse first char of last token
+fn parse_last_char(line: &str) -> Result<char> {
+    parse_last_token(line)
+        .and_then(|l| l.chars().next().ok_or_else(|| anyhow!("Can't parse state")))
+}
+
+/// Parse the last token in a line
+fn parse_last_token(line: &str) -> Result<&str> {
+    line.split_ascii_whitespace()
+        .last()
+        .ok_or_else(|| anyhow!("No last token found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 3);
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(input.lines().filter(|l| l.is_valid()).count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(input.lines().filter(|l| l.is_anagram_valid()).count())
+}
+
+trait Passphrase {
+    fn is_valid(&self) -> bool;
+    fn is_anagram_valid(&self) -> bool;
+}
+
+impl<T: AsRef<str>> Passphrase for T {
+    /// Check if all words in the passphrase can be uniquely inserted into a set.
+    fn is_valid(&self) -> bool {
+        let mut words = HashSet::new();
+        self.as_ref()
+            .split_ascii_whitespace()
+            .all(|w| words.insert(w))
+    }
+
+    /// Check if all words in the passphrase can be uniquely inserted into a set, representing each
+    /// word as the sorted list of its composite characters.
+    fn is_anagram_valid(&self) -> bool {
+        let mut words = HashSet::new();
+        self.as_ref()
+            .split_ascii_whitespace()
+            .map(|w| {
+                let mut chars: Vec<_> = w.chars().collect();
+                chars.sort();
+                chars
+            })
+            .all(|chars| words.insert(chars))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert!("aa bb cc dd ee".is_valid());
+        assert!(!"aa bb cc dd aa".is_valid());
+        assert!("aa bb cc dd aaa".is_valid());
+
+        assert!("abcde fghij".is_anagram_valid());
+        assert!(!"abcde xyz ecdab".is_anagram_valid());
+        assert!("a ab abc abd abf abj".is_anagram_valid());
+        assert!("iiii oiii ooii oooi oooo".is_anagram_valid());
+        assert!(!"oiii ioii iioi iiio".is_anagram_valid());
+    }
+}
@@ -0,0 +1,47 @@
+use anyhow::{Error, Result};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(find_exit(&parse_offsets(input)?, false))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(find_exit(&parse_offsets(input)?, true))
+}
+
+fn find_exit(offsets: &[isize], part2: bool) -> usize {
+    let mut offsets = offsets.to_vec();
+    let len = offsets.len() as isize;
+    let mut steps = 0;
+    let mut idx: isize = 0;
+    while idx >= 0 && idx < len {
+        let offset = &mut offsets[idx as usize];
+        idx += *offset;
+        if part2 && *offset >= 3 {
+            *offset -= 1;
+        } else {
+            *offset += 1;
+        }
+        steps += 1;
+    }
+    steps
+}
+
+fn parse_offsets(input: &str) -> Result<Vec<isize>> {
+    input
+        .lines()
+        .map(|l| l.trim().parse().map_err(Error::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0\n3\n0\n1\n-3\n";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 5);
+        assert_eq!(part2(EXAMPLE).unwrap(), 10);
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{Error, Result, anyhow};
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(redistribute(parse_memory_banks(input)?).0)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(redistribute(parse_memory_banks(input)?).1)
+}
+
+// -> (redistribution cycles until a repeat, size of the loop)
+fn redistribute(mut banks: Vec<usize>) -> (usize, usize) {
+    let mut seen = HashMap::new();
+    for step in 0usize.. {
+        let (mut idx, blocks) = max_bank(&banks);
+        banks[idx] = 0;
+        idx = (idx + 1) % banks.len();
+        for _ in 0..blocks {
+            banks[idx] += 1;
+            idx = (idx + 1) % banks.len();
+        }
+
+        if let Some(&s) = seen.get(&banks) {
+            return (step + 1, step - s);
+        }
+        seen.insert(banks.clone(), step);
+    }
+    unreachable!("Cycle not detected");
+}
+
+fn max_bank(banks: &[usize]) -> (usize, usize) {
+    let mut max = (0, banks[0]);
+    for (i, &blocks) in banks.iter().enumerate().skip(1) {
+        if blocks > max.1 {
+            max = (i, blocks);
+        }
+    }
+    max
+}
+
+fn parse_memory_banks(input: &str) -> Result<Vec<usize>> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| anyhow!("No memory banks given"))?;
+    line.split_ascii_whitespace()
+        .map(|bank| bank.parse().map_err(Error::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("0 2 7 0").unwrap(), 5);
+        assert_eq!(part2("0 2 7 0").unwrap(), 4);
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+use std::mem;
+
+pub fn part1(input: &str) -> Result<String> {
+    let graph = parse_input(input)?;
+    graph
+        .find_root()
+        .cloned()
+        .ok_or(anyhow!("Root node not found"))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut graph = parse_input(input)?;
+    let bottom_prog = graph
+        .find_root()
+        .ok_or(anyhow!("Root node not found"))?
+        .clone();
+    let mut corrected = None;
+    rebalance_dfs(&mut graph, &bottom_prog, &mut corrected);
+    corrected.ok_or(anyhow!("Tower is already balanced"))
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    programs: HashMap<String, usize>,
+    holding: HashMap<String, Vec<String>>,
+}
+
+impl Graph {
+    fn find_root(&self) -> Option<&String> {
+        self.programs
+            .keys()
+            .find(|p| self.holding.values().all(|held| !held.contains(p)))
+    }
+}
+
+fn rebalance_dfs(graph: &mut Graph, program: &str, corrected: &mut Option<usize>) -> usize {
+    if !graph.holding.contains_key(program) {
+        return graph.programs[program];
+    }
+
+    // Calculate map of weight -> held child programs at that weight. One map entry implies balance.
+    // If unbalanced/off, adjust the weight of the off balanced program (map entry with one child).
+    let mut weights_to_programs = HashMap::new();
+    let children = graph.holding[program].clone();
+    for child in &children {
+        weights_to_programs
+            .entry(rebalance_dfs(graph, child, corrected))
+            .and_modify(|programs: &mut Vec<&String>| programs.push(child))
+            .or_insert(vec![child]);
+    }
+    if weights_to_programs.keys().count() == 1 {
+        let (&weight, programs) = weights_to_programs.iter().next().unwrap();
+        weight * programs.len() + graph.programs[program]
+    } else {
+        let mut weights = weights_to_programs.iter().take(2);
+        let mut common = weights.next().unwrap();
+        let mut off = weights.next().unwrap();
+        if off.1.len() > common.1.len() {
+            mem::swap(&mut common, &mut off);
+        }
+        let diff = common.0.abs_diff(*off.0);
+        let program_weight = graph.programs.get_mut(off.1[0]).unwrap();
+        if off.0 < common.0 {
+            *program_weight += diff
+        } else {
+            *program_weight -= diff
+        }
+        corrected.get_or_insert(*program_weight);
+        *common.0 * (common.1.len() + 1) + graph.programs[program]
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Graph> {
+    let mut programs = HashMap::new();
+    let mut holding = HashMap::new();
+    for line in input.lines() {
+        let tokens: Vec<_> = line.split("->").collect();
+        let program_tokens: Vec<_> = tokens[0].split_ascii_whitespace().collect();
+        let holding_program = program_tokens[0];
+        let weight = program_tokens[1];
+        programs.insert(
+            holding_program.to_string(),
+            weight[1..weight.len() - 1].parse()?,
+        );
+
+        if tokens.len() > 1 {
+            for held in tokens[1].split_ascii_whitespace() {
+                let held = held.strip_suffix(",").unwrap_or(held);
+                holding
+                    .entry(holding_program.to_string())
+                    .and_modify(|held_programs: &mut Vec<String>| {
+                        held_programs.push(held.to_string())
+                    })
+                    .or_insert(vec![held.to_string()]);
+            }
+        }
+    }
+    Ok(Graph { programs, holding })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), "tknk");
+        assert_eq!(part2(EXAMPLE).unwrap(), 60);
+    }
+}
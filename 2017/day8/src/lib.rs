@@ -0,0 +1,87 @@
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<isize> {
+    Ok(execute(input)?.0.largest_val())
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    Ok(execute(input)?.1)
+}
+
+// -> (final registers, largest value held at any point)
+fn execute(input: &str) -> Result<(Registers, isize)> {
+    let mut registers = Registers::default();
+    let mut max = isize::MIN;
+    for line in input.lines() {
+        let tokens: Vec<_> = line.split_ascii_whitespace().collect();
+        let cond_reg = registers.get(tokens[4]);
+        let target_register = registers.get_mut(tokens[0]);
+        let op = tokens[1];
+        let adj_value: isize = tokens[2].parse()?;
+        let cond = tokens[5];
+        let cond_val: isize = tokens[6].parse()?;
+
+        let matches = match cond {
+            ">" => cond_reg > cond_val,
+            ">=" => cond_reg >= cond_val,
+            "<" => cond_reg < cond_val,
+            "<=" => cond_reg <= cond_val,
+            "==" => cond_reg == cond_val,
+            "!=" => cond_reg != cond_val,
+            _ => return Err(anyhow!("Unrecognized condition: {cond}")),
+        };
+        if matches {
+            match op {
+                "inc" => *target_register += adj_value,
+                "dec" => *target_register -= adj_value,
+                _ => return Err(anyhow!("Unrecognized op: {op}")),
+            }
+        }
+        max = max.max(registers.largest_val());
+    }
+    Ok((registers, max))
+}
+
+#[derive(Debug, Default)]
+struct Registers {
+    registers: HashMap<String, isize>,
+}
+
+impl Registers {
+    fn get_mut(&mut self, key: &str) -> &mut isize {
+        if !self.registers.contains_key(key) {
+            self.registers.insert(key.to_string(), 0);
+        }
+        self.registers.get_mut(key).unwrap()
+    }
+
+    fn get(&mut self, key: &str) -> isize {
+        if !self.registers.contains_key(key) {
+            self.registers.insert(key.to_string(), 0);
+        }
+        self.registers[key]
+    }
+
+    fn largest_val(&self) -> isize {
+        *self.registers.values().max().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 1);
+        assert_eq!(part2(EXAMPLE).unwrap(), 10);
+    }
+}
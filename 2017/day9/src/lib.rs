@@ -0,0 +1,71 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(score(&parse_stream(input)).0)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(score(&parse_stream(input)).1)
+}
+
+fn parse_stream(input: &str) -> Vec<char> {
+    input.trim().chars().collect()
+}
+
+// -> (total group score, non-canceled garbage characters)
+fn score(stream: &[char]) -> (usize, usize) {
+    let mut score = 0;
+    let mut garbage_chars = 0;
+    let mut groups = 0;
+    let mut idx = 0;
+    let mut in_garbage = false;
+    while idx < stream.len() {
+        match stream[idx] {
+            '{' if !in_garbage => groups += 1,
+            '}' if !in_garbage => {
+                score += groups;
+                groups -= 1;
+            }
+            '!' => idx += 1,
+            '<' if !in_garbage => in_garbage = true,
+            '>' if in_garbage => in_garbage = false,
+            _ if in_garbage => garbage_chars += 1,
+            _ => {}
+        }
+        idx += 1;
+    }
+    (score, garbage_chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        for (stream, expected) in [
+            ("{}", 1),
+            ("{{{}}}", 6),
+            ("{{},{}}", 5),
+            ("{{{},{},{{}}}}", 16),
+            ("{<a>,<a>,<a>,<a>}", 1),
+            ("{{<ab>},{<ab>},{<ab>},{<ab>}}", 9),
+            ("{{<!!>},{<!!>},{<!!>},{<!!>}}", 9),
+            ("{{<a!>},{<a!>},{<a!>},{<ab>}}", 3),
+        ] {
+            assert_eq!(part1(stream).unwrap(), expected, "{stream}");
+        }
+
+        for (stream, expected) in [
+            ("<>", 0),
+            ("<random characters>", 17),
+            ("<<<<>", 3),
+            ("<{!>}>", 2),
+            ("<!!>", 0),
+            ("<!!!>>", 0),
+            (r#"<{o"i!a,<{i<a>"#, 10),
+        ] {
+            assert_eq!(part2(stream).unwrap(), expected, "{stream}");
+        }
+    }
+}
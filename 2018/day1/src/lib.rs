@@ -0,0 +1,44 @@
+use anyhow::{Error, Result};
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<isize> {
+    Ok(parse_input(input)?.iter().sum())
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let frequencies = parse_input(input)?;
+    let mut seen = HashSet::new();
+    let mut frequency = 0;
+    for change in frequencies.iter().cycle() {
+        if !seen.insert(frequency) {
+            break;
+        }
+        frequency += change;
+    }
+    Ok(frequency)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<isize>> {
+    input
+        .lines()
+        .map(|l| l.trim_end().parse::<isize>().map_err(Error::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("+1\n-2\n+3\n+1").unwrap(), 3);
+        assert_eq!(part1("+1\n+1\n-2").unwrap(), 0);
+        assert_eq!(part1("-1\n-2\n-3").unwrap(), -6);
+
+        assert_eq!(part2("+1\n-2\n+3\n+1").unwrap(), 2);
+        assert_eq!(part2("+1\n-1").unwrap(), 0);
+        assert_eq!(part2("+3\n+3\n+4\n-2\n-4").unwrap(), 10);
+        assert_eq!(part2("-6\n+3\n+8\n+5\n-6").unwrap(), 5);
+        assert_eq!(part2("+7\n+7\n-2\n-7\n-4").unwrap(), 14);
+    }
+}
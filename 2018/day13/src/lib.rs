@@ -0,0 +1,181 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<String> {
+    let (x, y) = parse_input(input)
+        .run_until_one()
+        .0
+        .ok_or_else(|| anyhow!("No carts crashed"))?;
+    Ok(format!("{x},{y}"))
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    let (x, y) = parse_input(input).run_until_one().1;
+    Ok(format!("{x},{y}"))
+}
+
+pub fn parse_input(input: &str) -> Mine {
+    let mut tracks = Vec::new();
+    let mut carts = Vec::new();
+
+    for (y, line) in input.lines().enumerate() {
+        let mut row = Vec::new();
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                '^' | 'v' | '>' | '<' => {
+                    carts.push(Cart {
+                        x,
+                        y,
+                        dir: c,
+                        turn: Turn::Left,
+                        dead: false,
+                    });
+                    row.push(match c {
+                        '^' | 'v' => '|',
+                        _ => '-',
+                    });
+                }
+                _ => row.push(c),
+            }
+        }
+        tracks.push(row);
+    }
+
+    Mine { tracks, carts }
+}
+
+#[derive(Debug, Clone)]
+struct Cart {
+    x: usize,
+    y: usize,
+    dir: char,
+    turn: Turn,
+    dead: bool,
+}
+
+#[derive(Debug)]
+pub struct Mine {
+    carts: Vec<Cart>,
+    tracks: Vec<Vec<char>>,
+}
+
+impl Mine {
+    // -> (location of the first crash, location of the last cart standing)
+    fn run_until_one(&mut self) -> (Option<(usize, usize)>, (usize, usize)) {
+        let mut first_crash = None;
+
+        while self.carts.len() != 1 {
+            self.carts.sort_by_key(|c| (c.y, c.x));
+
+            for i in 0..self.carts.len() {
+                if self.carts[i].dead {
+                    continue;
+                }
+
+                match self.carts[i].dir {
+                    '^' => self.carts[i].y -= 1,
+                    'v' => self.carts[i].y += 1,
+                    '<' => self.carts[i].x -= 1,
+                    '>' => self.carts[i].x += 1,
+                    _ => {}
+                }
+
+                let (nx, ny) = (self.carts[i].x, self.carts[i].y);
+
+                for j in 0..self.carts.len() {
+                    if i != j
+                        && !self.carts[j].dead
+                        && self.carts[j].x == nx
+                        && self.carts[j].y == ny
+                    {
+                        self.carts[i].dead = true;
+                        self.carts[j].dead = true;
+                        first_crash.get_or_insert((nx, ny));
+                        break;
+                    }
+                }
+
+                if self.carts[i].dead {
+                    continue;
+                }
+
+                let track = self.tracks[ny][nx];
+                let dir = self.carts[i].dir;
+
+                match (track, dir) {
+                    ('/', '^') => self.carts[i].dir = '>',
+                    ('/', '<') => self.carts[i].dir = 'v',
+                    ('/', 'v') => self.carts[i].dir = '<',
+                    ('/', '>') => self.carts[i].dir = '^',
+                    ('\\', '^') => self.carts[i].dir = '<',
+                    ('\\', '>') => self.carts[i].dir = 'v',
+                    ('\\', 'v') => self.carts[i].dir = '>',
+                    ('\\', '<') => self.carts[i].dir = '^',
+                    ('+', _) => {
+                        self.carts[i].dir = self.carts[i].turn.as_dir(dir);
+                        self.carts[i].turn = self.carts[i].turn.next();
+                    }
+                    _ => {}
+                }
+            }
+
+            self.carts.retain(|c| !c.dead);
+        }
+
+        if let Some(last) = self.carts.first() {
+            (first_crash, (last.x, last.y))
+        } else {
+            panic!("Expected one cart!")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Turn {
+    Left,
+    Straight,
+    Right,
+}
+
+impl Turn {
+    fn next(&self) -> Self {
+        match self {
+            Turn::Left => Self::Straight,
+            Turn::Straight => Self::Right,
+            Turn::Right => Self::Left,
+        }
+    }
+
+    fn as_dir(&self, dir: char) -> char {
+        match (self, dir) {
+            (Self::Left, '^') => '<',
+            (Self::Left, '>') => '^',
+            (Self::Left, 'v') => '>',
+            (Self::Left, '<') => 'v',
+            (Self::Right, '^') => '>',
+            (Self::Right, '>') => 'v',
+            (Self::Right, 'v') => '<',
+            (Self::Right, '<') => '^',
+            _ => dir,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r"/>-<\  
+|   |  
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), "2,0");
+        assert_eq!(part2(EXAMPLE).unwrap(), "6,4");
+    }
+}
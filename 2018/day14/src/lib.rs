@@ -0,0 +1,82 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<String> {
+    let num_recipes = input.trim().parse::<usize>()?;
+
+    let mut scoreboard = Scoreboard::default();
+    while scoreboard.recipes.len() < num_recipes + 10 {
+        scoreboard.step();
+    }
+
+    Ok(scoreboard.recipes[num_recipes..num_recipes + 10]
+        .iter()
+        .map(|d| d.to_string())
+        .collect())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let digits: Vec<_> = input
+        .trim()
+        .chars()
+        .map(|d| d.to_digit(10).unwrap() as usize)
+        .collect();
+
+    let mut scoreboard = Scoreboard::default();
+    loop {
+        scoreboard.step();
+        // A step can add two recipes, so the digits may end just before the last one
+        let recipes = &scoreboard.recipes;
+        for end in [recipes.len() - 1, recipes.len()] {
+            if recipes[..end].ends_with(&digits) {
+                return Ok(end - digits.len());
+            }
+        }
+    }
+}
+
+struct Scoreboard {
+    recipes: Vec<usize>,
+    e1: usize,
+    e2: usize,
+}
+
+impl Default for Scoreboard {
+    fn default() -> Self {
+        Self {
+            recipes: vec![3, 7],
+            e1: 0,
+            e2: 1,
+        }
+    }
+}
+
+impl Scoreboard {
+    fn step(&mut self) {
+        let sum = self.recipes[self.e1] + self.recipes[self.e2];
+        if sum >= 10 {
+            self.recipes.push(sum / 10);
+        }
+        self.recipes.push(sum % 10);
+
+        self.e1 = (self.e1 + self.recipes[self.e1] + 1) % self.recipes.len();
+        self.e2 = (self.e2 + self.recipes[self.e2] + 1) % self.recipes.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("9").unwrap(), "5158916779");
+        assert_eq!(part1("5").unwrap(), "0124515891");
+        assert_eq!(part1("18").unwrap(), "9251071085");
+        assert_eq!(part1("2018").unwrap(), "5941429882");
+
+        assert_eq!(part2("51589").unwrap(), 9);
+        assert_eq!(part2("01245").unwrap(), 5);
+        assert_eq!(part2("92510").unwrap(), 18);
+        assert_eq!(part2("59414").unwrap(), 2018);
+    }
+}
@@ -1,8 +1,8 @@
 use anyhow::Result;
 
 fn main() -> Result<()> {
-    // Needed help with reverse engineering...
-    println!("Part 1: {}", 13270004);
-    println!("Part 2: {}", 12879142);
+    aoc_common::print_answer(1, aoc2018_day21::part1("")?);
+    aoc_common::print_answer(2, aoc2018_day21::part2("")?);
+
     Ok(())
 }
@@ -0,0 +1,137 @@
+use anyhow::Result;
+use text_io::try_scan;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let points = parse_input(input)?;
+    let n = points.len();
+    let mut uf = UnionFind::new(n);
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if manhattan(&points[i], &points[j]) <= 3 {
+                uf.union(i, j);
+            }
+        }
+    }
+
+    Ok((0..n).filter(|&i| uf.find_tree_root(i) == i).count())
+}
+
+fn manhattan(a: &[isize; 4], b: &[isize; 4]) -> isize {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum()
+}
+
+struct UnionFind {
+    // tree[i] = parent node of i
+    tree: Vec<usize>,
+    // Tree depth guide to better flatten the constructed trees
+    rank: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        Self {
+            tree: (0..n).collect(),
+            rank: vec![0; n],
+        }
+    }
+
+    fn find_tree_root(&mut self, x: usize) -> usize {
+        if self.tree[x] != x {
+            self.tree[x] = self.find_tree_root(self.tree[x]);
+        }
+        self.tree[x]
+    }
+
+    fn union(&mut self, x: usize, y: usize) {
+        let root_x = self.find_tree_root(x);
+        let root_y = self.find_tree_root(y);
+
+        if root_x == root_y {
+            return;
+        }
+
+        // Extend the shortest branch
+        match self.rank[root_x].cmp(&self.rank[root_y]) {
+            std::cmp::Ordering::Less => self.tree[root_x] = root_y,
+            std::cmp::Ordering::Greater => self.tree[root_y] = root_x,
+            std::cmp::Ordering::Equal => {
+                self.tree[root_y] = root_x;
+                self.rank[root_x] += 1;
+            }
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<[isize; 4]>> {
+    input
+        .lines()
+        .map(|l| {
+            let (a, b, c, d): (isize, isize, isize, isize);
+            try_scan!(l.trim().bytes() => "{},{},{},{}", a, b, c, d);
+            Ok([a, b, c, d])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let points = "\
+ 0,0,0,0
+ 3,0,0,0
+ 0,3,0,0
+ 0,0,3,0
+ 0,0,0,3
+ 0,0,0,6
+ 9,0,0,0
+12,0,0,0
+";
+        assert_eq!(part1(points).unwrap(), 2);
+
+        let points = "\
+-1,2,2,0
+0,0,2,-2
+0,0,0,-2
+-1,2,0,0
+-2,-2,-2,2
+3,0,2,-1
+-1,3,2,2
+-1,0,-1,0
+0,2,1,-2
+3,0,0,0
+";
+        assert_eq!(part1(points).unwrap(), 4);
+
+        let points = "\
+1,-1,0,1
+2,0,-1,0
+3,2,-1,0
+0,0,3,1
+0,0,-1,-1
+2,3,-2,0
+-2,2,0,0
+2,-2,0,-1
+1,-1,0,-1
+3,2,0,2
+";
+        assert_eq!(part1(points).unwrap(), 3);
+
+        let points = "\
+1,-1,-1,-2
+-2,-2,0,1
+0,2,1,3
+-2,3,-2,1
+0,2,3,-2
+-1,-1,1,-2
+0,-2,-1,0
+-2,2,3,-1
+1,2,2,0
+-1,-2,0,-2
+";
+        assert_eq!(part1(points).unwrap(), 8);
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input).fully_react().len())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let polymer = parse_input(input);
+    Ok(polymer
+        .unique_units()
+        .iter()
+        .map(|&r| polymer.removed(r).fully_react().len())
+        .min()
+        .unwrap())
+}
+
+pub fn parse_input(input: &str) -> Polymer {
+    Polymer {
+        units: input.trim().chars().collect(),
+    }
+}
+
+pub struct Polymer {
+    units: Vec<char>,
+}
+
+impl Polymer {
+    /// Return the fully reacted version of this Polymer
+    fn fully_react(&self) -> Self {
+        // "Collapse" from left to right with a stack so we only need one pass
+        let mut stack = Vec::new();
+        self.units.iter().for_each(|&u| {
+            // In ASCII table, lower and upper case versions of each letter have diff of 32
+            if stack.is_empty() || (*stack.last().unwrap() as u32) ^ (u as u32) != 32 {
+                stack.push(u);
+            } else {
+                stack.pop();
+            }
+        });
+
+        Self { units: stack }
+    }
+
+    fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    fn unique_units(&self) -> HashSet<char> {
+        self.units.iter().map(|u| u.to_ascii_lowercase()).collect()
+    }
+
+    /// Return the version of this polymer with the given unit removed
+    fn removed(&self, unit: char) -> Self {
+        let mut units = self.units.clone();
+        units.retain(|u| !u.eq_ignore_ascii_case(&unit));
+        Self { units }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("aA").unwrap(), 0);
+        assert_eq!(part1("abBA").unwrap(), 0);
+        assert_eq!(part1("abAB").unwrap(), 4);
+        assert_eq!(part1("dabAcCaCBAcCcaDA").unwrap(), 10);
+        assert_eq!(part2("dabAcCaCBAcCcaDA").unwrap(), 4);
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::{Error, Result};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.to_node(&mut 0).metadata_sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.to_node(&mut 0).value())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    input
+        .trim()
+        .split_ascii_whitespace()
+        .map(|n| n.parse().map_err(Error::from))
+        .collect()
+}
+
+struct Node {
+    children: Vec<Node>,
+    metadata: Vec<usize>,
+}
+
+trait ToNode {
+    fn to_node(&self, idx: &mut usize) -> Node;
+}
+
+impl ToNode for Vec<usize> {
+    fn to_node(&self, idx: &mut usize) -> Node {
+        let (children, metadata) = (self[*idx], self[*idx + 1]);
+        *idx += 2;
+
+        let children: Vec<_> = (0..children).map(|_| self.to_node(idx)).collect();
+
+        let metadata: Vec<_> = (0..metadata).map(|i| self[*idx + i]).collect();
+        *idx += metadata.len();
+
+        Node { children, metadata }
+    }
+}
+
+impl Node {
+    fn metadata_sum(&self) -> usize {
+        self.metadata.iter().sum::<usize>()
+            + self.children.iter().map(Node::metadata_sum).sum::<usize>()
+    }
+
+    fn value(&self) -> usize {
+        if self.children.is_empty() {
+            self.metadata.iter().sum()
+        } else {
+            self.metadata
+                .iter()
+                // Convert from 1-based index to 0-based
+                .filter(|&&m| m > 0)
+                .filter_map(|&m| self.children.get(m - 1))
+                .map(Node::value)
+                .sum()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 138);
+        assert_eq!(part2(EXAMPLE).unwrap(), 66);
+    }
+}
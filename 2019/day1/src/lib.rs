@@ -0,0 +1,42 @@
+use anyhow::{Error, Result};
+use std::iter::successors;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.iter().map(|mass| mass / 3 - 2).sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?
+        .iter()
+        .map(|mass| {
+            successors(Some(*mass), |m| (m / 3).checked_sub(2))
+                // Skip successors first (*mass)
+                .skip(1)
+                .sum::<usize>()
+        })
+        .sum())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    input
+        .lines()
+        .map(|l| l.parse().map_err(Error::from))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("12").unwrap(), 2);
+        assert_eq!(part1("14").unwrap(), 2);
+        assert_eq!(part1("1969").unwrap(), 654);
+        assert_eq!(part1("100756").unwrap(), 33583);
+
+        assert_eq!(part2("14").unwrap(), 2);
+        assert_eq!(part2("1969").unwrap(), 966);
+        assert_eq!(part2("100756").unwrap(), 50346);
+    }
+}
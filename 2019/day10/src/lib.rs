@@ -0,0 +1,247 @@
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+
+pub const NTH_DESTROYED: usize = 200;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input).best_monitor_location().1)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    part2_with(input, NTH_DESTROYED)
+}
+
+pub fn part2_with(input: &str, n: usize) -> Result<isize> {
+    let mut asteroid_field = parse_input(input);
+
+    // Handle samples where station location is given rather than calculated
+    let station = match asteroid_field.station {
+        Some(station) => station,
+        None => asteroid_field.best_monitor_location().0,
+    };
+    asteroid_field.station = Some(station);
+
+    let nth_destroyed = asteroid_field.nth_destroyed(n);
+    Ok(nth_destroyed.x * 100 + nth_destroyed.y)
+}
+
+pub fn parse_input(input: &str) -> AsteroidField {
+    let mut asteroids = HashSet::new();
+    let mut max = Coords { x: 0, y: 0 };
+    let mut station = None;
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.trim().chars().enumerate() {
+            let pos = Coords {
+                x: x as isize,
+                y: y as isize,
+            };
+            if c == '#' {
+                asteroids.insert(pos);
+            }
+            if c == 'X' {
+                station = Some(pos);
+                asteroids.insert(pos);
+            }
+            max = pos;
+        }
+    }
+    AsteroidField {
+        asteroids,
+        max,
+        station,
+    }
+}
+
+#[derive(Debug)]
+pub struct AsteroidField {
+    asteroids: HashSet<Coords>,
+    max: Coords,
+    station: Option<Coords>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Coords {
+    x: isize,
+    y: isize,
+}
+
+impl AsteroidField {
+    fn best_monitor_location(&self) -> (Coords, usize) {
+        let mut viewable: HashMap<Coords, HashSet<Coords>> = HashMap::new();
+        for &a in &self.asteroids {
+            viewable.insert(
+                a,
+                self.asteroids
+                    .difference(&HashSet::from([a]))
+                    .copied()
+                    .collect(),
+            );
+        }
+
+        for a1 in &self.asteroids {
+            for a2 in &self.asteroids {
+                if a1 == a2 {
+                    continue;
+                }
+
+                let diff = Coords {
+                    x: a2.x - a1.x,
+                    y: a2.y - a1.y,
+                };
+                let gcd = gcd(diff.x, diff.y);
+                let unit_dir_step = Coords {
+                    x: diff.x / gcd,
+                    y: diff.y / gcd,
+                };
+
+                for i in 1.. {
+                    let behind = Coords {
+                        x: a2.x + unit_dir_step.x * i,
+                        y: a2.y + unit_dir_step.y * i,
+                    };
+                    if behind.x > self.max.x
+                        || behind.y > self.max.y
+                        || behind.x < 0
+                        || behind.y < 0
+                    {
+                        break;
+                    }
+                    viewable.get_mut(a1).unwrap().remove(&behind);
+                }
+            }
+        }
+
+        let (coords, viewable) = viewable.iter().max_by_key(|(_coords, v)| v.len()).unwrap();
+        (*coords, viewable.len())
+    }
+
+    fn nth_destroyed(&mut self, n: usize) -> Coords {
+        let mut vaporized = 0;
+        let station = self.station.unwrap();
+        self.asteroids.remove(&station);
+        loop {
+            let mut viewable = self.viewable(&station);
+            viewable.sort_by(|a, b| {
+                clockwise_angle(station, *a)
+                    .partial_cmp(&clockwise_angle(station, *b))
+                    .unwrap()
+            });
+            for v in viewable {
+                self.asteroids.remove(&v);
+                vaporized += 1;
+                if vaporized == n {
+                    return v;
+                }
+            }
+        }
+    }
+
+    fn viewable(&self, station: &Coords) -> Vec<Coords> {
+        // Unit dir -> (closest asteroid dist^2 and coords)
+        let mut nearest: HashMap<Coords, (isize, Coords)> = HashMap::new();
+
+        for &a in &self.asteroids {
+            if &a == station {
+                continue;
+            }
+
+            let dx = a.x - station.x;
+            let dy = a.y - station.y;
+            let gcd = gcd(dx, dy);
+            let unit_dir = Coords {
+                x: dx / gcd,
+                y: dy / gcd,
+            };
+            // Distance but squared
+            let dist = dx * dx + dy * dy;
+
+            match nearest.get(&unit_dir) {
+                Some((best, _asteroid_coords)) if *best <= dist => {}
+                _ => {
+                    nearest.insert(unit_dir, (dist, a));
+                }
+            }
+        }
+
+        nearest.into_values().map(|(_, c)| c).collect()
+    }
+}
+
+fn gcd(mut a: isize, mut b: isize) -> isize {
+    while b != 0 {
+        a %= b;
+        std::mem::swap(&mut a, &mut b);
+    }
+    a.abs()
+}
+
+fn clockwise_angle(station: Coords, target: Coords) -> f64 {
+    // positive = right
+    let dx = (target.x - station.x) as f64;
+    // positive = down
+    let dy = (target.y - station.y) as f64;
+
+    // gives clockwise from north (up)
+    let angle = f64::atan2(dx, -dy);
+
+    if angle < 0.0 {
+        angle + 2.0 * std::f64::consts::PI
+    } else {
+        angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LARGE_EXAMPLE: &str = "\
+.#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##
+";
+
+    #[test]
+    fn test_examples() {
+        let small = ".#..#\n.....\n#####\n....#\n...##";
+        assert_eq!(
+            parse_input(small).best_monitor_location(),
+            (Coords { x: 3, y: 4 }, 8)
+        );
+        assert_eq!(
+            parse_input(LARGE_EXAMPLE).best_monitor_location(),
+            (Coords { x: 11, y: 13 }, 210)
+        );
+
+        let station_given = "\
+.#....#####...#..
+##...##.#####..##
+##...#...#.#####.
+..#.....X...###..
+..#.#.....#....##";
+        assert_eq!(part2_with(station_given, 1).unwrap(), 801);
+        assert_eq!(part2_with(station_given, 9).unwrap(), 1501);
+
+        assert_eq!(part2_with(LARGE_EXAMPLE, 1).unwrap(), 1112);
+        assert_eq!(part2_with(LARGE_EXAMPLE, 20).unwrap(), 1600);
+        assert_eq!(part2_with(LARGE_EXAMPLE, 200).unwrap(), 802);
+        assert_eq!(part2_with(LARGE_EXAMPLE, 299).unwrap(), 1101);
+    }
+}
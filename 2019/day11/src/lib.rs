@@ -0,0 +1,122 @@
+use anyhow::Result;
+use intcode::{Heading, IntcodeComputer, Pos, Program, RunState, parse_program};
+use std::fmt::Write;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let panels = paint_hull(&parse_program(input)?, 0)?;
+    Ok(panels.iter().flatten().filter(|p| p.painted).count())
+}
+
+// Renders HJALJZFH
+pub fn part2(input: &str) -> Result<String> {
+    let panels = paint_hull(&parse_program(input)?, 1)?;
+    let mut rendered = String::new();
+    for row in panels.iter() {
+        // Trim the output to only rows containing white since message is white-on-black
+        if !row.iter().any(|p| p.color == 1) {
+            continue;
+        }
+
+        writeln!(rendered)?;
+        for p in row {
+            let c = match p.color {
+                1 => '#',
+                _ => '.',
+            };
+            write!(rendered, "{c}")?;
+        }
+    }
+
+    Ok(rendered)
+}
+
+// Adjust as needed :)
+const GRID_LENGTH: usize = 150;
+
+fn paint_hull(program: &Program, start_color: isize) -> Result<Vec<Vec<Panel>>> {
+    let mut grid = vec![vec![Panel::default(); GRID_LENGTH]; GRID_LENGTH];
+    let middle = (GRID_LENGTH / 2) as isize;
+    let mut robot = Robot {
+        computer: IntcodeComputer::new(program),
+        heading: Heading::North,
+        pos: Pos::new(middle, middle),
+    };
+    *robot.panel(&mut grid) = Panel {
+        painted: false,
+        color: start_color,
+    };
+
+    let mut output = Vec::new();
+    loop {
+        match robot.computer.run()? {
+            RunState::Halted => break,
+            RunState::ProducedOutput => {
+                output.push(robot.computer.output.pop_front().unwrap());
+
+                if let &[color, turn] = output.as_slice() {
+                    let panel = robot.panel(&mut grid);
+                    panel.color = color;
+                    panel.painted = true;
+
+                    robot.apply_movement(turn);
+
+                    output.clear();
+                }
+            }
+            RunState::AwaitingInput => {
+                let color = robot.panel(&mut grid).color;
+                robot.computer.input.push_back(color);
+            }
+        }
+    }
+
+    Ok(grid)
+}
+
+struct Robot {
+    computer: IntcodeComputer,
+    heading: Heading,
+    pos: Pos,
+}
+
+impl Robot {
+    // Turns left for 0 and right for 1, then moves forward
+    fn apply_movement(&mut self, turn: isize) {
+        self.heading = if turn == 0 {
+            self.heading.left()
+        } else {
+            self.heading.right()
+        };
+
+        let pos = self.pos.step(self.heading);
+        if !(0..GRID_LENGTH as isize).contains(&pos.x)
+            || !(0..GRID_LENGTH as isize).contains(&pos.y)
+        {
+            panic!("Out of bounds - adjust GRID_LENGTH");
+        }
+        self.pos = pos;
+    }
+
+    fn panel<'a>(&self, grid: &'a mut [Vec<Panel>]) -> &'a mut Panel {
+        &mut grid[self.pos.y as usize][self.pos.x as usize]
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+struct Panel {
+    painted: bool,
+    color: isize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        // Plays back the example's (color, turn) outputs, ignoring the camera
+        let program = "104,1,104,0,104,0,104,0,104,1,104,0,104,1,104,0,\
+104,0,104,1,104,1,104,0,104,1,104,0,99";
+        assert_eq!(part1(program).unwrap(), 6);
+    }
+}
@@ -0,0 +1,313 @@
+use anyhow::{Result, bail};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, terminal};
+use intcode::{IntcodeComputer, Program, RunState, parse_program};
+use std::fmt;
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut cabinet = ArcadeCabinet::new(&parse_program(input)?);
+    cabinet.run(ArcadeCabinet::follow_ball)?;
+    let tiles = cabinet.screen.iter().flatten();
+    Ok(tiles.filter(|&&tile| tile == Tile::Block).count())
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let mut cabinet = ArcadeCabinet::free_play(&parse_program(input)?);
+    cabinet.run(ArcadeCabinet::follow_ball)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl Tile {
+    fn glyph(self) -> char {
+        match self {
+            Tile::Empty => ' ',
+            Tile::Wall => '█',
+            Tile::Block => '▒',
+            Tile::Paddle => '▔',
+            Tile::Ball => '●',
+        }
+    }
+}
+
+impl TryFrom<isize> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(id: isize) -> Result<Self> {
+        Ok(match id {
+            0 => Tile::Empty,
+            1 => Tile::Wall,
+            2 => Tile::Block,
+            3 => Tile::Paddle,
+            4 => Tile::Ball,
+            _ => bail!("Unknown tile id {id}"),
+        })
+    }
+}
+
+pub struct ArcadeCabinet {
+    screen: Vec<Vec<Tile>>,
+    score: isize,
+    ball_x: usize,
+    paddle_x: usize,
+    computer: IntcodeComputer,
+}
+
+const SCREEN_WIDTH: usize = 40;
+
+impl ArcadeCabinet {
+    pub fn new(program: &Program) -> Self {
+        Self {
+            screen: vec![vec![Tile::Empty; SCREEN_WIDTH]; SCREEN_WIDTH],
+            score: 0,
+            ball_x: 0,
+            paddle_x: 0,
+            computer: IntcodeComputer::new(program),
+        }
+    }
+
+    /// A cabinet with quarters in it, so the game can be played rather than just drawn.
+    pub fn free_play(program: &Program) -> Self {
+        let mut cabinet = Self::new(program);
+        cabinet.computer.memory[0] = 2;
+        cabinet
+    }
+
+    /// For attaching a debugger (`intcode::Debugger::new(cabinet.computer_mut())`).
+    pub fn computer_mut(&mut self) -> &mut IntcodeComputer {
+        &mut self.computer
+    }
+
+    pub fn score(&self) -> isize {
+        self.score
+    }
+
+    /// Keeps the paddle under the ball: -1 to move left, 1 to move right or 0 to stay.
+    pub fn follow_ball(&self) -> Result<isize> {
+        Ok((self.ball_x as isize - self.paddle_x as isize).signum())
+    }
+
+    /// Runs the game to the end, returning the final score. Each time the game wants the joystick
+    /// the screen holds a whole frame, and `joystick` is handed the cabinet to decide where to
+    /// tilt it.
+    pub fn run(&mut self, mut joystick: impl FnMut(&Self) -> Result<isize>) -> Result<isize> {
+        let mut output = Vec::new();
+        loop {
+            match self.computer.run()? {
+                RunState::Halted => break,
+                RunState::ProducedOutput => {
+                    output.push(self.computer.output.pop_front().unwrap());
+
+                    if let &[x, y, tile_id] = output.as_slice() {
+                        if (-1, 0) == (x, y) {
+                            self.score = tile_id;
+                        } else {
+                            let (x, y) = (x as usize, y as usize);
+                            let tile = Tile::try_from(tile_id)?;
+                            if tile == Tile::Paddle {
+                                self.paddle_x = x;
+                            } else if tile == Tile::Ball {
+                                self.ball_x = x;
+                            }
+                            self.screen[y][x] = tile;
+                        }
+
+                        output.clear();
+                    }
+                }
+                RunState::AwaitingInput => {
+                    let input = joystick(self)?;
+                    self.computer.input.push_back(input);
+                }
+            }
+        }
+        Ok(self.score)
+    }
+}
+
+// The score, then the screen down to its last non-empty row
+impl fmt::Display for ArcadeCabinet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Score: {}", self.score)?;
+        let drawn = self
+            .screen
+            .iter()
+            .rposition(|row| row.iter().any(|&tile| tile != Tile::Empty));
+        for row in &self.screen[..drawn.map_or(0, |last| last + 1)] {
+            let row: String = row.iter().map(|tile| tile.glyph()).collect();
+            write!(f, "\n{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Shows the game in the terminal as it's played, a frame every `frame_time`, with the paddle
+/// following the ball or, if `manual`, steered with the arrow keys. Returns the final score.
+pub fn watch(input: &str, manual: bool, frame_time: Duration) -> Result<isize> {
+    let mut cabinet = ArcadeCabinet::free_play(&parse_program(input)?);
+    let mut terminal = Terminal::new(manual)?;
+    let score = cabinet.run(|cabinet| {
+        terminal.draw(&cabinet.to_string())?;
+        if manual {
+            read_joystick(frame_time)
+        } else {
+            thread::sleep(frame_time);
+            cabinet.follow_ball()
+        }
+    })?;
+    terminal.draw(&cabinet.to_string())?;
+    Ok(score)
+}
+
+/// Plays the game with the paddle following the ball, without drawing it, and writes every frame
+/// to `out` for `replay`. Returns the final score.
+pub fn record(input: &str, mut out: impl Write) -> Result<isize> {
+    let mut cabinet = ArcadeCabinet::free_play(&parse_program(input)?);
+    let score = cabinet.run(|cabinet| {
+        write!(out, "{cabinet}{FRAME_END}")?;
+        cabinet.follow_ball()
+    })?;
+    write!(out, "{cabinet}{FRAME_END}")?;
+    Ok(score)
+}
+
+/// Shows the frames written by `record`, one every `frame_time`.
+pub fn replay(recording: &str, frame_time: Duration) -> Result<()> {
+    let mut terminal = Terminal::new(false)?;
+    for frame in frames(recording) {
+        terminal.draw(frame)?;
+        thread::sleep(frame_time);
+    }
+    Ok(())
+}
+
+// Recorded frames each end with a form feed on a line of its own
+const FRAME_END: &str = "\n\u{c}\n";
+
+fn frames(recording: &str) -> impl Iterator<Item = &str> {
+    recording.split_terminator(FRAME_END)
+}
+
+// Waits out the frame for the arrow keys: left is -1, right is 1 and neither is 0
+fn read_joystick(frame_time: Duration) -> Result<isize> {
+    let deadline = Instant::now() + frame_time;
+    let mut tilt = 0;
+    while let Some(left) = deadline.checked_duration_since(Instant::now())
+        && event::poll(left)?
+    {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Left => tilt = -1,
+            KeyCode::Right => tilt = 1,
+            KeyCode::Char('q') | KeyCode::Esc => bail!("Quit"),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => bail!("Quit"),
+            _ => {}
+        }
+    }
+    Ok(tilt)
+}
+
+// Draws frames over each other on the alternate screen, and puts the terminal back when dropped
+struct Terminal {
+    out: io::Stdout,
+    raw: bool,
+}
+
+impl Terminal {
+    // Raw mode reads keys as they're pressed, rather than a line at a time
+    fn new(raw: bool) -> Result<Self> {
+        let mut out = io::stdout();
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        if raw {
+            terminal::enable_raw_mode()?;
+        }
+        Ok(Self { out, raw })
+    }
+
+    fn draw(&mut self, frame: &str) -> Result<()> {
+        let clear = terminal::Clear(terminal::ClearType::All);
+        queue!(self.out, cursor::MoveTo(0, 0), clear)?;
+        // Raw mode doesn't go back to the start of the line by itself
+        write!(self.out, "{}", frame.replace('\n', "\r\n"))?;
+        self.out.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        if self.raw {
+            let _ = terminal::disable_raw_mode();
+        }
+        let _ = execute!(self.out, cursor::Show, terminal::LeaveAlternateScreen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use intcode::debugger::{Breakpoint, Debugger, Stop};
+
+    #[test]
+    fn test_examples() {
+        // Draws a wall, two blocks, the paddle (1,2) and ball (6,5) from the example, then a score
+        let program = "104,0,104,0,104,1,104,1,104,0,104,2,104,2,104,0,104,2,\
+104,1,104,2,104,3,104,6,104,5,104,4,104,-1,104,0,104,12345,99";
+        assert_eq!(part1(program).unwrap(), 2);
+
+        let mut cabinet = ArcadeCabinet::new(&parse_program(program).unwrap());
+        assert_eq!(cabinet.run(ArcadeCabinet::follow_ball).unwrap(), 12345);
+        assert_eq!(cabinet.screen[2][1], Tile::Paddle);
+        assert_eq!(cabinet.screen[5][6], Tile::Ball);
+        assert_eq!(cabinet.to_string(), "Score: 12345\n█▒▒\n\n ▔\n\n\n      ●");
+
+        // Draws the ball at (2,0), asks for the joystick, and draws it again moved by what it's told.
+        // Starts with an add that free play can overwrite.
+        let program = "1,0,0,0,104,2,104,0,104,4,3,100,1,100,17,17,104,2,104,0,104,4,99";
+        let mut cabinet = ArcadeCabinet::new(&parse_program(program).unwrap());
+        let mut shown = Vec::new();
+        cabinet
+            .run(|cabinet| {
+                shown.push(cabinet.to_string());
+                Ok(-2)
+            })
+            .unwrap();
+        assert_eq!(shown, ["Score: 0\n  ●"]);
+        assert_eq!(cabinet.screen[0][0], Tile::Ball);
+
+        // A debugger attached to the cabinet stops where it reads the joystick
+        let mut cabinet = ArcadeCabinet::new(&parse_program(program).unwrap());
+        let mut debugger = Debugger::new(cabinet.computer_mut());
+        debugger.add_breakpoint(Breakpoint::Opcode(3));
+        let mut stop = debugger.resume().unwrap();
+        while stop == Stop::State(RunState::ProducedOutput) {
+            stop = debugger.resume().unwrap();
+        }
+        assert_eq!(stop, Stop::Breakpoint(Breakpoint::Opcode(3)));
+        assert_eq!(debugger.computer().ip(), 10);
+
+        // The paddle (never drawn, so at 0) follows the ball right
+        let mut recording = Vec::new();
+        assert_eq!(record(program, &mut recording).unwrap(), 0);
+        let recording = String::from_utf8(recording).unwrap();
+        assert_eq!(
+            frames(&recording).collect::<Vec<_>>(),
+            ["Score: 0\n  ●", "Score: 0\n  ●●"]
+        );
+    }
+}
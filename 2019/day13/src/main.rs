@@ -1,78 +1,71 @@
-use anyhow::Result;
-use intcode::{IntcodeComputer, Program, RunState, parse_program};
+use anyhow::{Context, Result, bail};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::time::Duration;
 
-fn main() -> Result<()> {
-    let program = parse_input()?;
-
-    let mut cabinet = ArcadeCabinet::new(&program);
-    cabinet.run();
-    let block_tiles = cabinet.screen.iter().flatten().filter(|&&x| x == 2).count();
-    println!("Part 1: {block_tiles}");
-
-    let mut cabinet = ArcadeCabinet::new(&program);
-    // Free play
-    cabinet.computer.memory[0] = 2;
-    let score = cabinet.run();
-    println!("Part 2: {score}");
-
-    Ok(())
+enum Show {
+    Watch,
+    Play,
+    Record(String),
+    Replay(String),
 }
 
-fn parse_input() -> Result<Program> {
-    let mut line = String::new();
-    std::io::stdin().read_line(&mut line)?;
-    parse_program(&line)
-}
-
-struct ArcadeCabinet {
-    screen: Vec<Vec<usize>>,
-    computer: IntcodeComputer,
-}
-
-const SCREEN_WIDTH: usize = 40;
-
-impl ArcadeCabinet {
-    fn new(program: &Program) -> Self {
-        Self {
-            screen: vec![vec![0; SCREEN_WIDTH]; SCREEN_WIDTH],
-            computer: IntcodeComputer::new(program),
-        }
+/// Solves the puzzle from stdin, or shows the game with the program from the inputs directory:
+/// `--watch` the paddle follow the ball, `--play` with the arrow keys (q quits), `--record <file>`
+/// the frames without drawing them, or `--replay <file>` a recording. `--fps <n>` sets the speed
+/// for showing it, which defaults to 30 (0 is as fast as it goes).
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() {
+        return aoc_common::run(aoc2019_day13::part1, aoc2019_day13::part2);
     }
 
-    fn run(&mut self) -> isize {
-        let mut output = Vec::new();
-        let mut score = 0;
-        let mut ball_x = 0;
-        let mut paddle_x = 0;
-        loop {
-            match self.computer.run() {
-                RunState::Halted => break,
-                RunState::ProducedOutput => {
-                    output.push(self.computer.output.pop_front().unwrap());
-
-                    if let &[x, y, tile_id] = output.as_slice() {
-                        if (-1, 0) == (x, y) {
-                            score = tile_id;
-                        } else {
-                            let (x, y) = (x as usize, y as usize);
-                            if tile_id == 3 {
-                                paddle_x = x;
-                            } else if tile_id == 4 {
-                                ball_x = x;
-                            }
-                            self.screen[y][x] = tile_id as usize;
-                        }
-
-                        output.clear();
-                    }
-                }
-                RunState::AwaitingInput => {
-                    // Keep paddle under the ball (returns sign of difference: 0, -1, 1)
-                    let input = (ball_x as isize - paddle_x as isize).signum();
-                    self.computer.input.push_back(input);
-                }
+    let mut fps = 30.0;
+    let mut show = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--fps" => {
+                let value = args.next().context("--fps needs a number")?;
+                fps = value
+                    .parse()
+                    .with_context(|| format!("Bad --fps {value}"))?;
+            }
+            "--watch" => show = Some(Show::Watch),
+            "--play" => show = Some(Show::Play),
+            "--record" | "--replay" => {
+                let path = args.next().with_context(|| format!("{arg} needs a file"))?;
+                show = Some(match arg.as_str() {
+                    "--record" => Show::Record(path.clone()),
+                    _ => Show::Replay(path.clone()),
+                });
             }
+            _ => bail!("Unknown argument {arg}"),
         }
-        score
     }
+    let frame_time = if fps > 0.0 {
+        Duration::from_secs_f64(1.0 / fps)
+    } else {
+        Duration::ZERO
+    };
+
+    let input = || {
+        let path = aoc_common::input_path(&aoc_common::inputs_dir(), 2019, 13);
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+    };
+    let score = match show.context("--watch, --play, --record or --replay?")? {
+        Show::Watch => aoc2019_day13::watch(&input()?, false, frame_time)?,
+        Show::Play => aoc2019_day13::watch(&input()?, true, frame_time)?,
+        Show::Record(path) => {
+            let file = File::create(&path).with_context(|| format!("Failed to create {path}"))?;
+            aoc2019_day13::record(&input()?, BufWriter::new(file))?
+        }
+        Show::Replay(path) => {
+            let recording =
+                fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?;
+            return aoc2019_day13::replay(&recording, frame_time);
+        }
+    };
+    println!("Score: {score}");
+    Ok(())
 }
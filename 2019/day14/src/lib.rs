@@ -0,0 +1,172 @@
+use anyhow::{Context, Result};
+use binary_search::{Direction, binary_search};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let chem_rules = parse_input(input)?;
+    Ok(chem_rules
+        .min_required_for("ORE", Chemical::single("FUEL"))
+        .amount)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let chem_rules = parse_input(input)?;
+
+    // Assume the best we could do is 1:1 ORE -> FUEL: part 1 demonstrates this is an upper bound.
+    // Use that as an upper bound on FUEL in a binary search over ORE -> FUEL to find the point
+    // where we spend one trillion ORE.
+    let target_ore = 1_000_000_000_000;
+    let ((max_fuel, ()), _) = binary_search((0, ()), (target_ore, ()), |fuel| {
+        let spent_ore = chem_rules
+            .min_required_for("ORE", Chemical::new(fuel, "FUEL"))
+            .amount;
+        match spent_ore.cmp(&target_ore) {
+            Ordering::Greater => Direction::High(()),
+            _ => Direction::Low(()),
+        }
+    });
+    Ok(max_fuel)
+}
+
+pub fn parse_input(input: &str) -> Result<Reactions> {
+    let mut rules = Vec::new();
+    for line in input.lines() {
+        let (inputs, output) = line
+            .split_once(" => ")
+            .context("expected ' => ' delimiter")?;
+        let requires = inputs
+            .split(", ")
+            .map(|r| r.parse::<Chemical>())
+            .collect::<Result<Vec<_>>>()?;
+        let chemical = output.parse()?;
+        rules.push((chemical, requires));
+    }
+    Ok(Reactions { rules })
+}
+
+#[derive(Debug, Clone)]
+pub struct Reactions {
+    rules: Vec<(Chemical, Vec<Chemical>)>,
+}
+
+impl Reactions {
+    /// Minimum required "base" to make "target".
+    fn min_required_for(&self, base: &str, target: Chemical) -> Chemical {
+        let mut to_make = vec![target];
+        let mut required_for = Chemical::new(0, base);
+        // Since rules must be used in integer amounts, applying some rules yields extra chemicals.
+        let mut leftovers: HashMap<String, usize> = HashMap::new();
+
+        while let Some(mut target) = to_make.pop() {
+            if target.name == base {
+                required_for.amount += target.amount;
+                continue;
+            }
+
+            if let Some(leftover) = leftovers.get_mut(&target.name) {
+                let used = target.amount.min(*leftover);
+                *leftover -= used;
+                target.amount -= used;
+            }
+            leftovers.retain(|_, amount| *amount > 0);
+            if target.amount == 0 {
+                continue;
+            }
+
+            let (makes, made_by) = self
+                .rules
+                .iter()
+                .find(|(output, _)| output.name == target.name)
+                .unwrap();
+            let times_applied = target.amount.div_ceil(makes.amount);
+            let excess = makes.amount * times_applied - target.amount;
+            if excess > 0 {
+                *leftovers.entry(target.name.clone()).or_default() += excess;
+            }
+
+            for m in made_by {
+                if let Some(existing) = to_make.iter_mut().find(|tm| tm.name == m.name) {
+                    existing.amount += m.amount * times_applied;
+                } else {
+                    to_make.push(Chemical::new(m.amount * times_applied, &m.name));
+                }
+            }
+        }
+
+        required_for
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Chemical {
+    amount: usize,
+    name: String,
+}
+
+impl Chemical {
+    fn new(amount: usize, name: &str) -> Self {
+        Self {
+            amount,
+            name: name.to_owned(),
+        }
+    }
+
+    fn single(name: &str) -> Self {
+        Self::new(1, name)
+    }
+}
+
+impl FromStr for Chemical {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (amount, name) = s.split_once(' ').context("expected '<amount> <name>'")?;
+        Ok(Self::new(amount.parse()?, name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+157 ORE => 5 NZVS
+165 ORE => 6 DCFZ
+44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+179 ORE => 7 PSHF
+177 ORE => 5 HKGWZ
+7 DCFZ, 7 PSHF => 2 XJWVT
+165 ORE => 2 GPVTF
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT
+";
+
+    #[test]
+    fn test_examples() {
+        let reactions = "\
+10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL
+";
+        assert_eq!(part1(reactions).unwrap(), 31);
+
+        let reactions = "\
+9 ORE => 2 A
+8 ORE => 3 B
+7 ORE => 5 C
+3 A, 4 B => 1 AB
+5 B, 7 C => 1 BC
+4 C, 1 A => 1 CA
+2 AB, 3 BC, 4 CA => 1 FUEL
+";
+        assert_eq!(part1(reactions).unwrap(), 165);
+
+        assert_eq!(part1(EXAMPLE).unwrap(), 13312);
+        assert_eq!(part2(EXAMPLE).unwrap(), 82892753);
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use crossterm::{cursor, execute, terminal};
+use intcode::{IntcodeComputer, Pos, Program, RemoteExplorer, ShipMap, parse_program};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let ship = map_ship(&parse_program(input)?)?;
+    let oxygen_sys = ship.goal().context("No oxygen system found")?;
+    Ok(ship.distances(ship.start())[&oxygen_sys])
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let ship = map_ship(&parse_program(input)?)?;
+    Ok(oxygen_times(&ship)?.into_values().max().unwrap_or(0))
+}
+
+/// Draws the ship filling with oxygen in the terminal, a minute every `frame_time`.
+pub fn animate_oxygen(input: &str, frame_time: Duration) -> Result<()> {
+    let ship = map_ship(&parse_program(input)?)?;
+    let times = oxygen_times(&ship)?;
+    let mut out = io::stdout();
+    for minute in 0..=times.values().copied().max().unwrap_or(0) {
+        let frame = ship.render(|pos| {
+            let filled = times.get(&pos).is_some_and(|&time| time <= minute);
+            filled.then_some('O')
+        });
+        let clear = terminal::Clear(terminal::ClearType::All);
+        execute!(out, cursor::MoveTo(0, 0), clear)?;
+        writeln!(out, "Minute {minute}\n{frame}")?;
+        thread::sleep(frame_time);
+    }
+    Ok(())
+}
+
+fn map_ship(program: &Program) -> Result<ShipMap> {
+    RemoteExplorer::new(IntcodeComputer::new(program)).explore()
+}
+
+// The minute oxygen reaches each open cell, spreading a cell a minute from the oxygen system
+fn oxygen_times(ship: &ShipMap) -> Result<HashMap<Pos, usize>> {
+    let oxygen_sys = ship.goal().context("No oxygen system found")?;
+    Ok(ship.distances(oxygen_sys))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The droid needs the real repair program, so start from the example's explored map
+    #[test]
+    fn test_examples() {
+        let area = [" ##", "#..##", "#.#..#", "#.O.#", " ###"].join("\n");
+        let ship: ShipMap = area.parse().unwrap();
+        assert_eq!(oxygen_times(&ship).unwrap().into_values().max(), Some(4));
+    }
+}
@@ -0,0 +1,173 @@
+use anyhow::{Result, anyhow};
+
+pub const PHASES: usize = 100;
+
+pub fn part1(input: &str) -> Result<String> {
+    part1_with(input, PHASES)
+}
+
+pub fn part1_with(input: &str, phases: usize) -> Result<String> {
+    Ok(parse_input(input)?.ftf(phases, 0).message())
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    part2_with(input, PHASES)
+}
+
+pub fn part2_with(input: &str, phases: usize) -> Result<String> {
+    let signal = parse_input(input)?;
+    let offset = signal
+        .sequence
+        .iter()
+        .take(7)
+        .fold(0, |acc, d| acc * 10 + *d as usize);
+    Ok(signal.ftf(phases, offset).message())
+}
+
+pub fn parse_input(line: &str) -> Result<Signal> {
+    let sequence = line
+        .trim()
+        .chars()
+        .map(|c| {
+            c.to_digit(10).map(|d| d as isize).ok_or_else(|| {
+                anyhow!("Can't parse '{c}' as digit from input: \"{}\"", line.trim())
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Signal { sequence })
+}
+
+#[derive(Debug)]
+pub struct Signal {
+    sequence: Vec<isize>,
+}
+
+impl Signal {
+    fn ftf(&self, phases: usize, offset: usize) -> Self {
+        if offset == 0 {
+            // Part 1: general FTF
+            let mut signal = self.sequence.clone();
+
+            for _ in 0..phases {
+                signal = (0..signal.len())
+                    .map(|pos| {
+                        FtfPattern::new(pos + 1)
+                            .zip(signal.iter())
+                            .map(|(p, d)| p * d)
+                            .sum::<isize>()
+                            .abs()
+                            % 10
+                    })
+                    .collect::<Vec<_>>();
+            }
+
+            Self { sequence: signal }
+        } else {
+            // In Part 2: the input and sample cases all have the offset in the second half of the
+            // 10,000x sequence. In that case, all pattern coefficients are 0 for the first part
+            // and 1 for the second - meaning we don't need to use FtfPattern, and we only care
+            // about the second part of the sequence.
+            let mut signal: Vec<isize> = self
+                .sequence
+                .iter()
+                .cycle()
+                .skip(offset)
+                .take(self.sequence.len() * 10_000 - offset)
+                .copied()
+                .collect();
+
+            for _ in 0..phases {
+                let mut sum: isize = 0;
+                for i in (0..signal.len()).rev() {
+                    sum += signal[i];
+                    signal[i] = sum.abs() % 10;
+                }
+            }
+
+            Self { sequence: signal }
+        }
+    }
+
+    fn message(&self) -> String {
+        // Keep any leading zeros: the message is digits, not a number
+        self.sequence
+            .iter()
+            .take(8)
+            .map(|d| d.to_string())
+            .collect()
+    }
+}
+
+struct FtfPattern {
+    base: Vec<isize>,
+    repeat: usize,
+    repeated: usize,
+    position: usize,
+}
+
+impl FtfPattern {
+    fn new(repeat: usize) -> Self {
+        Self {
+            base: vec![0, 1, 0, -1],
+            repeat,
+            // Skip first value exactly once
+            repeated: 1,
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for FtfPattern {
+    type Item = isize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.repeated == self.repeat {
+            self.repeated = 0;
+            self.position = (self.position + 1) % self.base.len();
+        }
+
+        self.repeated += 1;
+
+        Some(self.base[self.position])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with("12345678", 1).unwrap(), "48226158");
+        assert_eq!(part1_with("12345678", 2).unwrap(), "34040438");
+        assert_eq!(part1_with("12345678", 3).unwrap(), "03415518");
+        assert_eq!(part1_with("12345678", 4).unwrap(), "01029498");
+
+        assert_eq!(
+            part1("80871224585914546619083218645595").unwrap(),
+            "24176176"
+        );
+        assert_eq!(
+            part1("19617804207202209144916044189917").unwrap(),
+            "73745418"
+        );
+        assert_eq!(
+            part1("69317163492948606335995924319873").unwrap(),
+            "52432133"
+        );
+
+        assert_eq!(
+            part2("03036732577212944063491565474664").unwrap(),
+            "84462026"
+        );
+        assert_eq!(
+            part2("02935109699940807407585447034323").unwrap(),
+            "78725270"
+        );
+        assert_eq!(
+            part2("03081770884921959731165446850517").unwrap(),
+            "53553731"
+        );
+    }
+}
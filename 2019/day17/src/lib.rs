@@ -0,0 +1,260 @@
+use anyhow::{Context, Result, bail};
+use intcode::{Heading, IntcodeComputer, Pos, Program, RunState, parse_program};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(build_map(&parse_program(input)?)?.alignment_param_sum())
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let program = parse_program(input)?;
+    let path = build_map(&program)?.path()?;
+    let routines = compress(&path).context("The path doesn't fit in three functions")?;
+
+    let mut computer = IntcodeComputer::new(&program);
+    computer.memory[0] = 2;
+    let mut robot = computer.ascii();
+    robot.send_line(&routines.main);
+    for function in &routines.functions {
+        robot.send_line(function);
+    }
+    robot.send_line("n");
+
+    let transcript = robot.read_all()?;
+    if transcript.state == RunState::AwaitingInput {
+        bail!(
+            "Robot requested unexpected additional input:\n{}",
+            transcript.text
+        );
+    }
+    transcript
+        .result
+        .context("The robot didn't report any dust")
+}
+
+fn build_map(program: &Program) -> Result<ScaffoldMap> {
+    let mut computer = IntcodeComputer::new(program);
+    let transcript = computer.ascii().read_all()?;
+    if transcript.state == RunState::AwaitingInput {
+        bail!("Unexpectedly awaiting input");
+    }
+
+    let cells = transcript.text.lines().map(|row| row.chars().collect());
+    Ok(ScaffoldMap {
+        cells: cells.collect(),
+    })
+}
+
+#[derive(Debug)]
+struct ScaffoldMap {
+    cells: Vec<Vec<char>>,
+}
+
+/// A turn ('L' or 'R') followed by a number of steps forward.
+type Move = (char, usize);
+
+// The robot's memory takes at most 20 characters per routine
+const MAX_ROUTINE_LEN: usize = 20;
+const FUNCTION_NAMES: [char; 3] = ['A', 'B', 'C'];
+
+#[derive(Debug, PartialEq)]
+struct Routines {
+    main: String,
+    functions: [String; 3],
+}
+
+// Splits the path into a main routine calling up to three functions, all within the robot's
+// memory limit
+fn compress(path: &[Move]) -> Option<Routines> {
+    let mut functions = Vec::new();
+    let mut main = Vec::new();
+    if !split(path, &mut functions, &mut main) {
+        return None;
+    }
+
+    // An empty path needs no functions, but the robot needs at least one to be given a routine
+    let first = *functions.first()?;
+    let main = main.iter().map(|&i| FUNCTION_NAMES[i].to_string());
+    // Unused functions still have to be entered, so they repeat the first one
+    let functions = [0, 1, 2].map(|i| encode(functions.get(i).copied().unwrap_or(first)));
+    Some(Routines {
+        main: main.collect::<Vec<_>>().join(","),
+        functions,
+    })
+}
+
+fn split<'a>(path: &'a [Move], functions: &mut Vec<&'a [Move]>, main: &mut Vec<usize>) -> bool {
+    if path.is_empty() {
+        return true;
+    }
+    // Each call takes two characters of the main routine, counting its comma
+    if main.len() == MAX_ROUTINE_LEN.div_ceil(2) {
+        return false;
+    }
+
+    for i in 0..functions.len() {
+        if let Some(rest) = path.strip_prefix(functions[i]) {
+            main.push(i);
+            if split(rest, functions, main) {
+                return true;
+            }
+            main.pop();
+        }
+    }
+
+    if functions.len() < FUNCTION_NAMES.len() {
+        for len in 1..=path.len() {
+            let (function, rest) = path.split_at(len);
+            if encode(function).len() > MAX_ROUTINE_LEN {
+                break;
+            }
+            functions.push(function);
+            main.push(functions.len() - 1);
+            if split(rest, functions, main) {
+                return true;
+            }
+            main.pop();
+            functions.pop();
+        }
+    }
+    false
+}
+
+fn encode(moves: &[Move]) -> String {
+    let moves = moves.iter().map(|(turn, steps)| format!("{turn},{steps}"));
+    moves.collect::<Vec<_>>().join(",")
+}
+
+impl ScaffoldMap {
+    fn alignment_param_sum(&self) -> usize {
+        let mut intersections = 0;
+        for (y, row) in self.cells.iter().enumerate() {
+            for (x, _c) in row.iter().enumerate() {
+                if self.is_intersection(Pos::new(x as isize, y as isize)) {
+                    intersections += x * y;
+                }
+            }
+        }
+        intersections
+    }
+
+    // The moves that take the robot from where it starts to the far end of the scaffold, going
+    // straight across intersections
+    fn path(&self) -> Result<Vec<Move>> {
+        let (mut pos, mut heading) = self
+            .cells
+            .iter()
+            .enumerate()
+            .find_map(|(y, row)| {
+                row.iter().enumerate().find_map(|(x, &c)| {
+                    let heading = Heading::from_arrow(c)?;
+                    Some((Pos::new(x as isize, y as isize), heading))
+                })
+            })
+            .context("No robot on the map")?;
+
+        let mut path = Vec::new();
+        while let Some((turn, new_heading)) = [('L', heading.left()), ('R', heading.right())]
+            .into_iter()
+            .find(|&(_, heading)| self.is_scaffolding(pos.step(heading)))
+        {
+            heading = new_heading;
+
+            let mut steps = 0;
+            while self.is_scaffolding(pos.step(heading)) {
+                pos = pos.step(heading);
+                steps += 1;
+            }
+            path.push((turn, steps));
+        }
+        Ok(path)
+    }
+
+    fn is_intersection(&self, pos: Pos) -> bool {
+        self.is_scaffolding(pos)
+            && pos
+                .neighbours()
+                .into_iter()
+                .all(|next| self.is_scaffolding(next))
+    }
+
+    fn is_scaffolding(&self, pos: Pos) -> bool {
+        let row = usize::try_from(pos.y).ok().and_then(|y| self.cells.get(y));
+        let cell = row
+            .zip(usize::try_from(pos.x).ok())
+            .and_then(|(row, x)| row.get(x));
+        cell == Some(&'#')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let view = "\
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..";
+        let map = ScaffoldMap {
+            cells: view.lines().map(|l| l.chars().collect()).collect(),
+        };
+        assert_eq!(map.alignment_param_sum(), 76);
+
+        let view = "\
+#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......";
+        let map = ScaffoldMap {
+            cells: view.lines().map(|l| l.chars().collect()).collect(),
+        };
+        let path = map.path().unwrap();
+        assert_eq!(
+            encode(&path),
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+
+        let routines = compress(&path).unwrap();
+        let expanded = routines.main.split(',').map(|name| {
+            let i = FUNCTION_NAMES.iter().position(|&f| f.to_string() == name);
+            routines.functions[i.unwrap()].as_str()
+        });
+        assert_eq!(expanded.collect::<Vec<_>>().join(","), encode(&path));
+        assert!(routines.main.len() <= MAX_ROUTINE_LEN);
+        assert!(
+            routines
+                .functions
+                .iter()
+                .all(|f| f.len() <= MAX_ROUTINE_LEN)
+        );
+        // A robot with nowhere to turn has no path to give it
+        assert!(compress(&[]).is_none());
+
+        // The path for an actual input, which used to be split by hand
+        let (a, b, c) = ("L,12,L,12,R,4", "R,10,R,6,R,4,R,4", "R,6,L,12,L,12");
+        let path: Vec<Move> = [a, b, a, c, b, a, b, c, c, b]
+            .join(",")
+            .split(',')
+            .collect::<Vec<_>>()
+            .chunks(2)
+            .map(|m| (m[0].chars().next().unwrap(), m[1].parse().unwrap()))
+            .collect();
+        assert!(compress(&path).is_some());
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::{Result, bail};
+use intcode::{IntcodeComputer, RunState, parse_program};
+
+pub const GRID: usize = 50;
+pub const SHIP: usize = 100;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, GRID)
+}
+
+pub fn part1_with(input: &str, grid: usize) -> Result<usize> {
+    let mut drone = IntcodeComputer::new(&parse_program(input)?);
+    let mut affected = 0;
+    for (x, y) in (0..grid).flat_map(|y| (0..grid).map(move |x| (x, y))) {
+        if is_affected(&mut drone, x, y)? {
+            affected += 1;
+        }
+    }
+    Ok(affected)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, SHIP)
+}
+
+pub fn part2_with(input: &str, ship: usize) -> Result<usize> {
+    let mut drone = IntcodeComputer::new(&parse_program(input)?);
+    let mut y = ship - 1;
+    let mut x = 0usize;
+
+    loop {
+        while !is_affected(&mut drone, x, y)? {
+            x += 1;
+        }
+
+        let top_y = y + 1 - ship;
+        let right_x = x + ship - 1;
+
+        if is_affected(&mut drone, right_x, top_y)? {
+            return Ok(x * 10_000 + top_y);
+        }
+
+        y += 1;
+    }
+}
+
+// The drone program only answers one query per run, so each query runs on a fresh copy of it
+fn is_affected(drone: &mut IntcodeComputer, x: usize, y: usize) -> Result<bool> {
+    match drone.query([x as isize, y as isize])? {
+        (RunState::ProducedOutput, copy) => Ok(copy.output.front() == Some(&1)),
+        (state, _) => bail!("Unexpected state {:?}", state),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A stand-in drone program whose beam covers everything above the diagonal (y < x)
+    const DRONE: &str = "3,11,3,12,7,12,11,13,4,13,99,0,0,0";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(DRONE, 10).unwrap(), 45);
+        // The first square to fit touches the top edge right past the diagonal
+        assert_eq!(part2_with(DRONE, 10).unwrap(), 10 * 10_000);
+    }
+}
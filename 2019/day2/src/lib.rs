@@ -0,0 +1,43 @@
+use anyhow::{Result, bail};
+use intcode::{IntcodeComputer, Program, parse_program};
+
+pub fn part1(input: &str) -> Result<isize> {
+    run_with_inputs(&parse_program(input)?, 12, 2)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let program = parse_program(input)?;
+
+    for noun in 0..100 {
+        for verb in 0..100 {
+            let result = run_with_inputs(&program, noun, verb)?;
+            if result == 19690720 {
+                return Ok(100 * noun + verb);
+            }
+        }
+    }
+
+    bail!("No noun and verb produce 19690720")
+}
+
+fn run_with_inputs(program: &Program, noun: isize, verb: isize) -> Result<isize> {
+    let mut computer = IntcodeComputer::new(program);
+    computer.memory[1] = noun;
+    computer.memory[2] = verb;
+    computer.run_to_halt()?;
+    Ok(computer.memory[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let program = parse_program("1,9,10,3,2,3,11,0,99,30,40,50").unwrap();
+        assert_eq!(run_with_inputs(&program, 9, 10).unwrap(), 3500);
+
+        let program = parse_program("1,1,1,4,99,5,6,0,99").unwrap();
+        assert_eq!(run_with_inputs(&program, 1, 1).unwrap(), 30);
+    }
+}
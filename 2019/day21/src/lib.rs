@@ -0,0 +1,338 @@
+use anyhow::{Context, Result, bail, ensure};
+use intcode::{IntcodeComputer, Program, RunState, parse_program};
+use std::fmt;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<isize> {
+    Ok(survey(&parse_program(input)?, Mode::Walk)?.1)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    Ok(survey(&parse_program(input)?, Mode::Run)?.1)
+}
+
+// -> (the script that made it across, the hull damage it reported)
+fn survey(program: &Program, mode: Mode) -> Result<(Script, isize)> {
+    synthesise(mode, |script| run_spring(program, script))
+}
+
+// The droid only remembers 15 instructions
+const MAX_INSTRUCTIONS: usize = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    // Sees A-D
+    Walk,
+    // Sees A-I
+    Run,
+}
+
+impl Mode {
+    fn sensors(self) -> usize {
+        match self {
+            Mode::Walk => 4,
+            Mode::Run => 9,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    And,
+    Or,
+    Not,
+}
+
+// `op x y` stores `x op y` in y, or `!x` for NOT. x is a sensor (A for the ground 1 tile ahead,
+// B for 2 tiles, ...), T or J, and y is T (scratch) or J (whether to jump). Both start each step
+// false.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Instruction {
+    op: Op,
+    x: char,
+    y: char,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Script {
+    instructions: Vec<Instruction>,
+    mode: Mode,
+}
+
+impl Script {
+    fn new(instructions: Vec<Instruction>, mode: Mode) -> Result<Self> {
+        ensure!(
+            instructions.len() <= MAX_INSTRUCTIONS,
+            "{} instructions is more than the droid can remember",
+            instructions.len()
+        );
+        let last_sensor = (b'A' + mode.sensors() as u8 - 1) as char;
+        for instruction in &instructions {
+            ensure!(
+                matches!(instruction.x, 'T' | 'J') || ('A'..=last_sensor).contains(&instruction.x),
+                "Can't read {} when {mode:?}ing",
+                instruction.x
+            );
+            ensure!(
+                matches!(instruction.y, 'T' | 'J'),
+                "Can't write to {}",
+                instruction.y
+            );
+        }
+        Ok(Self { instructions, mode })
+    }
+
+    // Jumps when any of `holes` is a hole, D is ground to land on, and any of `onward` is ground
+    // (or always, if there aren't any) to carry on from there
+    fn jump_when(mode: Mode, holes: &[char], onward: &[char]) -> Result<Self> {
+        let mut instructions = Vec::new();
+        let mut push = |op, x, y| instructions.push(Instruction { op, x, y });
+        for (i, &sensor) in holes.iter().enumerate() {
+            if i == 0 {
+                push(Op::Not, sensor, 'J');
+            } else {
+                push(Op::Not, sensor, 'T');
+                push(Op::Or, 'T', 'J');
+            }
+        }
+        push(Op::And, 'D', 'J');
+        if let [first, rest @ ..] = onward {
+            // T is left over from the holes, so set it to the first sensor by negating twice
+            push(Op::Not, *first, 'T');
+            push(Op::Not, 'T', 'T');
+            for &sensor in rest {
+                push(Op::Or, sensor, 'T');
+            }
+            push(Op::And, 'T', 'J');
+        }
+        Self::new(instructions, mode)
+    }
+
+    // Whether the droid jumps given the ground (true) or holes (false) at A-I
+    fn jumps(&self, sensors: &[bool; 9]) -> bool {
+        let (mut t, mut j) = (false, false);
+        for instruction in &self.instructions {
+            let x = match instruction.x {
+                'T' => t,
+                'J' => j,
+                sensor => sensors[(sensor as u8 - b'A') as usize],
+            };
+            let y = if instruction.y == 'T' { &mut t } else { &mut j };
+            *y = match instruction.op {
+                Op::And => x && *y,
+                Op::Or => x || *y,
+                Op::Not => !x,
+            };
+        }
+        j
+    }
+
+    // Simulates the droid crossing the hull from its first tile
+    fn survives(&self, hull: &Hull) -> bool {
+        let mut pos = 0;
+        while pos < hull.0.len() {
+            if !hull.ground(pos) {
+                return false;
+            }
+            let sensors = std::array::from_fn(|i| hull.ground(pos + i + 1));
+            pos += if self.jumps(&sensors) { 4 } else { 1 };
+        }
+        true
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for Instruction { op, x, y } in &self.instructions {
+            writeln!(f, "{} {x} {y}", format!("{op:?}").to_uppercase())?;
+        }
+        write!(f, "{}", format!("{:?}", self.mode).to_uppercase())
+    }
+}
+
+impl FromStr for Script {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines: Vec<_> = s.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+        let mode = match lines.pop() {
+            Some("WALK") => Mode::Walk,
+            Some("RUN") => Mode::Run,
+            _ => bail!("Scripts end with WALK or RUN"),
+        };
+        let instructions = lines
+            .iter()
+            .map(|line| {
+                let [op, x, y] = line.split(' ').collect::<Vec<_>>()[..] else {
+                    bail!("Invalid instruction: {line}");
+                };
+                let op = match op {
+                    "AND" => Op::And,
+                    "OR" => Op::Or,
+                    "NOT" => Op::Not,
+                    _ => bail!("Unknown instruction: {op}"),
+                };
+                let register = |r: &str| r.parse().with_context(|| format!("Bad register {r}"));
+                Ok(Instruction {
+                    op,
+                    x: register(x)?,
+                    y: register(y)?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Self::new(instructions, mode)
+    }
+}
+
+// A stretch of hull from the droid's first tile: ground (true) or holes. Past the end is ground.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hull(Vec<bool>);
+
+impl Hull {
+    fn parse(line: &str) -> Self {
+        Self(line.chars().map(|c| c == '#').collect())
+    }
+
+    fn ground(&self, pos: usize) -> bool {
+        self.0.get(pos).copied().unwrap_or(true)
+    }
+}
+
+enum Outcome {
+    Survived(isize),
+    Fell(Hull),
+}
+
+// Tries scripts on the droid simplest first, skipping any that the hulls it's already fallen
+// into show won't work
+fn synthesise(
+    mode: Mode,
+    mut attempt: impl FnMut(&Script) -> Result<Outcome>,
+) -> Result<(Script, isize)> {
+    let mut falls = Vec::new();
+    for script in candidates(mode)? {
+        if !falls.iter().all(|hull| script.survives(hull)) {
+            continue;
+        }
+        match attempt(&script)? {
+            Outcome::Survived(damage) => return Ok((script, damage)),
+            Outcome::Fell(hull) => falls.push(hull),
+        }
+    }
+    bail!("No script made it across {} hulls", falls.len())
+}
+
+// Scripts that jump over a hole in A-C when they can land on D, and maybe only when they can go
+// on from E-I, fewest instructions first
+fn candidates(mode: Mode) -> Result<Vec<Script>> {
+    let onward_sensors = ['E', 'F', 'G', 'H', 'I'];
+    let onward_sensors = &onward_sensors[..mode.sensors() - 4];
+    let mut scripts = Vec::new();
+    for holes in subsets(&['A', 'B', 'C']).filter(|holes| !holes.is_empty()) {
+        for onward in subsets(onward_sensors).filter(|onward| onward.len() <= 2) {
+            scripts.push(Script::jump_when(mode, &holes, &onward)?);
+        }
+    }
+    scripts.sort_by_key(|script| script.instructions.len());
+    Ok(scripts)
+}
+
+fn subsets(items: &[char]) -> impl Iterator<Item = Vec<char>> + '_ {
+    (0..1 << items.len()).map(move |mask| {
+        let items = items.iter().enumerate();
+        let subset = items.filter(|(i, _)| mask & (1 << i) != 0);
+        subset.map(|(_, &item)| item).collect()
+    })
+}
+
+fn run_spring(program: &Program, script: &Script) -> Result<Outcome> {
+    let mut computer = IntcodeComputer::new(program);
+    let mut droid = computer.ascii();
+    for line in script.to_string().lines() {
+        droid.send_line(line);
+    }
+
+    let transcript = droid.read_all()?;
+    match (transcript.result, transcript.state) {
+        (Some(damage), _) => Ok(Outcome::Survived(damage)),
+        (None, RunState::AwaitingInput) => bail!(
+            "The springdroid wants more input than the script:\n{}",
+            transcript.text
+        ),
+        // The droid shows its last moments, with the hull it fell into under the first
+        (None, _) => transcript
+            .text
+            .split("Didn't make it across")
+            .nth(1)
+            .and_then(|fall| fall.lines().find(|line| line.starts_with('#')))
+            .map(|hull| Outcome::Fell(Hull::parse(hull)))
+            .with_context(|| format!("No hull in the droid's fall:\n{}", transcript.text)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        // The puzzle's example script, which falls into the hull the droid then shows
+        let script: Script = "NOT D J\nWALK".parse().unwrap();
+        assert!(!script.survives(&Hull::parse("#####.###########")));
+        assert!("NOT E J\nWALK".parse::<Script>().is_err());
+        assert!("AND A B\nWALK".parse::<Script>().is_err());
+
+        // The scripts that used to be worked out by hand
+        let walk: Script = "NOT A J\nNOT B T\nOR T J\nNOT C T\nOR T J\nAND D J\nWALK"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            walk,
+            Script::jump_when(Mode::Walk, &['A', 'B', 'C'], &[]).unwrap()
+        );
+        let run = Script::jump_when(Mode::Run, &['A', 'B', 'C'], &['E', 'H']).unwrap();
+        assert_eq!(run.to_string().lines().count(), 11);
+        assert_eq!(run.to_string().parse::<Script>().unwrap(), run);
+        let mut sensors = [true; 9];
+        sensors[2] = false;
+        assert!(run.jumps(&sensors));
+        sensors[4] = false;
+        sensors[7] = false;
+        assert!(!run.jumps(&sensors));
+
+        // A droid that falls into the first of these it doesn't cross
+        for (mode, hulls) in [
+            (
+                Mode::Walk,
+                &[
+                    "#####.###########",
+                    "#####..#.########",
+                    "#####...#########",
+                ][..],
+            ),
+            (
+                Mode::Run,
+                &[
+                    "#####.###########",
+                    "#####...#########",
+                    "#####.#.##.#.####",
+                    "#####.##.##.#.###",
+                    "#####..#.########",
+                ][..],
+            ),
+        ] {
+            let hulls: Vec<_> = hulls.iter().map(|h| Hull::parse(h)).collect();
+            let mut attempts = 0;
+            let (script, _) = synthesise(mode, |script| {
+                attempts += 1;
+                Ok(match hulls.iter().find(|hull| !script.survives(hull)) {
+                    Some(hull) => Outcome::Fell(hull.clone()),
+                    None => Outcome::Survived(0),
+                })
+            })
+            .unwrap();
+            assert!(hulls.iter().all(|hull| script.survives(hull)));
+            assert!(attempts > 1);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use anyhow::{Context, Result, bail};
+use intcode::network::{Event, Packet, Topology};
+use intcode::{IntcodeComputer, Network, Program, parse_program};
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<isize> {
+    Ok(run_network(&parse_program(input)?)?.0)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    Ok(run_network(&parse_program(input)?)?.1)
+}
+
+// -> (first Y sent to the NAT, first Y the NAT delivers to address 0 twice in a row)
+fn run_network(nic_software: &Program) -> Result<(isize, isize)> {
+    // Packets are (address, X, Y) and NICs read -1 when there's nothing for them
+    let mut network = Network::new(Topology::Bus);
+    network.set_frame(3);
+    network.set_idle_input(Some(-1));
+    for address in 0..50 {
+        let nic = network.add(format!("NIC {address}"), IntcodeComputer::new(nic_software));
+        network.send(nic, &[address]);
+    }
+
+    // The NAT keeps the last packet sent to 255 and sends it to 0 whenever the network idles
+    let mut nat_packet = None;
+    let mut first_nat_y = None;
+    let mut y_sent_to_0 = HashSet::new();
+    loop {
+        match network.next_event()?.context("Every NIC halted")? {
+            Event::Packet(Packet {
+                to: Some(255),
+                payload,
+                ..
+            }) => {
+                first_nat_y.get_or_insert(payload[1]);
+                nat_packet = Some(payload);
+            }
+            Event::Packet(packet) => bail!("Packet for no one: {packet:?}"),
+            Event::Idle => {
+                if let Some(packet) = &nat_packet {
+                    network.send(0, packet);
+                    if !y_sent_to_0.insert(packet[1]) {
+                        return Ok((first_nat_y.unwrap(), packet[1]));
+                    }
+                }
+            }
+            Event::Halted(nic) => bail!("{} halted", network.name(nic)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A stand-in NIC that sends (0, <its address>) to the NAT once, then idles
+    const NIC: &str = "3,100,104,255,104,0,4,100,3,101,1105,1,8,99";
+
+    #[test]
+    fn test_examples() {
+        let program = parse_program(NIC).unwrap();
+        assert_eq!(run_network(&program).unwrap(), (0, 49));
+    }
+}
@@ -0,0 +1,430 @@
+use anyhow::{Context, Result, anyhow, bail};
+use intcode::{IntcodeComputer, Program, RunState, parse_program};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<u64> {
+    let result = solve(&parse_program(input)?)?;
+    // "...You should be able to get in by typing <password> on the keypad at the main airlock."
+    result
+        .split_ascii_whitespace()
+        .find_map(|token| token.parse().ok())
+        .ok_or_else(|| anyhow!("No password found in: {result}"))
+}
+
+/// Plays the adventure with commands read from `commands`, mapping the ship as it goes, and
+/// prints the map once the commands run out or the game ends.
+pub fn play(input: &str, commands: impl BufRead, mut out: impl Write) -> Result<()> {
+    let (mut droid, intro) = Droid::new(&parse_program(input)?)?;
+    write!(out, "{intro}")?;
+    out.flush()?;
+    for command in commands.lines() {
+        let command = command?;
+        let transcript = match command.trim().parse() {
+            Ok(door) => droid.go(door)?,
+            Err(_) => droid.command(command.trim())?,
+        };
+        write!(out, "{}", transcript.text)?;
+        out.flush()?;
+        if transcript.state == RunState::Halted {
+            break;
+        }
+    }
+    write!(out, "\n{}", droid.map)?;
+    Ok(())
+}
+
+// -> the droid's output after making it past the pressure-sensitive floor
+fn solve(program: &Program) -> Result<String> {
+    let (mut droid, _) = Droid::new(program)?;
+    droid.explore()?;
+    droid.pass_security()
+}
+
+// Enough for any command, but taking the infinite loop never finishes
+const STEP_LIMIT: usize = 10_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Door {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Door {
+    fn opposite(self) -> Self {
+        match self {
+            Door::North => Door::South,
+            Door::South => Door::North,
+            Door::East => Door::West,
+            Door::West => Door::East,
+        }
+    }
+}
+
+impl FromStr for Door {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "north" => Door::North,
+            "south" => Door::South,
+            "east" => Door::East,
+            "west" => Door::West,
+            _ => bail!("Unknown door {s}"),
+        })
+    }
+}
+
+impl fmt::Display for Door {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{self:?}").to_lowercase())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Item(String);
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Room {
+    name: String,
+    doors: Vec<Door>,
+    items: Vec<Item>,
+}
+
+impl Room {
+    // The rooms described in the droid's output, in order. Being thrown out of a room shows both.
+    fn parse_all(output: &str) -> Vec<Room> {
+        let mut rooms: Vec<Room> = Vec::new();
+        let (mut in_doors, mut in_items) = (false, false);
+        for line in output.lines() {
+            if let Some(name) = line.strip_prefix("== ").and_then(|l| l.strip_suffix(" ==")) {
+                rooms.push(Room {
+                    name: name.to_string(),
+                    doors: Vec::new(),
+                    items: Vec::new(),
+                });
+                (in_doors, in_items) = (false, false);
+            } else if line == "Doors here lead:" {
+                (in_doors, in_items) = (true, false);
+            } else if line == "Items here:" {
+                (in_doors, in_items) = (false, true);
+            } else if let Some(entry) = line.strip_prefix("- ")
+                && let Some(room) = rooms.last_mut()
+            {
+                if in_doors && let Ok(door) = entry.parse() {
+                    room.doors.push(door);
+                } else if in_items {
+                    room.items.push(Item(entry.to_string()));
+                }
+            } else if line.is_empty() {
+                (in_doors, in_items) = (false, false);
+            }
+        }
+        rooms
+    }
+}
+
+/// The rooms seen so far and the doors known to join them.
+#[derive(Debug, Default)]
+struct ShipMap {
+    rooms: HashMap<String, Room>,
+    exits: HashMap<(String, Door), String>,
+    // The checkpoint and the door to the pressure-sensitive floor, which throws the droid back
+    security: Option<(String, Door)>,
+}
+
+impl ShipMap {
+    // The doors to go through from one room to another
+    fn route(&self, from: &str, to: &str) -> Option<Vec<Door>> {
+        let mut queue = VecDeque::from([(from, Vec::new())]);
+        let mut visited = HashSet::from([from]);
+        while let Some((room, doors)) = queue.pop_front() {
+            if room == to {
+                return Some(doors);
+            }
+            for door in &self.rooms.get(room)?.doors {
+                if let Some(next) = self.exits.get(&(room.to_string(), *door))
+                    && visited.insert(next)
+                {
+                    let mut doors = doors.clone();
+                    doors.push(*door);
+                    queue.push_back((next, doors));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for ShipMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut names: Vec<_> = self.rooms.keys().collect();
+        names.sort();
+        for name in names {
+            writeln!(f, "{name}")?;
+            let room = &self.rooms[name];
+            for &door in &room.doors {
+                let key = (name.clone(), door);
+                match self.exits.get(&key) {
+                    Some(to) => writeln!(f, "  {door}: {to}")?,
+                    None if self.security == Some(key) => {
+                        writeln!(f, "  {door}: Pressure-Sensitive Floor")?
+                    }
+                    None => writeln!(f, "  {door}: ?")?,
+                }
+            }
+            for item in &room.items {
+                writeln!(f, "  item: {item}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The droid exploring the ship, driven through its Intcode program's text interface.
+pub struct Droid {
+    computer: IntcodeComputer,
+    map: ShipMap,
+    room: String,
+    inventory: Vec<Item>,
+    dangerous: HashSet<Item>,
+}
+
+impl Droid {
+    /// -> the droid and the description of the room it starts in
+    pub fn new(program: &Program) -> Result<(Self, String)> {
+        let mut droid = Self {
+            computer: IntcodeComputer::new(program),
+            map: ShipMap::default(),
+            room: String::new(),
+            inventory: Vec::new(),
+            dangerous: HashSet::new(),
+        };
+        let intro = droid.computer.ascii().read_all()?.text;
+        let room = Room::parse_all(&intro)
+            .pop()
+            .context("The droid didn't start in a room")?;
+        droid.room = room.name.clone();
+        droid.map.rooms.insert(room.name.clone(), room);
+        Ok((droid, intro))
+    }
+
+    /// For attaching a debugger (`intcode::Debugger::new(droid.computer_mut())`).
+    pub fn computer_mut(&mut self) -> &mut IntcodeComputer {
+        &mut self.computer
+    }
+
+    fn command(&mut self, command: &str) -> Result<intcode::ascii::Transcript> {
+        let mut droid = self.computer.ascii();
+        droid.send_line(command);
+        droid.read_all()
+    }
+
+    // Goes through a door, mapping where it leads
+    fn go(&mut self, door: Door) -> Result<intcode::ascii::Transcript> {
+        let transcript = self.command(&door.to_string())?;
+        let rooms = Room::parse_all(&transcript.text);
+        match &rooms[..] {
+            [] => {}
+            [room] => {
+                let from = std::mem::replace(&mut self.room, room.name.clone());
+                self.map
+                    .exits
+                    .insert((from.clone(), door), room.name.clone());
+                self.map
+                    .exits
+                    .insert((room.name.clone(), door.opposite()), from);
+                self.map.rooms.insert(room.name.clone(), room.clone());
+            }
+            [.., room] => {
+                self.map.security = Some((room.name.clone(), door));
+                self.room = room.name.clone();
+            }
+        }
+        Ok(transcript)
+    }
+
+    // Visits every room reachable from this one, taking what's safe to take, and comes back
+    fn explore(&mut self) -> Result<()> {
+        let here = self.map.rooms[&self.room].clone();
+        for item in &here.items {
+            if self.try_take(item)? {
+                self.inventory.push(item.clone());
+            }
+        }
+
+        for &door in &here.doors {
+            let key = (here.name.clone(), door);
+            if self.map.exits.contains_key(&key) || self.map.security == Some(key) {
+                continue;
+            }
+            let known_rooms = self.map.rooms.len();
+            self.go(door)?;
+            if self.room != here.name {
+                if self.map.rooms.len() > known_rooms {
+                    self.explore()?;
+                }
+                self.go(door.opposite())?;
+            }
+        }
+        Ok(())
+    }
+
+    // Takes an item unless it kills the droid, never lets go of it or stops it moving, in which
+    // case it's as if the droid had never tried
+    fn try_take(&mut self, item: &Item) -> Result<bool> {
+        if self.dangerous.contains(item) {
+            return Ok(false);
+        }
+        let before = self.computer.snapshot();
+        let mut droid = self.computer.ascii();
+        droid.send_line(&format!("take {item}"));
+        let safe = match droid.read_all_within(STEP_LIMIT)? {
+            Some(transcript) if transcript.state == RunState::AwaitingInput => {
+                let holding = self.computer.snapshot();
+                let door = self.map.rooms[&self.room].doors[0];
+                let moved = !Room::parse_all(&self.command(&door.to_string())?.text).is_empty();
+                self.computer.restore(&holding);
+                moved
+            }
+            _ => false,
+        };
+        if !safe {
+            self.computer.restore(&before);
+            self.dangerous.insert(item.clone());
+        }
+        Ok(safe)
+    }
+
+    // Walks to the checkpoint and tries the floor with every combination of items, changing
+    // one item between tries (Gray code order)
+    fn pass_security(&mut self) -> Result<String> {
+        let (checkpoint, floor) = self
+            .map
+            .security
+            .clone()
+            .context("Never found the pressure-sensitive floor")?;
+        let route = self
+            .map
+            .route(&self.room, &checkpoint)
+            .context("No way back to the checkpoint")?;
+        for door in route {
+            self.go(door)?;
+        }
+
+        let items = self.inventory.clone();
+        let mut held = vec![true; items.len()];
+        for i in 0..1_u32 << items.len() {
+            if i > 0 {
+                let changed = i.trailing_zeros() as usize;
+                held[changed] = !held[changed];
+                let verb = if held[changed] { "take" } else { "drop" };
+                self.command(&format!("{verb} {}", items[changed]))?;
+            }
+
+            let result = self.command(&floor.to_string())?.text;
+            if !result.contains("lighter") && !result.contains("heavier") {
+                return Ok(result);
+            }
+        }
+        bail!("Could not find correct item combination")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let output = "
+
+== Hull Breach ==
+You got in through a hole in the floor here. To keep your ship from also freezing, the hole has been sealed.
+
+Doors here lead:
+- north
+- east
+- south
+
+Items here:
+- photons
+- mug
+
+Command?
+";
+        let rooms = Room::parse_all(output);
+        assert_eq!(
+            rooms,
+            [Room {
+                name: "Hull Breach".to_string(),
+                doors: vec![Door::North, Door::East, Door::South],
+                items: vec![Item("photons".to_string()), Item("mug".to_string())],
+            }]
+        );
+
+        // Thrown back from the floor to the checkpoint
+        let output = "
+
+== Pressure-Sensitive Floor ==
+Analyzing...
+
+Doors here lead:
+- west
+
+A loud, robotic voice says \"Alert! Droids on this ship are heavier than the detected value!\" and you are ejected back to the checkpoint.
+
+== Security Checkpoint ==
+In the next room, a pressure-sensitive floor will verify your identity.
+
+Doors here lead:
+- north
+- east
+
+Command?
+";
+        let rooms = Room::parse_all(output);
+        assert_eq!(rooms.len(), 2);
+        assert_eq!(rooms[1].name, "Security Checkpoint");
+        assert_eq!(rooms[1].doors, [Door::North, Door::East]);
+
+        let mut map = ShipMap::default();
+        for (name, doors) in [
+            ("Hull Breach", vec![Door::North, Door::East]),
+            ("Stables", vec![Door::South]),
+            ("Kitchen", vec![Door::West, Door::North]),
+            ("Security Checkpoint", vec![Door::South, Door::East]),
+        ] {
+            let room = Room {
+                name: name.to_string(),
+                doors,
+                items: Vec::new(),
+            };
+            map.rooms.insert(name.to_string(), room);
+        }
+        for (from, door, to) in [
+            ("Hull Breach", Door::North, "Stables"),
+            ("Hull Breach", Door::East, "Kitchen"),
+            ("Kitchen", Door::North, "Security Checkpoint"),
+        ] {
+            map.exits.insert((from.to_string(), door), to.to_string());
+            map.exits
+                .insert((to.to_string(), door.opposite()), from.to_string());
+        }
+        assert_eq!(
+            map.route("Stables", "Security Checkpoint"),
+            Some(vec![Door::South, Door::East, Door::North])
+        );
+        assert_eq!(map.route("Stables", "Nowhere"), None);
+    }
+}
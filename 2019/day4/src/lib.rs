@@ -0,0 +1,66 @@
+use anyhow::Result;
+use std::ops::RangeInclusive;
+use text_io::try_scan;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.filter(|p| p.is_valid(false)).count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.filter(|p| p.is_valid(true)).count())
+}
+
+pub fn parse_input(input: &str) -> Result<RangeInclusive<usize>> {
+    let (start, end): (usize, usize);
+    try_scan!(input.trim().bytes() => "{}-{}", start, end);
+
+    Ok(start..=end)
+}
+
+trait Password {
+    fn is_valid(&self, small_groups: bool) -> bool;
+}
+
+impl Password for usize {
+    fn is_valid(&self, small_groups: bool) -> bool {
+        let mut double = false;
+        let (mut prev, mut num) = (*self % 10, *self / 10);
+        let mut seq = 1;
+
+        while num > 0 {
+            let digit = num % 10;
+            if digit > prev {
+                return false;
+            }
+
+            if digit == prev {
+                seq += 1;
+            } else {
+                double = double || if small_groups { seq == 2 } else { seq > 1 };
+                seq = 1;
+            }
+
+            prev = digit;
+            num /= 10;
+        }
+
+        // Handle last digit == to prev
+        double || if small_groups { seq == 2 } else { seq > 1 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert!(111111.is_valid(false));
+        assert!(!223450.is_valid(false));
+        assert!(!123789.is_valid(false));
+
+        assert!(112233.is_valid(true));
+        assert!(!123444.is_valid(true));
+        assert!(111122.is_valid(true));
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{Context, Result};
+use intcode::{IntcodeComputer, Program, parse_program};
+
+pub const SYSTEM_ID: isize = 5;
+
+pub fn part1(input: &str) -> Result<isize> {
+    run(&parse_program(input)?, 1)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    part2_with(input, SYSTEM_ID)
+}
+
+pub fn part2_with(input: &str, system_id: isize) -> Result<isize> {
+    run(&parse_program(input)?, system_id)
+}
+
+fn run(program: &Program, input: isize) -> Result<isize> {
+    let mut computer = IntcodeComputer::new(program);
+    computer.input.push_back(input);
+    computer.run_to_halt()?;
+    computer.output.pop_back().context("No output")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        // Outputs whatever it's given
+        assert_eq!(part1("3,0,4,0,99").unwrap(), 1);
+
+        // Is the input equal to / less than 8 (position and immediate modes)?
+        for (program, eight, seven) in [
+            ("3,9,8,9,10,9,4,9,99,-1,8", 1, 0),
+            ("3,9,7,9,10,9,4,9,99,-1,8", 0, 1),
+            ("3,3,1108,-1,8,3,4,3,99", 1, 0),
+            ("3,3,1107,-1,8,3,4,3,99", 0, 1),
+        ] {
+            assert_eq!(part2_with(program, 8).unwrap(), eight);
+            assert_eq!(part2_with(program, 7).unwrap(), seven);
+        }
+
+        // Is the input non-zero (jumps)?
+        for program in [
+            "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9",
+            "3,3,1105,-1,9,1101,0,0,12,4,12,99,1",
+        ] {
+            assert_eq!(part2_with(program, 0).unwrap(), 0);
+            assert_eq!(part2_with(program, 5).unwrap(), 1);
+        }
+
+        let compare_to_8 = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+        assert_eq!(part2_with(compare_to_8, 7).unwrap(), 999);
+        assert_eq!(part2_with(compare_to_8, 8).unwrap(), 1000);
+        assert_eq!(part2_with(compare_to_8, 9).unwrap(), 1001);
+    }
+}
@@ -1,29 +1,14 @@
 use anyhow::Result;
-use intcode::{IntcodeComputer, Program, parse_program};
-use std::env;
+use aoc_common::Config;
 
 fn main() -> Result<()> {
-    let program = parse_input()?;
+    let config = Config::load(2019, 5)?;
+    let system_id = config.get_or("system_id", aoc2019_day5::SYSTEM_ID)?;
 
-    let diagnostic = run(&program, 1);
-    println!("Part 1: {diagnostic}");
+    let input = aoc_common::read_input()?;
 
-    let input = env::args().nth(1).unwrap_or(String::from("5")).parse()?;
-    let diagnostic = run(&program, input);
-    println!("Part 2: {diagnostic}");
+    aoc_common::print_answer(1, aoc2019_day5::part1(&input)?);
+    aoc_common::print_answer(2, aoc2019_day5::part2_with(&input, system_id)?);
 
     Ok(())
 }
-
-fn parse_input() -> Result<Program> {
-    let mut line = String::new();
-    std::io::stdin().read_line(&mut line)?;
-    parse_program(&line)
-}
-
-fn run(program: &Program, input: isize) -> isize {
-    let mut computer = IntcodeComputer::new(program);
-    computer.input.push_back(input);
-    computer.run_to_halt();
-    computer.output.pop_back().unwrap()
-}
@@ -0,0 +1,110 @@
+use anyhow::{Result, bail};
+use std::collections::{HashMap, HashSet, VecDeque};
+use text_io::try_scan;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.orbit_count_dfs("COM", 0))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    parse_input(input)?.min_transfers_bfs("YOU", "SAN")
+}
+
+pub fn parse_input(input: &str) -> Result<Graph> {
+    let mut edges = HashMap::new();
+
+    for line in input.lines() {
+        let (orbited, orbiting): (String, String);
+        try_scan!(line.bytes() => "{}){}", orbited, orbiting);
+
+        edges
+            .entry(orbited)
+            .and_modify(|al: &mut HashSet<_>| {
+                al.insert(orbiting.clone());
+            })
+            .or_insert(HashSet::from([orbiting]));
+    }
+
+    Ok(Graph { edges })
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    edges: HashMap<String, HashSet<String>>,
+}
+
+impl Graph {
+    fn orbit_count_dfs(&self, current: &str, depth: usize) -> usize {
+        // Depth is direct + indirect
+        if !self.edges.contains_key(current) {
+            return depth;
+        }
+
+        depth
+            + self.edges[current]
+                .iter()
+                .map(|orbiting| self.orbit_count_dfs(orbiting, depth + 1))
+                .sum::<usize>()
+    }
+
+    fn min_transfers_bfs(&self, source: &str, target: &str) -> Result<usize> {
+        let mut undirected_edges = self.edges.clone();
+        for (from, to) in &self.edges {
+            for t in to.iter() {
+                undirected_edges
+                    .entry(t.to_owned())
+                    .and_modify(|al: &mut HashSet<_>| {
+                        al.insert(from.to_owned());
+                    })
+                    .or_insert(HashSet::from([from.to_owned()]));
+            }
+        }
+
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        queue.push_back((source, 0));
+        visited.insert(source);
+
+        while let Some((current, transfers)) = queue.pop_front() {
+            // - 2 since we want the distance between the objects source and target are orbiting
+            if current == target {
+                return Ok(transfers - 2);
+            }
+
+            if let Some(neighbors) = undirected_edges.get(current) {
+                for neighbor in neighbors {
+                    if visited.insert(neighbor) {
+                        queue.push_back((neighbor, transfers + 1));
+                    }
+                }
+            }
+        }
+
+        bail!("No transfer path found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 42);
+        assert_eq!(part2(&format!("{EXAMPLE}K)YOU\nI)SAN\n")).unwrap(), 4);
+    }
+}
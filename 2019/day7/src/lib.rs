@@ -0,0 +1,90 @@
+use anyhow::{Context, Result, bail};
+use intcode::network::{Event, Topology};
+use intcode::{IntcodeComputer, Network, Program, parse_program};
+use itertools::Itertools;
+use std::ops::Range;
+
+pub fn part1(input: &str) -> Result<isize> {
+    max_signal(&parse_program(input)?, 0..5, false)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    max_signal(&parse_program(input)?, 5..10, true)
+}
+
+fn max_signal(program: &Program, phase_range: Range<isize>, feedback: bool) -> Result<isize> {
+    // Grab this first since permutations() consumes self
+    let k = phase_range.len();
+    phase_range
+        .permutations(k)
+        .map(|phase_sequence| run_chain(program, 0, &phase_sequence, feedback))
+        .process_results(|signals| signals.max())?
+        .context("No phase sequences")
+}
+
+fn run_chain(
+    program: &Program,
+    input: isize,
+    phase_sequence: &[isize],
+    feedback: bool,
+) -> Result<isize> {
+    let mut amps = Network::new(if feedback {
+        Topology::Ring
+    } else {
+        Topology::Pipeline
+    });
+    for (name, &phase_setting) in ('A'..).zip(phase_sequence) {
+        let amp = amps.add(format!("Amplifier {name}"), IntcodeComputer::new(program));
+        amps.send(amp, &[phase_setting]);
+    }
+    amps.send(0, &[input]);
+
+    // The last amplifier's signal goes to the thrusters (and back round to A with feedback)
+    while let Some(event) = amps.next_event()? {
+        if event == Event::Idle {
+            bail!("The amplifiers are deadlocked");
+        }
+    }
+    amps.last_sent(phase_sequence.len() - 1)
+        .and_then(|signal| signal.first().copied())
+        .context("No signal reached the thrusters")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        for (program, max) in [
+            ("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0", 43210),
+            (
+                "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0",
+                54321,
+            ),
+            (
+                "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,\
+31,31,4,31,99,0,0,0",
+                65210,
+            ),
+        ] {
+            assert_eq!(part1(program).unwrap(), max);
+        }
+
+        for (program, max) in [
+            (
+                "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,\
+0,0,5",
+                139629729,
+            ),
+            (
+                "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,\
+12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,\
+0,10",
+                18216,
+            ),
+        ] {
+            assert_eq!(part2(program).unwrap(), max);
+        }
+    }
+}
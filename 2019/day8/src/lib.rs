@@ -0,0 +1,100 @@
+use anyhow::{Result, anyhow};
+use std::fmt::Write;
+
+pub const WIDTH: usize = 25;
+pub const HEIGHT: usize = 6;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, WIDTH, HEIGHT)
+}
+
+pub fn part1_with(input: &str, width: usize, height: usize) -> Result<usize> {
+    let pixels = parse_input(input)?;
+
+    let (mut min_zeros, mut prod_ones_twos) = (usize::MAX, 0);
+    for layer in pixels.chunks(width * height) {
+        let mut counts = [0; 10];
+        layer.iter().for_each(|&p| counts[p] += 1);
+        if counts[0] < min_zeros {
+            min_zeros = counts[0];
+            prod_ones_twos = counts[1] * counts[2];
+        }
+    }
+
+    Ok(prod_ones_twos)
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    part2_with(input, WIDTH, HEIGHT)
+}
+
+pub fn part2_with(input: &str, width: usize, height: usize) -> Result<String> {
+    let pixels = parse_input(input)?;
+
+    let mut image = vec![vec![Color::Transparent; width]; height];
+    for layer in pixels.chunks(width * height) {
+        for i in 0..height {
+            for j in 0..width {
+                if image[i][j] == Color::Transparent {
+                    image[i][j] = Color::from(layer[i * width + j]);
+                }
+            }
+        }
+    }
+
+    let mut rendered = String::new();
+    for row in image.iter() {
+        writeln!(rendered)?;
+        for pixel in row.iter() {
+            let c = match pixel {
+                Color::White => "#",
+                _ => " ",
+            };
+            write!(rendered, "{c}")?;
+        }
+    }
+
+    Ok(rendered)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    let mut pixels = Vec::with_capacity(input.trim().len());
+    for c in input.trim().chars() {
+        let digit = c
+            .to_digit(10)
+            .ok_or_else(|| anyhow!("Input contains non-digit character: {c:?}"))?;
+        pixels.push(digit as usize);
+    }
+
+    Ok(pixels)
+}
+
+#[repr(usize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Color {
+    Black = 0,
+    White = 1,
+    Transparent = 2,
+}
+
+impl From<usize> for Color {
+    fn from(value: usize) -> Self {
+        match value {
+            0 => Color::Black,
+            1 => Color::White,
+            2 => Color::Transparent,
+            v => panic!("Invalid color value: {v}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with("123456789012", 3, 2).unwrap(), 1);
+        assert_eq!(part2_with("0222112222120000", 2, 2).unwrap(), "\n #\n# ");
+    }
+}
@@ -0,0 +1,43 @@
+use anyhow::{Result, anyhow};
+use intcode::{IntcodeComputer, Program, parse_program};
+
+pub fn part1(input: &str) -> Result<isize> {
+    run_boost(&parse_program(input)?, 1)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    run_boost(&parse_program(input)?, 2)
+}
+
+fn run_boost(program: &Program, input: isize) -> Result<isize> {
+    let mut computer = IntcodeComputer::new(program);
+    computer.input.push_back(input);
+    computer.run_to_halt()?;
+    computer.output.pop_front().ok_or(anyhow!("No output"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let mut computer = IntcodeComputer::new(&parse_program(quine).unwrap());
+        computer.run_to_halt().unwrap();
+        assert_eq!(
+            computer
+                .output
+                .iter()
+                .map(isize::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+            quine
+        );
+
+        let program = parse_program("1102,34915192,34915192,7,4,7,99,0").unwrap();
+        assert_eq!(run_boost(&program, 1).unwrap(), 1219070632396864);
+        let program = parse_program("104,1125899906842624,99").unwrap();
+        assert_eq!(run_boost(&program, 1).unwrap(), 1125899906842624);
+    }
+}
@@ -0,0 +1,215 @@
+//! Talking to the programs that speak ASCII, like day 17's vacuum robot and day 25's droid.
+//! Their output is text, except for a final answer too big to be a character, which is kept
+//! apart as the transcript's `result`.
+
+use crate::{IntcodeComputer, RunState, Snapshot};
+use anyhow::{Result, bail};
+use std::io::{BufRead, Write};
+
+/// What a program output before stopping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript {
+    pub text: String,
+    /// The last output that wasn't ASCII, which is where the programs put their answers
+    pub result: Option<isize>,
+    pub state: RunState,
+}
+
+/// Borrows a computer to send it lines of text and read its output back as text.
+#[derive(Debug)]
+pub struct AsciiIo<'a> {
+    computer: &'a mut IntcodeComputer,
+}
+
+impl IntcodeComputer {
+    pub fn ascii(&mut self) -> AsciiIo<'_> {
+        AsciiIo::new(self)
+    }
+}
+
+impl<'a> AsciiIo<'a> {
+    pub fn new(computer: &'a mut IntcodeComputer) -> Self {
+        Self { computer }
+    }
+
+    pub fn computer(&self) -> &IntcodeComputer {
+        self.computer
+    }
+
+    pub fn computer_mut(&mut self) -> &mut IntcodeComputer {
+        self.computer
+    }
+
+    /// Queues text as input, without a newline.
+    pub fn send(&mut self, text: &str) {
+        self.computer.input.extend(text.bytes().map(isize::from));
+    }
+
+    /// Queues a line of text as input, adding the newline.
+    pub fn send_line(&mut self, line: &str) {
+        self.send(line);
+        self.computer.input.push_back(b'\n'.into());
+    }
+
+    /// Runs until the program halts or wants input it hasn't been sent.
+    pub fn read_all(&mut self) -> Result<Transcript> {
+        let transcript = self.read_all_within(usize::MAX)?;
+        Ok(transcript.expect("The program ran for usize::MAX instructions"))
+    }
+
+    /// Like `read_all`, but gives up with `None` if the program is still going after `max_steps`
+    /// instructions (say, because it's stuck in an infinite loop).
+    pub fn read_all_within(&mut self, max_steps: usize) -> Result<Option<Transcript>> {
+        let mut text = String::new();
+        let mut result = None;
+        for _ in 0..max_steps {
+            match self.computer.step()? {
+                None => {}
+                Some(RunState::ProducedOutput) => {
+                    let output = self.computer.output.pop_front().unwrap();
+                    match ascii(output) {
+                        Some(c) => text.push(c),
+                        None => result = Some(output),
+                    }
+                }
+                Some(state) => {
+                    return Ok(Some(Transcript {
+                        text,
+                        result,
+                        state,
+                    }));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Runs until the text output ends with `prompt`, returning the text up to and including it.
+    /// It's an error for the program to halt or want input first.
+    pub fn read_until_prompt(&mut self, prompt: &str) -> Result<String> {
+        let mut text = String::new();
+        while !text.ends_with(prompt) {
+            match self.computer.run()? {
+                RunState::ProducedOutput => {
+                    let output = self.computer.output.pop_front().unwrap();
+                    match ascii(output) {
+                        Some(c) => text.push(c),
+                        None => bail!("Got {output} waiting for {prompt:?} after:\n{text}"),
+                    }
+                }
+                state => bail!("{state:?} waiting for {prompt:?} after:\n{text}"),
+            }
+        }
+        Ok(text)
+    }
+}
+
+fn ascii(output: isize) -> Option<char> {
+    u8::try_from(output)
+        .ok()
+        .filter(u8::is_ascii)
+        .map(char::from)
+}
+
+/// Plays an ASCII program from a terminal: its text goes to `out` and each line read from `input`
+/// is sent to it, until it halts or `input` runs out. Lines starting with `!` aren't sent:
+/// `!save <file>` and `!load <file>` save and restore the game.
+pub fn play(
+    computer: &mut IntcodeComputer,
+    input: impl BufRead,
+    mut out: impl Write,
+) -> Result<RunState> {
+    let mut lines = input.lines();
+    loop {
+        let transcript = computer.ascii().read_all()?;
+        write!(out, "{}", transcript.text)?;
+        if let Some(result) = transcript.result {
+            writeln!(out, "{result}")?;
+        }
+        out.flush()?;
+        if transcript.state == RunState::Halted {
+            return Ok(RunState::Halted);
+        }
+
+        loop {
+            let Some(line) = lines.next().transpose()? else {
+                return Ok(RunState::AwaitingInput);
+            };
+            let line = line.trim_end();
+            match line.split_once(' ') {
+                Some(("!save", path)) => {
+                    computer.snapshot().save(path.trim())?;
+                    writeln!(out, "Saved to {}", path.trim())?;
+                }
+                Some(("!load", path)) => {
+                    computer.restore(&Snapshot::load(path.trim())?);
+                    writeln!(out, "Loaded {}", path.trim())?;
+                }
+                _ if line.starts_with('!') => writeln!(out, "!save <file> or !load <file>")?,
+                _ => {
+                    computer.ascii().send_line(line);
+                    break;
+                }
+            }
+            out.flush()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+
+    #[test]
+    fn test_examples() {
+        // Prints "Hi?\n", echoes a line back, then outputs 1000 and halts
+        let program = assemble(
+            "\
+      out #72
+      out #105
+      out #63
+      out #10
+loop: in -> [c]
+      out [c]
+      eq [c], #10 -> [t]
+      jf [t], loop
+      out #1000
+      hlt
+c:    data 0
+t:    data 0",
+        )
+        .unwrap();
+
+        let mut computer = IntcodeComputer::new(&program);
+        let mut io = computer.ascii();
+        assert_eq!(io.read_until_prompt("?").unwrap(), "Hi?");
+        assert_eq!(
+            io.read_all().unwrap(),
+            Transcript {
+                text: "\n".to_string(),
+                result: None,
+                state: RunState::AwaitingInput
+            }
+        );
+        io.send_line("ok");
+        assert_eq!(
+            io.read_all().unwrap(),
+            Transcript {
+                text: "ok\n".to_string(),
+                result: Some(1000),
+                state: RunState::Halted
+            }
+        );
+        assert!(io.read_until_prompt("?").is_err());
+
+        let mut spinning = IntcodeComputer::new(&assemble("loop: jt #1, loop").unwrap());
+        assert_eq!(spinning.ascii().read_all_within(1000).unwrap(), None);
+
+        let mut computer = IntcodeComputer::new(&program);
+        let mut out = Vec::new();
+        let state = play(&mut computer, "ok\n".as_bytes(), &mut out).unwrap();
+        assert_eq!(state, RunState::Halted);
+        assert_eq!(String::from_utf8(out).unwrap(), "Hi?\nok\n1000\n");
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{Mode, Program};
+use anyhow::{Context, Result, bail};
+use std::collections::HashMap;
+use std::iter::successors;
+
+/// (mnemonic, opcode, parameter count). The last parameter of `add`, `mul`, `in`, `lt` and `eq`
+/// is written to.
+const MNEMONICS: &[(&str, isize, usize)] = &[
+    ("add", 1, 3),
+    ("mul", 2, 3),
+    ("in", 3, 1),
+    ("out", 4, 1),
+    ("jt", 5, 2),
+    ("jf", 6, 2),
+    ("lt", 7, 3),
+    ("eq", 8, 3),
+    ("arb", 9, 1),
+    ("hlt", 99, 0),
+];
+
+/// Assembles a program from one instruction per line, e.g.
+/// ```text
+/// loop: add [rb+3], #5 -> [count]   ; comments run to the end of the line
+///       jt [count], loop
+///       hlt
+/// count: data 0
+/// ```
+/// Parameters are `[addr]` (position), `#value` or a bare value (immediate) and `[rb+offset]`
+/// (relative), where values can be numbers or labels. The written parameter can follow a `->`
+/// instead of a comma. Mnemonics are case-insensitive and `data` lays out values as they are.
+/// Numeric labels like `0042:` are checked against the address they label, so `disassemble`'s
+/// listings assemble back into the program.
+pub fn assemble(source: &str) -> Result<Program> {
+    let mut labels = HashMap::new();
+    let mut items = Vec::new();
+    let mut addr = 0;
+    for (i, line) in source.lines().enumerate() {
+        let item =
+            parse_line(line, addr, &mut labels).with_context(|| format!("Line {}", i + 1))?;
+        if let Some(item) = item {
+            addr += item.size();
+            items.push((i, item));
+        }
+    }
+
+    let mut program = Vec::new();
+    for (i, item) in items {
+        item.encode(&labels, &mut program)
+            .with_context(|| format!("Line {}", i + 1))?;
+    }
+    Ok(program)
+}
+
+enum Item<'a> {
+    Instruction {
+        opcode: isize,
+        params: Vec<Param<'a>>,
+    },
+    Data(Vec<Value<'a>>),
+}
+
+struct Param<'a> {
+    mode: Mode,
+    value: Value<'a>,
+}
+
+enum Value<'a> {
+    Number(isize),
+    Label(&'a str),
+}
+
+fn parse_line<'a>(
+    line: &'a str,
+    addr: usize,
+    labels: &mut HashMap<&'a str, usize>,
+) -> Result<Option<Item<'a>>> {
+    let mut line = line.split(';').next().unwrap().trim();
+    while let Some((label, rest)) = line.split_once(':')
+        && !label.contains(char::is_whitespace)
+    {
+        if let Ok(labelled) = label.parse::<usize>() {
+            if labelled != addr {
+                bail!("{label}: is at address {addr}");
+            }
+        } else if labels.insert(label, addr).is_some() {
+            bail!("Label {label} is defined twice");
+        }
+        line = rest.trim();
+    }
+    if line.is_empty() {
+        return Ok(None);
+    }
+
+    let (mnemonic, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let operands = rest
+        .split(',')
+        .flat_map(|o| o.split("->"))
+        .map(str::trim)
+        .filter(|o| !o.is_empty());
+    let mnemonic = mnemonic.to_ascii_lowercase();
+    if mnemonic == "data" {
+        let values = operands.map(parse_value).collect::<Result<Vec<_>>>()?;
+        return Ok(Some(Item::Data(values)));
+    }
+
+    let &(_, opcode, count) = MNEMONICS
+        .iter()
+        .find(|(m, _, _)| *m == mnemonic)
+        .with_context(|| format!("Unknown mnemonic {mnemonic}"))?;
+    let params = operands.map(parse_param).collect::<Result<Vec<_>>>()?;
+    if params.len() != count {
+        bail!("{mnemonic} takes {count} parameters");
+    }
+    if matches!(opcode, 1 | 2 | 3 | 7 | 8) && params[count - 1].mode == Mode::Immediate {
+        bail!("{mnemonic} can't write to an immediate");
+    }
+    Ok(Some(Item::Instruction { opcode, params }))
+}
+
+fn parse_param(operand: &str) -> Result<Param<'_>> {
+    if let Some(value) = operand.strip_prefix('#') {
+        return Ok(Param {
+            mode: Mode::Immediate,
+            value: parse_value(value)?,
+        });
+    }
+    let Some(inner) = operand.strip_prefix('[').and_then(|o| o.strip_suffix(']')) else {
+        return Ok(Param {
+            mode: Mode::Immediate,
+            value: parse_value(operand)?,
+        });
+    };
+    let inner = inner.trim();
+    match inner
+        .strip_prefix("rb")
+        .map(|offset| offset.replace(' ', ""))
+    {
+        Some(offset) if offset.is_empty() || offset.starts_with(['+', '-']) => {
+            let offset = offset.strip_prefix('+').unwrap_or(&offset);
+            Ok(Param {
+                mode: Mode::Relative,
+                value: Value::Number(if offset.is_empty() {
+                    0
+                } else {
+                    offset.parse()?
+                }),
+            })
+        }
+        _ => Ok(Param {
+            mode: Mode::Position,
+            value: parse_value(inner)?,
+        }),
+    }
+}
+
+fn parse_value(value: &str) -> Result<Value<'_>> {
+    let value = value.trim();
+    if let Ok(n) = value.parse() {
+        Ok(Value::Number(n))
+    } else if value.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        Ok(Value::Label(value))
+    } else {
+        bail!("Invalid value {value}")
+    }
+}
+
+impl Item<'_> {
+    fn size(&self) -> usize {
+        match self {
+            Item::Instruction { params, .. } => params.len() + 1,
+            Item::Data(values) => values.len(),
+        }
+    }
+
+    fn encode(&self, labels: &HashMap<&str, usize>, program: &mut Program) -> Result<()> {
+        let resolve = |value: &Value| match *value {
+            Value::Number(n) => Ok(n),
+            Value::Label(label) => labels
+                .get(label)
+                .map(|&addr| addr as isize)
+                .with_context(|| format!("Unknown label {label}")),
+        };
+        match self {
+            Item::Instruction { opcode, params } => {
+                let modes: isize = params
+                    .iter()
+                    .zip(successors(Some(100), |place| Some(place * 10)))
+                    .map(|(param, place)| place * isize::from(param.mode))
+                    .sum();
+                program.push(opcode + modes);
+                for param in params {
+                    program.push(resolve(&param.value)?);
+                }
+            }
+            Item::Data(values) => {
+                for value in values {
+                    program.push(resolve(value)?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{disassemble, parse_program};
+
+    #[test]
+    fn test_examples() {
+        let program = assemble(
+            "\
+; Counts [count] up to 10
+loop: add [count], #1 -> [count]
+      lt [count], 10, [rb-2]
+      JT [rb-2], loop
+      hlt
+count: data 0, loop
+",
+        )
+        .unwrap();
+        assert_eq!(
+            program,
+            [1001, 12, 1, 12, 21007, 12, 10, -2, 1205, -2, 0, 99, 0, 0]
+        );
+
+        // The day 9 quine
+        let quine =
+            parse_program("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99").unwrap();
+        assert_eq!(assemble(&disassemble(&quine)).unwrap(), quine);
+
+        assert!(assemble("add #1, #2 -> #3").is_err());
+        assert!(assemble("jt #1, nowhere").is_err());
+        assert!(assemble("0001: hlt").is_err());
+    }
+}
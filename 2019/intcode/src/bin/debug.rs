@@ -0,0 +1,17 @@
+use anyhow::{Context, Result};
+use intcode::{IntcodeComputer, debugger, parse_program};
+use std::fs;
+use std::io::{stdin, stdout};
+
+/// Debugs the Intcode program in the given file with commands typed on stdin, e.g.
+/// `cargo run -p intcode --bin debug -- inputs/2019/25.txt` (`help` lists the commands)
+fn main() -> Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .context("Usage: debug <program file>")?;
+    let program = parse_program(
+        &fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?,
+    )?;
+    let mut computer = IntcodeComputer::new(&program);
+    debugger::repl(&mut computer, stdin().lock(), stdout())
+}
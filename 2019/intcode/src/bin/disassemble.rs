@@ -0,0 +1,10 @@
+use anyhow::Result;
+use intcode::{disassemble, parse_program};
+
+/// Prints the listing of the Intcode program read from stdin, e.g.
+/// `cargo run -p intcode --bin disassemble < inputs/2019/25.txt`
+fn main() -> Result<()> {
+    let program = parse_program(&aoc_common::read_input()?)?;
+    print!("{}", disassemble(&program));
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anyhow::{Context, Result};
+use intcode::{IntcodeComputer, ascii, parse_program};
+use std::fs;
+use std::io::{stdin, stdout};
+
+/// Plays the ASCII Intcode program in the given file from the terminal, e.g.
+/// `cargo run -p intcode --bin play -- inputs/2019/25.txt` (`!save <file>` and `!load <file>`
+/// save and restore the game)
+fn main() -> Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .context("Usage: play <program file>")?;
+    let program = parse_program(
+        &fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?,
+    )?;
+    let mut computer = IntcodeComputer::new(&program);
+    ascii::play(&mut computer, stdin().lock(), stdout())?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anyhow::{Context, Result, bail};
+use intcode::trace::{Profile, TraceWriter};
+use intcode::{IntcodeComputer, RunState, parse_program};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::sync::{Arc, Mutex};
+
+const USAGE: &str = "Usage: profile <program file> [--input 1,2,...] [--trace <file>]";
+
+/// Runs the Intcode program in the given file until it halts or runs out of input, then prints its
+/// output and a profile of what it executed, e.g.
+/// `cargo run --release -p intcode --bin profile -- inputs/2019/9.txt --input 2 --trace day9.trace`
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().context(USAGE)?;
+    let mut input = Vec::new();
+    let mut trace_path = None;
+    while let Some(arg) = args.next() {
+        let value = args.next().context(USAGE)?;
+        match arg.as_str() {
+            "--input" => input = parse_program(&value)?,
+            "--trace" => trace_path = Some(value),
+            _ => bail!(USAGE),
+        }
+    }
+
+    let program = parse_program(
+        &fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?,
+    )?;
+    let mut computer = IntcodeComputer::new(&program);
+    computer.input.extend(input);
+
+    let profile = Arc::new(Mutex::new(Profile::default()));
+    let trace = match &trace_path {
+        Some(trace_path) => {
+            let file = File::create(trace_path)
+                .with_context(|| format!("Failed to create {trace_path}"))?;
+            let trace = Arc::new(Mutex::new(TraceWriter::new(BufWriter::new(file))));
+            computer.set_tracer(Some(Arc::new(Mutex::new((profile.clone(), trace.clone())))));
+            Some(trace)
+        }
+        None => {
+            computer.set_tracer(Some(profile.clone()));
+            None
+        }
+    };
+
+    let state = loop {
+        match computer.run()? {
+            RunState::ProducedOutput => continue,
+            state => break state,
+        }
+    };
+    computer.set_tracer(None);
+
+    let output: Vec<_> = computer.output.iter().map(|v| v.to_string()).collect();
+    println!("Output: {}", output.join(","));
+    if state == RunState::AwaitingInput {
+        println!("Stopped at ip {} waiting for input", computer.ip());
+    }
+    println!("{}", profile.lock().unwrap());
+
+    if let Some(trace) = trace {
+        let trace = Arc::into_inner(trace).unwrap().into_inner().unwrap();
+        trace.finish().context("Failed to write the trace")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,497 @@
+//! Stepping through an `IntcodeComputer` with breakpoints and watchpoints, either from code or
+//! through the `repl`. A debugger borrows the computer, so any day can attach one to the computer
+//! it built (`Debugger::new(cabinet.computer_mut())`) and hand it back afterwards.
+
+use crate::{Instruction, IntcodeComputer, IntcodeError, RunState, Snapshot};
+use anyhow::{Context, Result, bail};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::io::{BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    /// Stop before the instruction at this address
+    Ip(usize),
+    /// Stop before any instruction with this opcode, whatever its parameter modes
+    Opcode(isize),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Ip(ip) => write!(f, "ip {ip:04}"),
+            Breakpoint::Opcode(opcode) => write!(f, "opcode {opcode}"),
+        }
+    }
+}
+
+/// Why the debugger handed control back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// The computer halted, produced output or is waiting for input, like `run` stops for
+    State(RunState),
+    Breakpoint(Breakpoint),
+    Watchpoint {
+        addr: usize,
+        old: isize,
+        new: isize,
+        /// What the step that changed it also stopped for, if anything
+        state: Option<RunState>,
+    },
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stop::State(state) => write!(f, "{state:?}"),
+            Stop::Breakpoint(breakpoint) => write!(f, "breakpoint on {breakpoint}"),
+            Stop::Watchpoint {
+                addr,
+                old,
+                new,
+                state,
+            } => {
+                write!(f, "[{addr}] changed {old} -> {new}")?;
+                match state {
+                    Some(state) => write!(f, ", then {state:?}"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+pub struct Debugger<'a> {
+    computer: &'a mut IntcodeComputer,
+    breakpoints: BTreeSet<Breakpoint>,
+    watchpoints: BTreeSet<usize>,
+    /// Where `resume` last stopped for a breakpoint, so resuming from there moves past it. Kept
+    /// until that instruction has run, which waiting for input doesn't count as.
+    stopped_at: Option<usize>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(computer: &'a mut IntcodeComputer) -> Self {
+        Self {
+            computer,
+            breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            stopped_at: None,
+        }
+    }
+
+    pub fn computer(&self) -> &IntcodeComputer {
+        self.computer
+    }
+
+    /// For feeding input or patching memory between steps.
+    pub fn computer_mut(&mut self) -> &mut IntcodeComputer {
+        self.computer
+    }
+
+    pub fn breakpoints(&self) -> impl Iterator<Item = &Breakpoint> {
+        self.breakpoints.iter()
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.insert(breakpoint);
+    }
+
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        self.breakpoints.remove(&breakpoint)
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = &usize> {
+        self.watchpoints.iter()
+    }
+
+    /// Stops whenever the value at `addr` changes.
+    pub fn add_watchpoint(&mut self, addr: usize) {
+        self.watchpoints.insert(addr);
+    }
+
+    pub fn remove_watchpoint(&mut self, addr: usize) -> bool {
+        self.watchpoints.remove(&addr)
+    }
+
+    /// Executes a single instruction, regardless of breakpoints.
+    pub fn step(&mut self) -> Result<Option<Stop>, IntcodeError> {
+        let watched: Vec<_> = self
+            .watchpoints
+            .iter()
+            .map(|&addr| (addr, self.computer.memory[addr]))
+            .collect();
+
+        let state = self.computer.step()?;
+        if state != Some(RunState::AwaitingInput) {
+            self.stopped_at = None;
+        }
+
+        for (addr, old) in watched {
+            let new = self.computer.memory[addr];
+            if new != old {
+                return Ok(Some(Stop::Watchpoint {
+                    addr,
+                    old,
+                    new,
+                    state,
+                }));
+            }
+        }
+        Ok(state.map(Stop::State))
+    }
+
+    /// Runs until a breakpoint, a watchpoint or a state `run` would stop for. Breakpoints are
+    /// checked before every instruction, except that resuming from the breakpoint it last stopped
+    /// at runs that instruction rather than stopping there again, also when it had to wait for
+    /// input first.
+    pub fn resume(&mut self) -> Result<Stop, IntcodeError> {
+        loop {
+            let ip = self.computer.ip();
+            if self.stopped_at != Some(ip)
+                && let Some(&breakpoint) = self.breakpoints.iter().find(|b| self.is_at(b))
+            {
+                self.stopped_at = Some(ip);
+                return Ok(Stop::Breakpoint(breakpoint));
+            }
+            if let Some(stop) = self.step()? {
+                return Ok(stop);
+            }
+        }
+    }
+
+    fn is_at(&self, breakpoint: &Breakpoint) -> bool {
+        let ip = self.computer.ip();
+        match *breakpoint {
+            Breakpoint::Ip(addr) => addr == ip,
+            Breakpoint::Opcode(opcode) => self.computer.memory[ip] % 100 == opcode,
+        }
+    }
+
+    /// The instruction at `ip` as `0042: ADD [rb+3], #5 -> [120]`.
+    pub fn current_instruction(&self) -> String {
+        let ip = self.computer.ip();
+        match Instruction::decode(&self.computer.memory, ip) {
+            Ok(instruction) => format!("{ip:04}: {instruction}"),
+            Err(_) => format!("{ip:04}: DATA {}", self.computer.memory[ip]),
+        }
+    }
+}
+
+/// Keeps a mistyped `mem` count from trying to show (and allocate) most of memory.
+const MAX_MEM_CELLS: usize = 4096;
+
+const HELP: &str = "\
+step [n]               execute n (default 1) instructions, ignoring breakpoints
+continue               run until a breakpoint, watchpoint, output, input request or halt
+break <addr>           stop before the instruction at addr
+break op <opcode>      stop before any instruction with the opcode
+unbreak <addr>         remove a breakpoint (unbreak op <opcode> for opcodes)
+watch <addr>           stop when the value at addr changes
+unwatch <addr>         remove a watchpoint
+info                   show ip, the relative base, the queues, breakpoints and watchpoints
+mem <addr> [count]     show count (default 8) memory cells from addr
+input <n>...           queue numbers as input
+ascii <text>           queue text and a newline as ASCII input
+output                 show and clear the output queue
+save <file>            save the computer's state to file
+load <file>            restore a state saved with save
+help                   show this
+quit                   stop debugging";
+
+/// Runs debugger commands read line by line from `commands` against `computer`, writing what they
+/// show to `out`, until `quit` or the commands run out. Bad commands and program faults are
+/// reported without ending the session.
+pub fn repl(
+    computer: &mut IntcodeComputer,
+    commands: impl BufRead,
+    mut out: impl Write,
+) -> Result<()> {
+    let mut debugger = Debugger::new(computer);
+    writeln!(out, "{}", debugger.current_instruction())?;
+    write!(out, "> ")?;
+    out.flush()?;
+    for line in commands.lines() {
+        let line = line?;
+        let words: Vec<_> = line.split_ascii_whitespace().collect();
+        match words.first() {
+            Some(&("quit" | "q")) => break,
+            Some(_) => {
+                if let Err(e) = command(&mut debugger, &line, &words, &mut out) {
+                    writeln!(out, "error: {e:#}")?;
+                }
+            }
+            None => {}
+        }
+        write!(out, "> ")?;
+        out.flush()?;
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+fn command(
+    debugger: &mut Debugger,
+    line: &str,
+    words: &[&str],
+    out: &mut impl Write,
+) -> Result<()> {
+    match words {
+        ["step" | "s"] => step(debugger, 1, out)?,
+        ["step" | "s", n] => step(debugger, n.parse()?, out)?,
+        ["continue" | "c"] => {
+            let stop = debugger.resume()?;
+            writeln!(out, "stopped: {stop}")?;
+            writeln!(out, "{}", debugger.current_instruction())?;
+        }
+        ["break" | "b", "op", opcode] => {
+            debugger.add_breakpoint(Breakpoint::Opcode(opcode.parse()?))
+        }
+        ["break" | "b", addr] => debugger.add_breakpoint(Breakpoint::Ip(addr.parse()?)),
+        ["unbreak", "op", opcode] => {
+            if !debugger.remove_breakpoint(Breakpoint::Opcode(opcode.parse()?)) {
+                bail!("No breakpoint on opcode {opcode}");
+            }
+        }
+        ["unbreak", addr] => {
+            if !debugger.remove_breakpoint(Breakpoint::Ip(addr.parse()?)) {
+                bail!("No breakpoint at {addr}");
+            }
+        }
+        ["watch" | "w", addr] => debugger.add_watchpoint(addr.parse()?),
+        ["unwatch", addr] => {
+            if !debugger.remove_watchpoint(addr.parse()?) {
+                bail!("No watchpoint on {addr}");
+            }
+        }
+        ["info" | "i"] => {
+            let computer = debugger.computer();
+            writeln!(out, "{}", debugger.current_instruction())?;
+            writeln!(out, "relative base: {}", computer.relative_base())?;
+            writeln!(out, "input: {:?}", computer.input)?;
+            writeln!(out, "output: {:?}", computer.output)?;
+            let breakpoints: Vec<_> = debugger.breakpoints().map(|b| b.to_string()).collect();
+            writeln!(out, "breakpoints: {}", breakpoints.join(", "))?;
+            let watchpoints: Vec<_> = debugger.watchpoints().map(|w| w.to_string()).collect();
+            writeln!(out, "watchpoints: {}", watchpoints.join(", "))?;
+        }
+        ["mem" | "x", addr, rest @ ..] => {
+            let addr: usize = addr.parse()?;
+            let count = match rest {
+                [] => 8,
+                [count] => count.parse()?,
+                _ => bail!("Usage: mem <addr> [count]"),
+            };
+            if count > MAX_MEM_CELLS {
+                bail!("mem shows at most {MAX_MEM_CELLS} cells at a time");
+            }
+            let Some(end) = addr.checked_add(count) else {
+                bail!("{count} cells from {addr} runs past the last address");
+            };
+            let values = debugger.computer().memory.range(addr..end);
+            for (i, chunk) in values.chunks(8).enumerate() {
+                let chunk: Vec<_> = chunk.iter().map(isize::to_string).collect();
+                writeln!(out, "{:04}: {}", addr + i * 8, chunk.join(", "))?;
+            }
+        }
+        ["input", numbers @ ..] if !numbers.is_empty() => {
+            let numbers = numbers
+                .iter()
+                .map(|n| n.parse())
+                .collect::<Result<Vec<isize>, _>>()?;
+            debugger.computer_mut().input.extend(numbers);
+        }
+        ["ascii", ..] => {
+            let text = line
+                .trim_start()
+                .strip_prefix("ascii")
+                .context("Usage: ascii <text>")?
+                .trim();
+            let input = &mut debugger.computer_mut().input;
+            input.extend(text.bytes().map(isize::from));
+            input.push_back(b'\n'.into());
+        }
+        ["output" | "o"] => {
+            let output: Vec<_> = debugger.computer_mut().output.drain(..).collect();
+            writeln!(out, "{output:?}")?;
+            if let Some(text) = ascii(&output) {
+                write!(out, "{text}")?;
+            }
+        }
+        ["save", path] => debugger.computer().snapshot().save(path)?,
+        ["load", path] => {
+            let snapshot = Snapshot::load(path)?;
+            debugger.computer_mut().restore(&snapshot);
+            writeln!(out, "{}", debugger.current_instruction())?;
+        }
+        ["help" | "h"] => writeln!(out, "{HELP}")?,
+        _ => bail!("Unknown command: {line} (try help)"),
+    }
+    Ok(())
+}
+
+fn step(debugger: &mut Debugger, n: usize, out: &mut impl Write) -> Result<()> {
+    for _ in 0..n {
+        if let Some(stop) = debugger.step()? {
+            writeln!(out, "stopped: {stop}")?;
+            break;
+        }
+    }
+    writeln!(out, "{}", debugger.current_instruction())?;
+    Ok(())
+}
+
+/// The output as text, if it's all printable ASCII (like the droids' prompts).
+fn ascii(output: &[isize]) -> Option<String> {
+    output
+        .iter()
+        .map(|&c| {
+            u8::try_from(c)
+                .ok()
+                .filter(|c| c.is_ascii_graphic() || c.is_ascii_whitespace())
+                .map(char::from)
+        })
+        .collect::<Option<String>>()
+        .filter(|text| !text.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assemble, parse_program};
+
+    #[test]
+    fn test_examples() {
+        // Counts [100] up from its input to 10, outputting each count, then halts
+        let program = "3,100,4,100,1001,100,1,100,1007,100,10,101,1005,101,2,99";
+        let mut computer = IntcodeComputer::new(&parse_program(program).unwrap());
+        computer.input.push_back(7);
+        let mut debugger = Debugger::new(&mut computer);
+
+        assert_eq!(debugger.step().unwrap(), None);
+        assert_eq!(debugger.current_instruction(), "0002: OUT [100]");
+
+        debugger.add_breakpoint(Breakpoint::Opcode(7));
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::State(RunState::ProducedOutput)
+        );
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Breakpoint(Breakpoint::Opcode(7))
+        );
+        assert_eq!(debugger.computer().ip(), 8);
+
+        debugger.remove_breakpoint(Breakpoint::Opcode(7));
+        debugger.add_watchpoint(100);
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::State(RunState::ProducedOutput)
+        );
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Watchpoint {
+                addr: 100,
+                old: 8,
+                new: 9,
+                state: None,
+            }
+        );
+
+        let mut out = Vec::new();
+        repl(
+            &mut computer,
+            "mem 100 2\nmem 18446744073709551615 8\ncontinue\noutput\nc\nc\nc\n".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+0008: LT [100], #10 -> [101]
+> 0100: 9, 1
+> error: 8 cells from 18446744073709551615 runs past the last address
+> stopped: ProducedOutput
+0004: ADD [100], #1 -> [100]
+> [7, 8, 9]
+> stopped: Halted
+0015: HLT
+> stopped: Halted
+0015: HLT
+> stopped: Halted
+0015: HLT
+> \n"
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_after_output() {
+        let program = assemble("out #1\nadd #1, #1 -> [9]\nout #2\nhlt").unwrap();
+        let mut computer = IntcodeComputer::new(&program);
+        let mut debugger = Debugger::new(&mut computer);
+        debugger.add_breakpoint(Breakpoint::Ip(2));
+
+        let stops: Vec<_> = (0..4).map(|_| debugger.resume().unwrap()).collect();
+        assert_eq!(
+            stops,
+            [
+                Stop::State(RunState::ProducedOutput),
+                Stop::Breakpoint(Breakpoint::Ip(2)),
+                Stop::State(RunState::ProducedOutput),
+                Stop::State(RunState::Halted),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_after_step() {
+        let program = assemble("add #1, #1 -> [9]\nout #2\nhlt").unwrap();
+        let mut computer = IntcodeComputer::new(&program);
+        let mut debugger = Debugger::new(&mut computer);
+        debugger.add_breakpoint(Breakpoint::Ip(4));
+
+        assert_eq!(debugger.step().unwrap(), None);
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Breakpoint(Breakpoint::Ip(4))
+        );
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::State(RunState::ProducedOutput)
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_awaiting_input() {
+        let program = assemble("in [7]\nout [7]\nhlt").unwrap();
+        let mut computer = IntcodeComputer::new(&program);
+        let mut debugger = Debugger::new(&mut computer);
+        debugger.add_breakpoint(Breakpoint::Opcode(3));
+
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::Breakpoint(Breakpoint::Opcode(3))
+        );
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::State(RunState::AwaitingInput)
+        );
+        debugger.computer_mut().input.push_back(5);
+        assert_eq!(
+            debugger.resume().unwrap(),
+            Stop::State(RunState::ProducedOutput)
+        );
+        assert_eq!(debugger.computer().output, [5]);
+    }
+
+    #[test]
+    fn test_watchpoint_display() {
+        let stop = Stop::Watchpoint {
+            addr: 7,
+            old: 0,
+            new: 5,
+            state: Some(RunState::ProducedOutput),
+        };
+        assert_eq!(stop.to_string(), "[7] changed 0 -> 5, then ProducedOutput");
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Instruction, Memory, Program};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// How many data cells go on a line of the listing.
+const DATA_PER_LINE: usize = 8;
+
+/// Lists a program as one instruction per line, e.g. `0042: ADD [rb+3], #5 -> [120]`, with an
+/// `L<address>:` label ahead of every known jump target that starts a line. Jumps to anywhere
+/// else keep their target as a number.
+///
+/// Code and data are told apart by following the program's control flow from address 0: through
+/// each instruction, into known jump targets and past calls (a constant store of the return
+/// address right before an unconditional jump). Whatever that doesn't reach, like code only
+/// jumped to through memory, is listed as data.
+pub fn disassemble(program: &Program) -> String {
+    let memory = Memory::new(program);
+    let decode = |ip: usize| {
+        Instruction::decode(&memory, ip)
+            .ok()
+            .filter(|instruction| ip + instruction.size() <= program.len())
+    };
+
+    let mut code = BTreeMap::new();
+    let mut labels = BTreeSet::new();
+    let mut to_trace = vec![0];
+    while let Some(mut ip) = to_trace.pop() {
+        let mut stored = None;
+        while !code.contains_key(&ip) {
+            let Some(instruction) = decode(ip) else {
+                break;
+            };
+            let next = ip + instruction.size();
+            if let Some(target) = instruction.jump_target() {
+                labels.insert(target);
+                to_trace.push(target);
+            }
+            let falls_through = match instruction {
+                Instruction::Halt => false,
+                _ if instruction.is_unconditional_jump() => stored == Some(next),
+                _ => true,
+            };
+            stored = constant_store(&instruction);
+            code.insert(ip, instruction);
+            if !falls_through {
+                break;
+            }
+            ip = next;
+        }
+    }
+
+    // Split into lines first so that jumps can tell whether their target gets a label
+    let mut lines = Vec::new();
+    let mut addr = 0;
+    while addr < program.len() {
+        let start = addr;
+        if let Some(instruction) = code.get(&addr) {
+            addr += instruction.size();
+        } else {
+            addr += 1;
+            while addr < program.len()
+                && addr - start < DATA_PER_LINE
+                && !code.contains_key(&addr)
+                && !labels.contains(&addr)
+            {
+                addr += 1;
+            }
+        }
+        lines.push(start..addr);
+    }
+    let starts: BTreeSet<_> = lines.iter().map(|line| line.start).collect();
+
+    let mut listing = String::new();
+    for line in lines {
+        let addr = line.start;
+        if labels.contains(&addr) {
+            writeln!(listing, "L{addr:04}:").unwrap();
+        }
+        match code.get(&addr) {
+            // A target outside the program or inside another instruction gets no label, so it's
+            // listed as the number it is
+            Some(instruction)
+                if instruction
+                    .jump_target()
+                    .is_some_and(|target| !starts.contains(&target)) =>
+            {
+                let [condition, target] = instruction.params() else {
+                    unreachable!("jumps have two parameters")
+                };
+                let mnemonic = instruction.mnemonic();
+                writeln!(listing, "{addr:04}: {mnemonic} {condition}, {target}").unwrap();
+            }
+            Some(instruction) => writeln!(listing, "{addr:04}: {instruction}").unwrap(),
+            None => {
+                let data: Vec<_> = program[line].iter().map(isize::to_string).collect();
+                writeln!(listing, "{addr:04}: DATA {}", data.join(", ")).unwrap();
+            }
+        }
+    }
+    listing
+}
+
+/// The value an instruction stores when it only works on immediates, like a return address
+/// being pushed with `ADD #42, #0 -> [rb+1]`.
+fn constant_store(instruction: &Instruction) -> Option<usize> {
+    use crate::Mode::Immediate;
+    let value = match instruction {
+        Instruction::Add([a, b, _]) if a.mode == Immediate && b.mode == Immediate => {
+            a.value + b.value
+        }
+        Instruction::Multiply([a, b, _]) if a.mode == Immediate && b.mode == Immediate => {
+            a.value * b.value
+        }
+        _ => return None,
+    };
+    usize::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assemble, parse_program};
+
+    #[test]
+    fn test_examples() {
+        // Calls a subroutine at 10 that doubles [rb+0] and returns through [rb+1], then halts,
+        // leaving a couple of data cells after the code
+        let program = "109,20,21101,9,0,1,1105,1,10,99,\
+22201,0,0,0,2106,0,1,7,-3";
+        assert_eq!(
+            disassemble(&parse_program(program).unwrap()),
+            "\
+0000: ARB #20
+0002: ADD #9, #0 -> [rb+1]
+0006: JT #1, L0010
+0009: HLT
+L0010:
+0010: ADD [rb+0], [rb+0] -> [rb+0]
+0014: JF #0, [rb+1]
+0017: DATA 7, -3
+"
+        );
+
+        // Jumps out of the program and into the middle of an instruction
+        let program = parse_program("1005,7,100,1106,0,4,99,0").unwrap();
+        assert_eq!(
+            disassemble(&program),
+            "\
+0000: JT [7], #100
+0003: JF #0, #4
+0006: DATA 99, 0
+"
+        );
+        assert_eq!(assemble(&disassemble(&program)).unwrap(), program);
+    }
+}
@@ -0,0 +1,261 @@
+//! Mapping an unknown maze through a droid that can only be told to take a step and reports what
+//! it ran into, like day 15's repair droid. The explorer walks the maze depth first on the one
+//! droid, backtracking the way it came, and records everything it finds in a `ShipMap`.
+
+use crate::grid::{Heading, Pos};
+use crate::{IntcodeComputer, RunState};
+use anyhow::{Result, bail, ensure};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+/// What a droid found when told to move: status 0, 1 or 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    /// The droid stayed where it was.
+    Wall,
+    Open,
+    /// Open, with what the droid is looking for in it (day 15's oxygen system).
+    Goal,
+}
+
+/// A droid that can be moved a step at a time.
+pub trait Remote {
+    /// Tells the droid to step towards `heading`, returning what's there. It doesn't move into
+    /// walls.
+    fn go(&mut self, heading: Heading) -> Result<Cell>;
+}
+
+// Movement commands 1-4 go north, south, west and east
+impl Remote for IntcodeComputer {
+    fn go(&mut self, heading: Heading) -> Result<Cell> {
+        let command = match heading {
+            Heading::North => 1,
+            Heading::South => 2,
+            Heading::West => 3,
+            Heading::East => 4,
+        };
+        self.input.push_back(command);
+        match self.run()? {
+            RunState::ProducedOutput => match self.output.pop_front().unwrap() {
+                0 => Ok(Cell::Wall),
+                1 => Ok(Cell::Open),
+                2 => Ok(Cell::Goal),
+                status => bail!("Unknown status {status}"),
+            },
+            state => bail!("{state:?} instead of moving {heading:?}"),
+        }
+    }
+}
+
+/// Steers a droid around a maze, mapping it from the droid's starting point at the origin.
+#[derive(Debug)]
+pub struct RemoteExplorer<R> {
+    remote: R,
+    pos: Pos,
+    map: ShipMap,
+}
+
+impl<R: Remote> RemoteExplorer<R> {
+    pub fn new(remote: R) -> Self {
+        let mut map = ShipMap::default();
+        map.insert(Pos::default(), Cell::Open);
+        Self {
+            remote,
+            pos: Pos::default(),
+            map,
+        }
+    }
+
+    pub fn pos(&self) -> Pos {
+        self.pos
+    }
+
+    pub fn map(&self) -> &ShipMap {
+        &self.map
+    }
+
+    /// Moves the droid a step if it can, recording what it found.
+    pub fn go(&mut self, heading: Heading) -> Result<Cell> {
+        let next = self.pos.step(heading);
+        let cell = self.remote.go(heading)?;
+        self.map.insert(next, cell);
+        if cell != Cell::Wall {
+            self.pos = next;
+        }
+        Ok(cell)
+    }
+
+    /// Maps every cell the droid can reach, and the walls around them.
+    pub fn explore(mut self) -> Result<ShipMap> {
+        // The way back to the start is to undo these in reverse
+        let mut path = Vec::new();
+        loop {
+            let unknown = Heading::ALL
+                .into_iter()
+                .find(|&heading| self.map.get(self.pos.step(heading)).is_none());
+            if let Some(heading) = unknown {
+                if self.go(heading)? != Cell::Wall {
+                    path.push(heading);
+                }
+            } else if let Some(heading) = path.pop() {
+                let back = self.go(heading.reverse())?;
+                ensure!(
+                    back != Cell::Wall,
+                    "The droid couldn't go back the way it came"
+                );
+            } else {
+                return Ok(self.map);
+            }
+        }
+    }
+}
+
+/// The cells a droid has found, and where it started.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ShipMap {
+    cells: HashMap<Pos, Cell>,
+    start: Pos,
+}
+
+impl ShipMap {
+    pub fn get(&self, pos: Pos) -> Option<Cell> {
+        self.cells.get(&pos).copied()
+    }
+
+    pub fn insert(&mut self, pos: Pos, cell: Cell) {
+        self.cells.insert(pos, cell);
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    pub fn goal(&self) -> Option<Pos> {
+        let mut cells = self.cells.iter();
+        cells.find_map(|(&pos, &cell)| (cell == Cell::Goal).then_some(pos))
+    }
+
+    /// The fewest steps from `from` to each cell that can be reached from it.
+    pub fn distances(&self, from: Pos) -> HashMap<Pos, usize> {
+        let mut distances = HashMap::from([(from, 0)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(pos) = queue.pop_front() {
+            let steps = distances[&pos] + 1;
+            for next in pos.neighbours() {
+                let open = matches!(self.get(next), Some(Cell::Open | Cell::Goal));
+                if open && !distances.contains_key(&next) {
+                    distances.insert(next, steps);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Draws the map with `#` for walls, `.` for open cells, `O` for the goal and spaces for
+    /// cells that haven't been found, except where `overlay` says to draw something else.
+    pub fn render(&self, mut overlay: impl FnMut(Pos) -> Option<char>) -> String {
+        let Some(min_x) = self.cells.keys().map(|pos| pos.x).min() else {
+            return String::new();
+        };
+        let max_x = self.cells.keys().map(|pos| pos.x).max().unwrap();
+        let min_y = self.cells.keys().map(|pos| pos.y).min().unwrap();
+        let max_y = self.cells.keys().map(|pos| pos.y).max().unwrap();
+
+        let rows = (min_y..=max_y).map(|y| {
+            let row = (min_x..=max_x).map(|x| {
+                let pos = Pos::new(x, y);
+                overlay(pos).unwrap_or(match self.get(pos) {
+                    None => ' ',
+                    Some(Cell::Wall) => '#',
+                    Some(Cell::Open) => '.',
+                    Some(Cell::Goal) => 'O',
+                })
+            });
+            row.collect::<String>().trim_end().to_string()
+        });
+        rows.collect::<Vec<_>>().join("\n")
+    }
+}
+
+// The map with the droid's start as `D`
+impl fmt::Display for ShipMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let start = self.start;
+        write!(f, "{}", self.render(|pos| (pos == start).then_some('D')))
+    }
+}
+
+// Reads what `Display` draws. Without a `D` the start is the top left.
+impl FromStr for ShipMap {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut map = ShipMap::default();
+        for (y, line) in s.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let pos = Pos::new(x as isize, y as isize);
+                let cell = match c {
+                    ' ' => continue,
+                    '#' => Cell::Wall,
+                    '.' => Cell::Open,
+                    'O' => Cell::Goal,
+                    'D' => {
+                        map.start = pos;
+                        Cell::Open
+                    }
+                    _ => bail!("Unknown cell {c:?}"),
+                };
+                map.insert(pos, cell);
+            }
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A droid in a maze that's already known
+    struct Maze {
+        map: ShipMap,
+        pos: Pos,
+    }
+
+    impl Remote for Maze {
+        fn go(&mut self, heading: Heading) -> Result<Cell> {
+            let next = self.pos.step(heading);
+            let cell = self.map.get(next).unwrap_or(Cell::Wall);
+            if cell != Cell::Wall {
+                self.pos = next;
+            }
+            Ok(cell)
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        // Day 15's example
+        let area = [" ##", "#D.##", "#.#..#", "#.O.#", " ###"].join("\n");
+        let map: ShipMap = area.parse().unwrap();
+        assert_eq!(map.to_string(), area);
+        assert_eq!(map.goal(), Some(Pos::new(2, 3)));
+        assert_eq!(map.distances(map.start())[&Pos::new(2, 3)], 3);
+
+        let pos = map.start();
+        let explored = RemoteExplorer::new(Maze { map, pos }).explore().unwrap();
+        assert_eq!(explored.to_string(), area);
+        assert_eq!(explored.goal(), Some(Pos::new(1, 2)));
+
+        let mut droid = RemoteExplorer::new(Maze {
+            map: area.parse().unwrap(),
+            pos: Pos::new(1, 1),
+        });
+        assert_eq!(droid.go(Heading::North).unwrap(), Cell::Wall);
+        assert_eq!(droid.go(Heading::East).unwrap(), Cell::Open);
+        assert_eq!(droid.pos(), Pos::new(1, 0));
+        assert_eq!(droid.map().to_string(), "#\nD.");
+    }
+}
@@ -0,0 +1,79 @@
+//! Positions and headings for the robots that move around a grid: day 11's painting robot, day
+//! 15's repair droid and day 17's vacuum robot. x grows to the right and y grows downwards, the
+//! way the puzzles draw their maps.
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Pos {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// The position one step away in `heading`.
+    pub fn step(self, heading: Heading) -> Self {
+        let (dx, dy) = heading.delta();
+        Self::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn neighbours(self) -> [Self; 4] {
+        Heading::ALL.map(|heading| self.step(heading))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Heading {
+    pub const ALL: [Heading; 4] = [Heading::North, Heading::South, Heading::West, Heading::East];
+
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Heading::North => (0, -1),
+            Heading::South => (0, 1),
+            Heading::West => (-1, 0),
+            Heading::East => (1, 0),
+        }
+    }
+
+    pub fn left(self) -> Self {
+        match self {
+            Heading::North => Heading::West,
+            Heading::West => Heading::South,
+            Heading::South => Heading::East,
+            Heading::East => Heading::North,
+        }
+    }
+
+    pub fn right(self) -> Self {
+        self.left().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Heading::North => Heading::South,
+            Heading::South => Heading::North,
+            Heading::West => Heading::East,
+            Heading::East => Heading::West,
+        }
+    }
+
+    /// The heading a robot drawn as `^`, `v`, `<` or `>` faces.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Heading::North),
+            'v' => Some(Heading::South),
+            '<' => Some(Heading::West),
+            '>' => Some(Heading::East),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,31 @@
 use anyhow::Result;
 use std::collections::VecDeque;
+use std::fmt;
 use std::iter::successors;
 use std::ops::{Div, Rem};
+use std::sync::{Arc, Mutex};
+
+pub mod ascii;
+mod assemble;
+pub mod debugger;
+mod disassemble;
+pub mod explore;
+pub mod grid;
+mod memory;
+pub mod network;
+mod snapshot;
+pub mod trace;
+
+pub use ascii::AsciiIo;
+pub use assemble::assemble;
+pub use debugger::Debugger;
+pub use disassemble::disassemble;
+pub use explore::{RemoteExplorer, ShipMap};
+pub use grid::{Heading, Pos};
+pub use memory::Memory;
+pub use network::Network;
+pub use snapshot::Snapshot;
+pub use trace::{Step, Tracer};
 
 pub type Program = Vec<isize>;
 
@@ -16,158 +40,301 @@ pub enum RunState {
     AwaitingInput,
 }
 
+/// A program fault, with the instruction it happened at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntcodeError {
+    pub ip: usize,
+    /// The faulting instruction, parameter modes included.
+    pub opcode: isize,
+    pub fault: Fault,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    UnknownOpcode,
+    UnknownMode(isize),
+    ImmediateWrite,
+    NegativeAddress(isize),
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ip {} (opcode {})", self.ip, self.opcode)?;
+        match self.fault {
+            Fault::UnknownOpcode => write!(f, ": unknown opcode"),
+            Fault::UnknownMode(mode) => write!(f, ": unknown mode {mode}"),
+            Fault::ImmediateWrite => write!(f, ": can't write in immediate mode"),
+            Fault::NegativeAddress(address) => write!(f, ": negative address {address}"),
+        }
+    }
+}
+
+impl std::error::Error for IntcodeError {}
+
+/// How an `IntcodeComputer` turns the memory at `ip` into an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Engine {
+    /// Decodes every instruction each time it's executed. This is the reference the cached
+    /// engine is checked against.
+    Interpreter,
+    /// Decodes each instruction once and reuses it until something writes over it.
+    #[default]
+    Cached,
+}
+
 #[derive(Debug, Clone)]
 pub struct IntcodeComputer {
     ip: usize,
     relative_base: isize,
-    pub memory: Vec<isize>,
+    engine: Engine,
+    pub memory: Memory,
     pub input: VecDeque<isize>,
     pub output: VecDeque<isize>,
+    /// Shared so that clones of a computer all report to the same tracer.
+    tracer: Option<Arc<Mutex<dyn Tracer>>>,
 }
 
 impl IntcodeComputer {
     pub fn new(program: &Program) -> Self {
-        // Allocate and zero-out extra main memory to allow writes past the program's end
-        let mut memory = program.clone();
-        memory.resize(program.len() * 50, 0);
-
         Self {
             ip: 0,
             relative_base: 0,
-            memory,
+            engine: Engine::default(),
+            memory: Memory::new(program),
             input: VecDeque::new(),
             output: VecDeque::new(),
+            tracer: None,
         }
     }
 
-    pub fn run(&mut self) -> RunState {
-        loop {
-            match self.decode() {
-                Instruction::Add(params) => {
-                    let result = self.param_value(&params[0]) + self.param_value(&params[1]);
-                    let addr = self.write_addr(&params[2]);
-                    self.memory[addr] = result;
-                    self.ip += params.len() + 1;
-                }
-                Instruction::Multiply(params) => {
-                    let result = self.param_value(&params[0]) * self.param_value(&params[1]);
-                    let addr = self.write_addr(&params[2]);
-                    self.memory[addr] = result;
-                    self.ip += params.len() + 1;
-                }
-                Instruction::Input(param) => {
-                    let Some(input) = self.input.pop_front() else {
-                        return RunState::AwaitingInput;
-                    };
-                    let addr = self.write_addr(&param);
-                    self.memory[addr] = input;
-                    self.ip += 2;
-                }
-                Instruction::Output(param) => {
-                    self.output.push_back(self.param_value(&param));
-                    self.ip += 2;
-                    return RunState::ProducedOutput;
-                }
-                Instruction::JumpIfTrue(params) => {
-                    if self.param_value(&params[0]) != 0 {
-                        self.ip = self.param_value(&params[1]) as usize;
-                    } else {
-                        self.ip += params.len() + 1;
-                    }
-                }
-                Instruction::JumpIfFalse(params) => {
-                    if self.param_value(&params[0]) == 0 {
-                        self.ip = self.param_value(&params[1]) as usize;
-                    } else {
-                        self.ip += params.len() + 1;
-                    }
-                }
-                Instruction::LessThan(params) => {
-                    let result =
-                        isize::from(self.param_value(&params[0]) < self.param_value(&params[1]));
-                    let addr = self.write_addr(&params[2]);
-                    self.memory[addr] = result;
-                    self.ip += params.len() + 1;
-                }
-                Instruction::Equals(params) => {
-                    let result =
-                        isize::from(self.param_value(&params[0]) == self.param_value(&params[1]));
-                    let addr = self.write_addr(&params[2]);
-                    self.memory[addr] = result;
-                    self.ip += params.len() + 1;
-                }
-                Instruction::AdjustRelativeBase(param) => {
-                    self.relative_base += self.param_value(&param);
-                    self.ip += 2;
+    /// Has every instruction executed from now on reported to `tracer`, or stops tracing.
+    pub fn set_tracer(&mut self, tracer: Option<Arc<Mutex<dyn Tracer>>>) {
+        self.tracer = tracer;
+    }
+
+    pub fn set_engine(&mut self, engine: Engine) {
+        self.engine = engine;
+    }
+
+    pub fn engine(&self) -> Engine {
+        self.engine
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn relative_base(&self) -> isize {
+        self.relative_base
+    }
+
+    pub fn run(&mut self) -> Result<RunState, IntcodeError> {
+        // Tracing is checked once here rather than on every instruction
+        if self.tracer.is_some() {
+            loop {
+                if let Some(state) = self.traced_step()? {
+                    return Ok(state);
                 }
-                Instruction::Halt => return RunState::Halted,
+            }
+        }
+        loop {
+            if let Some(state) = self.untraced_step()? {
+                return Ok(state);
             }
         }
     }
 
-    pub fn run_to_halt(&mut self) -> RunState {
+    pub fn run_to_halt(&mut self) -> Result<RunState, IntcodeError> {
         loop {
-            match self.run() {
-                RunState::Halted => return RunState::Halted,
+            match self.run()? {
+                RunState::Halted => return Ok(RunState::Halted),
                 RunState::ProducedOutput => continue,
-                RunState::AwaitingInput => return RunState::AwaitingInput,
+                RunState::AwaitingInput => return Ok(RunState::AwaitingInput),
             }
         }
     }
 
-    fn decode(&self) -> Instruction {
-        let instruction = self.memory[self.ip];
-        let mut mode_digits =
-            successors(Some(instruction.div_rem(100)), |dr| Some(dr.0.div_rem(10)));
+    /// Runs a copy of the computer with `input` queued until it stops, returning the state and
+    /// the copy. The computer is left as it was apart from taking on the instructions the copy
+    /// decoded, so a program that answers one query per run is only decoded once.
+    pub fn query(
+        &mut self,
+        input: impl IntoIterator<Item = isize>,
+    ) -> Result<(RunState, IntcodeComputer), IntcodeError> {
+        let mut copy = self.clone();
+        copy.input.extend(input);
+        let state = copy.run();
+        self.memory.learn_decoded(&copy.memory);
+        Ok((state?, copy))
+    }
 
-        let (_instruction, opcode) = mode_digits.next().unwrap();
+    /// Executes one instruction, returning the state if it's one `run` stops at.
+    pub fn step(&mut self) -> Result<Option<RunState>, IntcodeError> {
+        if self.tracer.is_some() {
+            self.traced_step()
+        } else {
+            self.untraced_step()
+        }
+    }
 
-        let mut params = mode_digits
-            .map(|dr| Mode::from(dr.1))
+    #[inline]
+    fn untraced_step(&mut self) -> Result<Option<RunState>, IntcodeError> {
+        self.execute().map_err(|fault| IntcodeError {
+            ip: self.ip,
+            opcode: self.memory[self.ip],
+            fault,
+        })
+    }
+
+    fn traced_step(&mut self) -> Result<Option<RunState>, IntcodeError> {
+        // A fault is left for execute to report
+        let step = self.trace_step().ok();
+        let state = self.untraced_step()?;
+        if let (Some(step), Some(tracer)) = (step, &self.tracer)
+            && state != Some(RunState::AwaitingInput)
+        {
+            tracer.lock().unwrap().record(&step);
+        }
+        Ok(state)
+    }
+
+    fn trace_step(&self) -> Result<Step, Fault> {
+        let instruction = Instruction::decode(&self.memory, self.ip)?;
+        let params = instruction.params();
+        let values = params
+            .iter()
             .enumerate()
-            .map(|(i, mode)| Parameter {
-                value: self.memory[self.ip + i + 1],
-                mode,
-            });
-
-        match opcode {
-            1 => Instruction::Add(take_params(&mut params)),
-            2 => Instruction::Multiply(take_params(&mut params)),
-            3 => Instruction::Input(params.next().unwrap()),
-            4 => Instruction::Output(params.next().unwrap()),
-            5 => Instruction::JumpIfTrue(take_params(&mut params)),
-            6 => Instruction::JumpIfFalse(take_params(&mut params)),
-            7 => Instruction::LessThan(take_params(&mut params)),
-            8 => Instruction::Equals(take_params(&mut params)),
-            9 => Instruction::AdjustRelativeBase(params.next().unwrap()),
-            99 => Instruction::Halt,
-            unknown => panic!("Unknown opcode {unknown}"),
-        }
-    }
-
-    fn param_value(&self, param: &Parameter) -> isize {
+            .map(|(i, param)| {
+                if instruction.writes() && i == params.len() - 1 {
+                    Ok(self.write_addr(param)? as isize)
+                } else {
+                    self.param_value(param)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Step {
+            ip: self.ip,
+            relative_base: self.relative_base,
+            instruction,
+            values,
+        })
+    }
+
+    // `ip` is only moved once the instruction succeeds so a fault leaves it on the faulting
+    // instruction. It's inlined so that `run`'s loop doesn't make a call per instruction.
+    #[inline(always)]
+    fn execute(&mut self) -> Result<Option<RunState>, Fault> {
+        let instruction = match self.engine {
+            Engine::Interpreter => Instruction::decode(&self.memory, self.ip)?,
+            Engine::Cached => self.memory.decode_cached(self.ip)?,
+        };
+        match instruction {
+            Instruction::Add(params) => {
+                let result = self.param_value(&params[0])? + self.param_value(&params[1])?;
+                self.write(&params[2], result)?;
+                self.ip += params.len() + 1;
+            }
+            Instruction::Multiply(params) => {
+                let result = self.param_value(&params[0])? * self.param_value(&params[1])?;
+                self.write(&params[2], result)?;
+                self.ip += params.len() + 1;
+            }
+            Instruction::Input(param) => {
+                let Some(&input) = self.input.front() else {
+                    return Ok(Some(RunState::AwaitingInput));
+                };
+                self.write(&param, input)?;
+                self.input.pop_front();
+                self.ip += 2;
+            }
+            Instruction::Output(param) => {
+                self.output.push_back(self.param_value(&param)?);
+                self.ip += 2;
+                return Ok(Some(RunState::ProducedOutput));
+            }
+            Instruction::JumpIfTrue(params) => {
+                if self.param_value(&params[0])? != 0 {
+                    self.ip = address(self.param_value(&params[1])?)?;
+                } else {
+                    self.ip += params.len() + 1;
+                }
+            }
+            Instruction::JumpIfFalse(params) => {
+                if self.param_value(&params[0])? == 0 {
+                    self.ip = address(self.param_value(&params[1])?)?;
+                } else {
+                    self.ip += params.len() + 1;
+                }
+            }
+            Instruction::LessThan(params) => {
+                let result =
+                    isize::from(self.param_value(&params[0])? < self.param_value(&params[1])?);
+                self.write(&params[2], result)?;
+                self.ip += params.len() + 1;
+            }
+            Instruction::Equals(params) => {
+                let result =
+                    isize::from(self.param_value(&params[0])? == self.param_value(&params[1])?);
+                self.write(&params[2], result)?;
+                self.ip += params.len() + 1;
+            }
+            Instruction::AdjustRelativeBase(param) => {
+                self.relative_base += self.param_value(&param)?;
+                self.ip += 2;
+            }
+            Instruction::Halt => return Ok(Some(RunState::Halted)),
+        }
+        Ok(None)
+    }
+
+    #[inline]
+    fn param_value(&self, param: &Parameter) -> Result<isize, Fault> {
         match param.mode {
-            Mode::Position => self.memory[param.value as usize],
-            Mode::Immediate => param.value,
-            Mode::Relative => self.memory[(param.value + self.relative_base) as usize],
+            Mode::Position => Ok(self.memory[address(param.value)?]),
+            Mode::Immediate => Ok(param.value),
+            Mode::Relative => Ok(self.memory[address(param.value + self.relative_base)?]),
         }
     }
 
-    fn write_addr(&self, param: &Parameter) -> usize {
+    #[inline]
+    fn write_addr(&self, param: &Parameter) -> Result<usize, Fault> {
         match param.mode {
-            Mode::Position => param.value as usize,
-            Mode::Immediate => panic!("Can't write in immediate mode"),
-            Mode::Relative => (param.value + self.relative_base) as usize,
+            Mode::Position => address(param.value),
+            Mode::Immediate => Err(Fault::ImmediateWrite),
+            Mode::Relative => address(param.value + self.relative_base),
         }
     }
+
+    #[inline(always)]
+    fn write(&mut self, param: &Parameter, value: isize) -> Result<(), Fault> {
+        let addr = self.write_addr(param)?;
+        self.memory[addr] = value;
+        Ok(())
+    }
+}
+
+fn address(value: isize) -> Result<usize, Fault> {
+    usize::try_from(value).map_err(|_| Fault::NegativeAddress(value))
+}
+
+// The mode digits run on forever (as zeros) so there's always a next parameter to decode.
+fn take_param(
+    params: &mut impl Iterator<Item = Result<Parameter, Fault>>,
+) -> Result<Parameter, Fault> {
+    params.next().unwrap()
 }
 
-fn take_params<const N: usize>(params: &mut impl Iterator<Item = Parameter>) -> [Parameter; N] {
-    std::array::from_fn(|_| {
-        params
-            .next()
-            .expect("instruction missing expected parameter")
-    })
+fn take_params<const N: usize>(
+    params: &mut impl Iterator<Item = Result<Parameter, Fault>>,
+) -> Result<[Parameter; N], Fault> {
+    let mut taken = [Parameter {
+        value: 0,
+        mode: Mode::Position,
+    }; N];
+    for param in taken.iter_mut() {
+        *param = take_param(params)?;
+    }
+    Ok(taken)
 }
 
 trait DivRem: Sized {
@@ -180,8 +347,8 @@ impl<T: Copy + Div<Output = T> + Rem<Output = T>> DivRem for T {
     }
 }
 
-#[derive(Debug, Clone)]
-enum Instruction {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
     Add([Parameter; 3]),
     Multiply([Parameter; 3]),
     Input(Parameter),
@@ -194,26 +361,371 @@ enum Instruction {
     Halt,
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Parameter {
-    value: isize,
-    mode: Mode,
+impl Instruction {
+    /// Decodes the instruction at `ip`.
+    pub fn decode(memory: &Memory, ip: usize) -> Result<Self, Fault> {
+        let instruction = memory[ip];
+        let mut mode_digits =
+            successors(Some(instruction.div_rem(100)), |dr| Some(dr.0.div_rem(10)));
+
+        let (_instruction, opcode) = mode_digits.next().unwrap();
+
+        let mut params = mode_digits.enumerate().map(|(i, dr)| {
+            Ok(Parameter {
+                value: memory[ip + i + 1],
+                mode: Mode::try_from(dr.1)?,
+            })
+        });
+
+        Ok(match opcode {
+            1 => Self::Add(take_params(&mut params)?),
+            2 => Self::Multiply(take_params(&mut params)?),
+            3 => Self::Input(take_param(&mut params)?),
+            4 => Self::Output(take_param(&mut params)?),
+            5 => Self::JumpIfTrue(take_params(&mut params)?),
+            6 => Self::JumpIfFalse(take_params(&mut params)?),
+            7 => Self::LessThan(take_params(&mut params)?),
+            8 => Self::Equals(take_params(&mut params)?),
+            9 => Self::AdjustRelativeBase(take_param(&mut params)?),
+            99 => Self::Halt,
+            _ => return Err(Fault::UnknownOpcode),
+        })
+    }
+
+    pub fn params(&self) -> &[Parameter] {
+        match self {
+            Self::Add(params)
+            | Self::Multiply(params)
+            | Self::LessThan(params)
+            | Self::Equals(params) => params,
+            Self::JumpIfTrue(params) | Self::JumpIfFalse(params) => params,
+            Self::Input(param) | Self::Output(param) | Self::AdjustRelativeBase(param) => {
+                std::slice::from_ref(param)
+            }
+            Self::Halt => &[],
+        }
+    }
+
+    /// Whether the last parameter is written to.
+    pub fn writes(&self) -> bool {
+        matches!(
+            self,
+            Self::Add(_) | Self::Multiply(_) | Self::Input(_) | Self::LessThan(_) | Self::Equals(_)
+        )
+    }
+
+    /// How many memory cells the instruction takes up, opcode included.
+    pub fn size(&self) -> usize {
+        self.params().len() + 1
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Self::Add(_) => "ADD",
+            Self::Multiply(_) => "MUL",
+            Self::Input(_) => "IN",
+            Self::Output(_) => "OUT",
+            Self::JumpIfTrue(_) => "JT",
+            Self::JumpIfFalse(_) => "JF",
+            Self::LessThan(_) => "LT",
+            Self::Equals(_) => "EQ",
+            Self::AdjustRelativeBase(_) => "ARB",
+            Self::Halt => "HLT",
+        }
+    }
+
+    /// Where a jump goes when its target is known without running the program.
+    pub fn jump_target(&self) -> Option<usize> {
+        match self {
+            Self::JumpIfTrue([_, target]) | Self::JumpIfFalse([_, target]) => match target.mode {
+                Mode::Immediate => usize::try_from(target.value).ok(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// A jump whose condition is an immediate that always takes it.
+    pub fn is_unconditional_jump(&self) -> bool {
+        match self {
+            Self::JumpIfTrue([condition, _]) => {
+                condition.mode == Mode::Immediate && condition.value != 0
+            }
+            Self::JumpIfFalse([condition, _]) => {
+                condition.mode == Mode::Immediate && condition.value == 0
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Prints as e.g. `ADD [rb+3], #5 -> [120]`, with the written parameter after the arrow and
+/// known jump targets as `L<address>` labels.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        match self {
+            Self::Add([a, b, to])
+            | Self::Multiply([a, b, to])
+            | Self::LessThan([a, b, to])
+            | Self::Equals([a, b, to]) => write!(f, " {a}, {b} -> {to}"),
+            Self::Input(to) => write!(f, " -> {to}"),
+            Self::Output(param) | Self::AdjustRelativeBase(param) => write!(f, " {param}"),
+            Self::JumpIfTrue([condition, target]) | Self::JumpIfFalse([condition, target]) => {
+                match self.jump_target() {
+                    Some(label) => write!(f, " {condition}, L{label:04}"),
+                    None => write!(f, " {condition}, {target}"),
+                }
+            }
+            Self::Halt => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+    pub value: isize,
+    pub mode: Mode,
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.mode {
+            Mode::Position => write!(f, "[{}]", self.value),
+            Mode::Immediate => write!(f, "#{}", self.value),
+            Mode::Relative if self.value < 0 => write!(f, "[rb{}]", self.value),
+            Mode::Relative => write!(f, "[rb+{}]", self.value),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Mode {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
     Position,
     Immediate,
     Relative,
 }
 
-impl From<isize> for Mode {
-    fn from(value: isize) -> Self {
+impl TryFrom<isize> for Mode {
+    type Error = Fault;
+
+    fn try_from(value: isize) -> Result<Self, Fault> {
         match value {
-            0 => Self::Position,
-            1 => Self::Immediate,
-            2 => Self::Relative,
-            v => panic!("Unknown mode {v}"),
+            0 => Ok(Self::Position),
+            1 => Ok(Self::Immediate),
+            2 => Ok(Self::Relative),
+            v => Err(Fault::UnknownMode(v)),
+        }
+    }
+}
+
+impl From<Mode> for isize {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Position => 0,
+            Mode::Immediate => 1,
+            Mode::Relative => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn computer(source: &str) -> IntcodeComputer {
+        IntcodeComputer::new(&assemble(source).unwrap())
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        // Day 2's first example, which leaves 3500 at 0
+        let mut computer = computer(
+            "\
+add [9], [10] -> [3]
+mul [3], [11] -> [0]
+hlt
+data 30, 40, 50",
+        );
+        assert_eq!(computer.run_to_halt().unwrap(), RunState::Halted);
+        assert_eq!(computer.memory[0], 3500);
+    }
+
+    #[test]
+    fn test_input_and_output() {
+        let mut computer = computer(
+            "\
+loop: in -> [x]
+      mul [x], #2 -> [x]
+      out [x]
+      jt #1, loop
+x:    data 0",
+        );
+        assert_eq!(computer.run().unwrap(), RunState::AwaitingInput);
+        computer.input.extend([3, 5]);
+        assert_eq!(computer.run().unwrap(), RunState::ProducedOutput);
+        assert_eq!(computer.run().unwrap(), RunState::ProducedOutput);
+        assert_eq!(computer.run().unwrap(), RunState::AwaitingInput);
+        assert_eq!(computer.output, [6, 10]);
+    }
+
+    #[test]
+    fn test_query() {
+        let mut doubler = computer("in -> [x]\nmul [x], #2 -> [x]\nout [x]\nhlt\nx: data 0");
+        for n in [3, 5] {
+            let (state, copy) = doubler.query([n]).unwrap();
+            assert_eq!(state, RunState::ProducedOutput);
+            assert_eq!(copy.output, [n * 2]);
         }
+        // The computer itself hasn't run, but has the instructions its copies decoded
+        assert_eq!(doubler.ip(), 0);
+        assert_eq!(doubler.memory[9], 0);
+        assert!(doubler.memory.cached(2).is_some());
+    }
+
+    #[test]
+    fn test_comparisons_and_jumps() {
+        // Outputs 999, 1000 or 1001 for input below, equal to or above 8
+        let compare_to_8 = assemble(
+            "\
+      in -> [x]
+      lt [x], #8 -> [t]
+      jt [t], below
+      eq [x], #8 -> [t]
+      jf [t], above
+      out #1000
+      hlt
+below: out #999
+      hlt
+above: out #1001
+      hlt
+x:    data 0
+t:    data 0",
+        )
+        .unwrap();
+        for (input, output) in [(7, 999), (8, 1000), (9, 1001)] {
+            let mut computer = IntcodeComputer::new(&compare_to_8);
+            computer.input.push_back(input);
+            computer.run_to_halt().unwrap();
+            assert_eq!(computer.output, [output]);
+        }
+    }
+
+    #[test]
+    fn test_relative_base_and_memory() {
+        // Writes far past the program through the relative base, reading back untouched memory as 0
+        let mut computer = computer(
+            "\
+arb #1000000
+add [rb+5], #7 -> [rb+1]
+arb #-1
+out [rb+2]
+out [rb+50]
+hlt",
+        );
+        computer.run_to_halt().unwrap();
+        assert_eq!(computer.relative_base(), 999999);
+        assert_eq!(computer.output, [7, 0]);
+        assert_eq!(computer.memory[1000001], 7);
+
+        // Addresses far beyond that only cost the page they land on
+        let mut computer = IntcodeComputer::new(
+            &parse_program("1101,1,1,1000000000000,4,1000000000000,99").unwrap(),
+        );
+        computer.run_to_halt().unwrap();
+        assert_eq!(computer.output, [2]);
+    }
+
+    #[test]
+    fn test_faults() {
+        let fault = |program: &[isize]| IntcodeComputer::new(&program.to_vec()).run().unwrap_err();
+        assert_eq!(
+            fault(&[1, 0, 0, 0, 77]),
+            IntcodeError {
+                ip: 4,
+                opcode: 77,
+                fault: Fault::UnknownOpcode
+            }
+        );
+        assert_eq!(fault(&[304, 0]).fault, Fault::UnknownMode(3));
+        assert_eq!(fault(&[11101, 1, 1, 0]).fault, Fault::ImmediateWrite);
+        assert_eq!(fault(&[4, -5]).fault, Fault::NegativeAddress(-5));
+        assert_eq!(fault(&[1105, 1, -1]).fault, Fault::NegativeAddress(-1));
+    }
+
+    #[test]
+    fn test_fault_stops() {
+        let mut computer = IntcodeComputer::new(&vec![1101, 2, 3, 5, 42, 0]);
+        let error = computer.run().unwrap_err();
+        assert_eq!(error.to_string(), "ip 4 (opcode 42): unknown opcode");
+        assert_eq!(computer.ip(), 4);
+        // The add before it still happened, and running again hits the same fault
+        assert_eq!(computer.memory[5], 5);
+        assert_eq!(computer.run().unwrap_err(), error);
+    }
+
+    /// Steps an interpreter and a cached computer side by side, checking they agree after every
+    /// instruction.
+    fn lockstep(program: &Program, input: &[isize]) {
+        let mut computers = [Engine::Interpreter, Engine::Cached].map(|engine| {
+            let mut computer = IntcodeComputer::new(program);
+            computer.set_engine(engine);
+            computer.input.extend(input);
+            computer
+        });
+        for _ in 0..100_000 {
+            let [state, cached_state] = computers.each_mut().map(|c| c.step());
+            assert_eq!(state, cached_state);
+            let [reference, cached] = &computers;
+            assert_eq!(reference.ip, cached.ip);
+            assert_eq!(reference.relative_base, cached.relative_base);
+            assert_eq!(reference.output, cached.output);
+            if !matches!(state, Ok(None) | Ok(Some(RunState::ProducedOutput))) {
+                let addrs = 0..program.len() + 100;
+                assert_eq!(
+                    reference.memory.range(addrs.clone()),
+                    cached.memory.range(addrs)
+                );
+                return;
+            }
+        }
+    }
+
+    #[test]
+    fn test_engines_agree() {
+        // The day 9 quine
+        lockstep(
+            &parse_program("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99").unwrap(),
+            &[],
+        );
+        // Rewrites the instruction it loops over from adding 1 to multiplying by 3 once x is 4
+        let self_modifying = assemble(
+            "loop: add [x], #1 -> [x]
+      eq [x], #4 -> [t]
+      jf [t], skip
+      mul #1, #1002 -> [loop]
+      add #0, #3 -> [2]
+skip: lt [x], #100 -> [t]
+      jt [t], loop
+      out [x]
+      hlt
+x:    data 0
+t:    data 0",
+        )
+        .unwrap();
+        lockstep(&self_modifying, &[]);
+        lockstep(&[1, 0, 0, 0, 77].to_vec(), &[]);
+        lockstep(&[3, 0, 4, 0, 99].to_vec(), &[]);
+
+        let mut modified = IntcodeComputer::new(&self_modifying);
+        modified.run().unwrap();
+        assert_eq!(modified.output, [108]);
+
+        // Patching memory from outside is seen too
+        let mut patched = computer("loop: out #7\n jt #1, loop");
+        patched.run().unwrap();
+        patched.memory[1] = 8;
+        patched.run().unwrap();
+        assert_eq!(patched.output, [7, 8]);
     }
 }
@@ -0,0 +1,356 @@
+use crate::{Fault, Instruction};
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Index, IndexMut, Range};
+use std::sync::Arc;
+
+const PAGE_SIZE: usize = 1024;
+/// Pages below this are kept in order rather than looked up, since that's where programs keep
+/// their code, data and stack. It's also as far as the decode cache goes: code past it is decoded
+/// every time.
+const LOW_CELLS: usize = 1 << 16;
+
+/// An Intcode computer's memory. It's unbounded, and every cell that hasn't been written reads as
+/// 0. Cells live in fixed-size pages. The low pages are kept in a `Vec` up to the highest one
+/// written, so reading them costs no more than two indexes. Pages past them are only allocated
+/// once something is written to them, so a program that pokes a far-off address only pays for
+/// that page.
+///
+/// Pages are shared between clones until one of them writes to the page (copy-on-write), so
+/// cloning memory to branch a search only copies the pages the branches go on to change.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(into = "SavedMemory", try_from = "SavedMemory")]
+pub struct Memory {
+    /// The pages holding the low cells, up to the highest one written.
+    low: Vec<Arc<[isize; PAGE_SIZE]>>,
+    /// The pages past the low cells that have been written to, by page number.
+    pages: HashMap<usize, Arc<[isize; PAGE_SIZE]>>,
+    /// Instructions the cached engine has decoded, by address. Every write goes through
+    /// `IndexMut`, which drops any instruction it lands on, so self-modifying code (or a day
+    /// patching memory) is always decoded afresh. Kept in pages like the cells, up to the highest
+    /// one anything's been decoded in, and shared between clones the same way.
+    decoded: Vec<Arc<[Decoded; PAGE_SIZE]>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Decoded {
+    /// The instruction starting at this address.
+    instruction: Option<Instruction>,
+    /// Whether a decoded instruction covers this address, so that writes to plain data don't
+    /// have to look for one.
+    covered: bool,
+}
+
+/// Memory as it's saved: the allocated pages, by page number.
+#[derive(Serialize, Deserialize)]
+struct SavedMemory(BTreeMap<usize, Vec<isize>>);
+
+impl From<Memory> for SavedMemory {
+    fn from(memory: Memory) -> Self {
+        let low = memory.low.iter().map(|page| page.to_vec());
+        let far = memory.pages.iter().map(|(&n, page)| (n, page.to_vec()));
+        Self(low.enumerate().chain(far).collect())
+    }
+}
+
+impl TryFrom<SavedMemory> for Memory {
+    type Error = anyhow::Error;
+
+    fn try_from(saved: SavedMemory) -> Result<Self> {
+        let mut low = Vec::new();
+        let mut pages = HashMap::new();
+        for (n, cells) in saved.0 {
+            let Ok(page) = <[isize; PAGE_SIZE]>::try_from(cells) else {
+                bail!("Page {n} isn't {PAGE_SIZE} cells long");
+            };
+            if n < LOW_CELLS / PAGE_SIZE {
+                if low.len() <= n {
+                    low.resize(n + 1, Arc::new([0; PAGE_SIZE]));
+                }
+                low[n] = Arc::new(page);
+            } else {
+                pages.insert(n, Arc::new(page));
+            }
+        }
+        Ok(Self {
+            low,
+            pages,
+            decoded: Vec::new(),
+        })
+    }
+}
+
+impl Memory {
+    pub fn new(program: &[isize]) -> Self {
+        let mut memory = Self::default();
+        for (addr, &value) in program.iter().enumerate() {
+            memory[addr] = value;
+        }
+        memory
+    }
+
+    pub fn range(&self, addrs: Range<usize>) -> Vec<isize> {
+        addrs.map(|addr| self[addr]).collect()
+    }
+
+    /// Takes on the instructions `other` has decoded wherever its memory still holds the same
+    /// instruction, so that later clones start with them.
+    pub(crate) fn learn_decoded(&mut self, other: &Memory) {
+        // Pages still shared with other's have nothing new in them
+        let pages = other.decoded.iter().enumerate().filter(|&(n, page)| {
+            self.decoded
+                .get(n)
+                .is_none_or(|mine| !Arc::ptr_eq(mine, page))
+        });
+        let decoded = pages.flat_map(|(n, page)| {
+            let cells = page.iter().enumerate();
+            cells.map(move |(i, decoded)| (n * PAGE_SIZE + i, decoded))
+        });
+        let learnt: Vec<_> = decoded
+            .filter_map(|(ip, decoded)| Some((ip, decoded.instruction?)))
+            .filter(|&(ip, _)| self.cached(ip).is_none())
+            .filter(|&(ip, instruction)| {
+                (ip..ip + instruction.size()).all(|addr| self[addr] == other[addr])
+            })
+            .collect();
+        for (ip, instruction) in learnt {
+            self.cache(ip, instruction);
+        }
+    }
+
+    /// Decodes the instruction at `ip`, or reuses the last decoding if nothing's written over it
+    /// since.
+    #[inline]
+    pub(crate) fn decode_cached(&mut self, ip: usize) -> Result<Instruction, Fault> {
+        if let Some(instruction) = self.cached(ip) {
+            return Ok(instruction);
+        }
+        self.decode_and_cache(ip)
+    }
+
+    #[inline]
+    pub(crate) fn cached(&self, ip: usize) -> Option<Instruction> {
+        self.decoded_at(ip)?.instruction
+    }
+
+    #[inline]
+    fn decoded_at(&self, addr: usize) -> Option<&Decoded> {
+        Some(&self.decoded.get(addr / PAGE_SIZE)?[addr % PAGE_SIZE])
+    }
+
+    /// Unshares the decode page holding `addr`, which must exist.
+    fn decoded_mut(&mut self, addr: usize) -> &mut Decoded {
+        &mut Arc::make_mut(&mut self.decoded[addr / PAGE_SIZE])[addr % PAGE_SIZE]
+    }
+
+    // Kept out of line so the hit above stays small enough to inline into the run loop
+    #[cold]
+    fn decode_and_cache(&mut self, ip: usize) -> Result<Instruction, Fault> {
+        let instruction = Instruction::decode(self, ip)?;
+        if ip < LOW_CELLS {
+            self.cache(ip, instruction);
+        }
+        Ok(instruction)
+    }
+
+    fn cache(&mut self, ip: usize, instruction: Instruction) {
+        let cells = ip..ip + instruction.size();
+        let last_page = (cells.end - 1) / PAGE_SIZE;
+        if self.decoded.len() <= last_page {
+            let empty = Arc::new([Decoded::default(); PAGE_SIZE]);
+            self.decoded.resize(last_page + 1, empty);
+        }
+        self.decoded_mut(ip).instruction = Some(instruction);
+        for cell in cells {
+            self.decoded_mut(cell).covered = true;
+        }
+    }
+
+    /// Where the decoded instructions covering `addr` start. Instructions are at most 4 cells
+    /// long, so they start at most 3 cells back.
+    fn decoded_over(&self, addr: usize) -> Vec<usize> {
+        let starts = addr.saturating_sub(3)..=addr;
+        let decoded = starts.filter_map(|ip| Some((ip, self.cached(ip)?)));
+        let covering = decoded.filter(|(ip, instruction)| ip + instruction.size() > addr);
+        covering.map(|(ip, _)| ip).collect()
+    }
+
+    #[cold]
+    fn forget_decoded(&mut self, addr: usize) {
+        for ip in self.decoded_over(addr) {
+            self.decoded_mut(ip).instruction = None;
+        }
+        // The forgotten instructions reach at most 3 cells past addr, and what's still decoded
+        // may cover some of those cells too
+        let end = (addr + 4).min(self.decoded.len() * PAGE_SIZE);
+        for cell in addr.saturating_sub(3)..end {
+            let covered = !self.decoded_over(cell).is_empty();
+            if self
+                .decoded_at(cell)
+                .is_some_and(|decoded| decoded.covered != covered)
+            {
+                self.decoded_mut(cell).covered = covered;
+            }
+        }
+    }
+
+    /// Adds zeroed low pages up to and including page `n`. They start out sharing one page, which
+    /// is copied as each is written.
+    #[cold]
+    fn grow_low(&mut self, n: usize) {
+        self.low.resize(n + 1, Arc::new([0; PAGE_SIZE]));
+    }
+
+    #[cold]
+    fn far_cell(&mut self, addr: usize) -> &mut isize {
+        let page = self
+            .pages
+            .entry(addr / PAGE_SIZE)
+            .or_insert_with(|| Arc::new([0; PAGE_SIZE]));
+        &mut Arc::make_mut(page)[addr % PAGE_SIZE]
+    }
+
+    /// How many of this memory's pages are still shared with `other`'s.
+    #[cfg(test)]
+    pub(crate) fn shared_pages(&self, other: &Memory) -> usize {
+        let low = self.low.iter().zip(&other.low);
+        let far = self
+            .pages
+            .iter()
+            .filter_map(|(n, page)| Some((page, other.pages.get(n)?)));
+        low.chain(far).filter(|(a, b)| Arc::ptr_eq(a, b)).count()
+    }
+}
+
+impl Index<usize> for Memory {
+    type Output = isize;
+
+    #[inline]
+    fn index(&self, addr: usize) -> &isize {
+        if let Some(page) = self.low.get(addr / PAGE_SIZE) {
+            return &page[addr % PAGE_SIZE];
+        }
+        self.pages
+            .get(&(addr / PAGE_SIZE))
+            .map_or(&0, |page| &page[addr % PAGE_SIZE])
+    }
+}
+
+impl IndexMut<usize> for Memory {
+    #[inline]
+    fn index_mut(&mut self, addr: usize) -> &mut isize {
+        // Checking first saves unsharing the cache for writes to plain data
+        if self.decoded_at(addr).is_some_and(|decoded| decoded.covered) {
+            self.forget_decoded(addr);
+        }
+
+        if addr >= LOW_CELLS {
+            return self.far_cell(addr);
+        }
+        let n = addr / PAGE_SIZE;
+        if self.low.len() <= n {
+            self.grow_low(n);
+        }
+        &mut Arc::make_mut(&mut self.low[n])[addr % PAGE_SIZE]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwritten_cells() {
+        let memory = Memory::new(&[1, 2, 3]);
+        assert_eq!(memory.range(0..5), [1, 2, 3, 0, 0]);
+        assert_eq!(memory[PAGE_SIZE * 1000 + 7], 0);
+        assert_eq!(memory[usize::MAX], 0);
+        assert_eq!(memory.low.len(), 1);
+        assert!(memory.pages.is_empty());
+    }
+
+    #[test]
+    fn test_far_writes() {
+        // Each write only allocates the page it lands on, however far out it is
+        let mut memory = Memory::new(&[1, 2, 3]);
+        let far = PAGE_SIZE * 10_000 + 5;
+        let huge = (1 << 40) + 3;
+        memory[far] = 42;
+        memory[huge] = 7;
+        memory[usize::MAX] = 8;
+        assert_eq!(memory[far], 42);
+        assert_eq!(memory[far - 1], 0);
+        assert_eq!(memory[far + PAGE_SIZE], 0);
+        assert_eq!(memory[huge], 7);
+        assert_eq!(memory[huge - 1], 0);
+        assert_eq!(memory[usize::MAX], 8);
+        assert_eq!(memory.range(0..3), [1, 2, 3]);
+        assert_eq!(memory.low.len(), 1);
+        assert_eq!(memory.pages.len(), 3);
+    }
+
+    #[test]
+    fn test_sharing() {
+        let mut memory = Memory::new(&[1, 2, 3]);
+        memory[PAGE_SIZE * 3] = 4;
+        let mut clone = memory.clone();
+        assert_eq!(clone.shared_pages(&memory), 4);
+        clone[1] = 5;
+        clone[PAGE_SIZE] = 6;
+        assert_eq!(clone.range(0..3), [1, 5, 3]);
+        assert_eq!(clone[PAGE_SIZE], 6);
+        assert_eq!(memory.range(0..3), [1, 2, 3]);
+        assert_eq!(memory[PAGE_SIZE], 0);
+        // Only the pages written to are copied, and the zeroed page between is still shared
+        assert_eq!(clone.shared_pages(&memory), 2);
+    }
+
+    #[test]
+    fn test_saving() {
+        let mut memory = Memory::new(&[1, 2, 3]);
+        let far = PAGE_SIZE * 10_000 + 5;
+        memory[far] = 42;
+        memory[usize::MAX] = 8;
+        let saved = SavedMemory::from(memory);
+        let pages: Vec<_> = saved.0.keys().copied().collect();
+        assert_eq!(pages, [0, 10_000, usize::MAX / PAGE_SIZE]);
+        let loaded = Memory::try_from(saved).unwrap();
+        assert_eq!(loaded.range(0..4), [1, 2, 3, 0]);
+        assert_eq!(loaded[far], 42);
+        assert_eq!(loaded[usize::MAX], 8);
+    }
+
+    #[test]
+    fn test_learn_decoded() {
+        let mut template = Memory::new(&[1101, 1, 2, 7, 99, 0, 0, 0]);
+        let mut clone = template.clone();
+        clone.decode_cached(0).unwrap();
+        // Rewriting the halt into an output
+        clone[4] = 104;
+        clone.decode_cached(4).unwrap();
+
+        template.learn_decoded(&clone);
+        assert!(template.cached(0).is_some());
+        assert!(template.cached(4).is_none());
+        assert!(template.clone().cached(0).is_some());
+    }
+
+    #[test]
+    fn test_decoded_sharing() {
+        // A halt decoded on each of the first two pages, then a clone overwriting the first
+        let mut memory = Memory::new(&[99]);
+        memory[PAGE_SIZE] = 99;
+        memory.decode_cached(0).unwrap();
+        memory.decode_cached(PAGE_SIZE).unwrap();
+        let mut clone = memory.clone();
+        clone[0] = 104;
+
+        assert!(clone.cached(0).is_none());
+        assert!(clone.cached(PAGE_SIZE).is_some());
+        assert!(memory.cached(0).is_some());
+        // Only the decode page written over is copied
+        assert!(!Arc::ptr_eq(&clone.decoded[0], &memory.decoded[0]));
+        assert!(Arc::ptr_eq(&clone.decoded[1], &memory.decoded[1]));
+    }
+}
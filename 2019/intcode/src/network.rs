@@ -0,0 +1,293 @@
+//! Running several computers that talk to each other, like day 7's amplifiers and day 23's NICs.
+//! Machines are scheduled round robin, each running until it waits for input or halts, and the
+//! messages they output are routed by the network's `Topology`. The caller drives the network
+//! with `next_event` and acts as its supervisor: it sees what leaves the network and when the
+//! network goes idle, and can `send` messages back in (day 23's NAT).
+
+use crate::{IntcodeComputer, RunState};
+use anyhow::{Context, Result};
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Each machine's messages go to the next one. The last machine's leave the network.
+    Pipeline,
+    /// A pipeline whose last machine feeds the first.
+    Ring,
+    /// Every message starts with the address (id) of the machine it's for. Messages for addresses
+    /// with no machine leave the network.
+    Bus,
+}
+
+/// A message that left the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub from: usize,
+    /// The address on a bus, or `None` off the end of a pipeline
+    pub to: Option<isize>,
+    pub payload: Vec<isize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Packet(Packet),
+    /// A whole round went by with every machine waiting on input it didn't have and nothing
+    /// sent. Without an idle input this means the network is deadlocked.
+    Idle,
+    Halted(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Machine {
+    name: String,
+    computer: IntcodeComputer,
+    waiting: bool,
+    halted: bool,
+    last_sent: Option<Vec<isize>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Network {
+    topology: Topology,
+    frame: usize,
+    idle_input: Option<isize>,
+    machines: Vec<Machine>,
+    events: VecDeque<Event>,
+}
+
+impl Network {
+    /// A network whose messages are one output each, until `set_frame` says otherwise.
+    pub fn new(topology: Topology) -> Self {
+        Self {
+            topology,
+            frame: 1,
+            idle_input: None,
+            machines: Vec::new(),
+            events: VecDeque::new(),
+        }
+    }
+
+    /// How many outputs make up a message, including a bus message's address.
+    pub fn set_frame(&mut self, outputs: usize) {
+        self.frame = outputs;
+    }
+
+    /// What a machine reads when it asks for input and has none, if anything (day 23's -1).
+    pub fn set_idle_input(&mut self, input: Option<isize>) {
+        self.idle_input = input;
+    }
+
+    /// Adds a machine, returning its id. Ids count up from 0 and are the machines' bus addresses.
+    pub fn add(&mut self, name: impl Into<String>, computer: IntcodeComputer) -> usize {
+        self.machines.push(Machine {
+            name: name.into(),
+            computer,
+            waiting: false,
+            halted: false,
+            last_sent: None,
+        });
+        self.machines.len() - 1
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.machines.iter().position(|m| m.name == name)
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.machines[id].name
+    }
+
+    pub fn computer(&self, id: usize) -> &IntcodeComputer {
+        &self.machines[id].computer
+    }
+
+    pub fn computer_mut(&mut self, id: usize) -> &mut IntcodeComputer {
+        &mut self.machines[id].computer
+    }
+
+    /// Queues a message's values as input to a machine.
+    pub fn send(&mut self, id: usize, payload: &[isize]) {
+        self.machines[id].computer.input.extend(payload);
+    }
+
+    /// The last message a machine sent, wherever it went.
+    pub fn last_sent(&self, id: usize) -> Option<&[isize]> {
+        self.machines[id].last_sent.as_deref()
+    }
+
+    /// Runs the network until something happens that the supervisor should see, or returns
+    /// `None` once every machine has halted.
+    pub fn next_event(&mut self) -> Result<Option<Event>> {
+        while self.events.is_empty() {
+            if self.machines.iter().all(|m| m.halted) {
+                return Ok(None);
+            }
+            if self.round()? {
+                self.events.push_back(Event::Idle);
+            }
+        }
+        Ok(self.events.pop_front())
+    }
+
+    // Gives every machine a turn, returning whether they were all idle.
+    fn round(&mut self) -> Result<bool> {
+        let mut idle = true;
+        for id in 0..self.machines.len() {
+            idle &= self.turn(id)?;
+        }
+        Ok(idle)
+    }
+
+    fn turn(&mut self, id: usize) -> Result<bool> {
+        let machine = &mut self.machines[id];
+        if machine.halted {
+            return Ok(true);
+        }
+        let mut idle = machine.waiting && machine.computer.input.is_empty();
+        if idle && let Some(input) = self.idle_input {
+            machine.computer.input.push_back(input);
+        }
+
+        loop {
+            let machine = &mut self.machines[id];
+            let state = machine
+                .computer
+                .run()
+                .with_context(|| format!("{} faulted", machine.name))?;
+            match state {
+                RunState::ProducedOutput => {
+                    if machine.computer.output.len() == self.frame {
+                        let message: Vec<_> = machine.computer.output.drain(..).collect();
+                        machine.last_sent = Some(message.clone());
+                        self.route(id, message);
+                        idle = false;
+                    }
+                }
+                RunState::AwaitingInput => {
+                    machine.waiting = true;
+                    return Ok(idle);
+                }
+                RunState::Halted => {
+                    machine.halted = true;
+                    self.events.push_back(Event::Halted(id));
+                    return Ok(idle);
+                }
+            }
+        }
+    }
+
+    fn route(&mut self, from: usize, mut message: Vec<isize>) {
+        let next = (from + 1) % self.machines.len();
+        let (id, to) = match self.topology {
+            Topology::Pipeline => ((next != 0).then_some(next), None),
+            Topology::Ring => (Some(next), None),
+            Topology::Bus => {
+                let address = message.remove(0);
+                let id = usize::try_from(address).ok();
+                (id.filter(|&id| id < self.machines.len()), Some(address))
+            }
+        };
+        match id {
+            Some(id) => self.send(id, &message),
+            None => self.events.push_back(Event::Packet(Packet {
+                from,
+                to,
+                payload: message,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assemble;
+
+    #[test]
+    fn test_examples() {
+        // Adds one to each input until it reads 0
+        let increment = assemble(
+            "\
+loop: in -> [x]
+      jf [x], end
+      add [x], #1 -> [x]
+      out [x]
+      jt #1, loop
+end:  hlt
+x:    data 0",
+        )
+        .unwrap();
+
+        let mut pipeline = Network::new(Topology::Pipeline);
+        for name in ["a", "b", "c"] {
+            pipeline.add(name, IntcodeComputer::new(&increment));
+        }
+        pipeline.send(0, &[10]);
+        let packet = Packet {
+            from: 2,
+            to: None,
+            payload: vec![13],
+        };
+        assert_eq!(pipeline.next_event().unwrap(), Some(Event::Packet(packet)));
+        assert_eq!(pipeline.next_event().unwrap(), Some(Event::Idle));
+        pipeline.send(pipeline.id("a").unwrap(), &[0]);
+        assert_eq!(pipeline.next_event().unwrap(), Some(Event::Halted(0)));
+        assert_eq!(pipeline.next_event().unwrap(), Some(Event::Idle));
+
+        // Adds one to each input, halting once it's output 20 or more
+        let count_to_20 = assemble(
+            "\
+loop: in -> [x]
+      add [x], #1 -> [x]
+      out [x]
+      lt [x], #20 -> [t]
+      jt [t], loop
+      hlt
+x:    data 0
+t:    data 0",
+        )
+        .unwrap();
+        let mut ring = Network::new(Topology::Ring);
+        for name in ["a", "b", "c"] {
+            ring.add(name, IntcodeComputer::new(&count_to_20));
+        }
+        ring.send(0, &[1]);
+        for id in 0..3 {
+            assert_eq!(ring.next_event().unwrap(), Some(Event::Halted(id)));
+        }
+        assert_eq!(ring.next_event().unwrap(), None);
+        assert_eq!(ring.last_sent(0), Some(&[20][..]));
+        assert_eq!(ring.last_sent(2), Some(&[22][..]));
+
+        // Sends (address, 7) to each address it's given, then (99, 8) off the bus
+        let sender = assemble(
+            "\
+loop: in -> [to]
+      jf [to], end
+      out [to]
+      out #7
+      jt #1, loop
+end:  out #99
+      out #8
+      hlt
+to:   data 0",
+        )
+        .unwrap();
+        let mut bus = Network::new(Topology::Bus);
+        bus.set_frame(2);
+        bus.set_idle_input(Some(-1));
+        bus.add("sender", IntcodeComputer::new(&sender));
+        let echo = bus.add("echo", IntcodeComputer::new(&increment));
+        bus.send(0, &[1, 0]);
+        assert_eq!(
+            bus.next_event().unwrap(),
+            Some(Event::Packet(Packet {
+                from: 0,
+                to: Some(99),
+                payload: vec![8]
+            }))
+        );
+        assert_eq!(bus.next_event().unwrap(), Some(Event::Halted(0)));
+        assert_eq!(bus.computer(echo).output, [8]);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{IntcodeComputer, Memory};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+/// A computer's state at some point: where it is, its memory and its queues. Memory pages are
+/// shared with the computer until one of them writes to a page, and restoring a snapshot only
+/// copies the pages the computer goes on to change, so it's cheap to keep one per branch of a
+/// search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub(crate) ip: usize,
+    pub(crate) relative_base: isize,
+    pub(crate) memory: Memory,
+    pub(crate) input: VecDeque<isize>,
+    pub(crate) output: VecDeque<isize>,
+}
+
+impl Snapshot {
+    /// Saves the snapshot as JSON, e.g. to pick a droid session up again later.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, serde_json::to_string(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Invalid snapshot {}", path.display()))
+    }
+}
+
+impl IntcodeComputer {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            ip: self.ip,
+            relative_base: self.relative_base,
+            memory: self.memory.clone(),
+            input: self.input.clone(),
+            output: self.output.clone(),
+        }
+    }
+
+    /// Puts the computer back in the snapshot's state. The engine and tracer are left as they are.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.ip = snapshot.ip;
+        self.relative_base = snapshot.relative_base;
+        self.memory = snapshot.memory.clone();
+        self.input = snapshot.input.clone();
+        self.output = snapshot.output.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RunState, assemble};
+
+    #[test]
+    fn test_examples() {
+        // Adds up its inputs, outputting the running total
+        let program = assemble(
+            "\
+loop:  in -> [x]
+       add [x], [total] -> [total]
+       out [total]
+       jt #1, loop
+x:     data 0
+total: data 0",
+        )
+        .unwrap();
+        let mut computer = IntcodeComputer::new(&program);
+        computer.input.extend([1, 2]);
+        computer.run_to_halt().unwrap();
+        let snapshot = computer.snapshot();
+
+        computer.input.push_back(10);
+        computer.run_to_halt().unwrap();
+        assert_eq!(computer.output, [1, 3, 13]);
+
+        computer.restore(&snapshot);
+        computer.input.push_back(100);
+        assert_eq!(computer.run_to_halt().unwrap(), RunState::AwaitingInput);
+        assert_eq!(computer.output, [1, 3, 103]);
+
+        let path =
+            std::env::temp_dir().join(format!("intcode-snapshot-{}.json", std::process::id()));
+        snapshot.save(&path).unwrap();
+        let mut resumed = IntcodeComputer::new(&Vec::new());
+        resumed.restore(&Snapshot::load(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+        resumed.input.push_back(1000);
+        resumed.run_to_halt().unwrap();
+        assert_eq!(resumed.output, [1, 3, 1003]);
+    }
+
+    #[test]
+    fn test_sharing() {
+        // Memory written across three pages, all shared with the snapshot until one is written
+        let mut computer = IntcodeComputer::new(&assemble("out #1\nhlt").unwrap());
+        computer.memory[1024] = 5;
+        computer.memory[2048] = 6;
+        computer.run().unwrap();
+        let snapshot = computer.snapshot();
+        assert_eq!(computer.memory.shared_pages(&snapshot.memory), 3);
+
+        computer.memory[1025] = 7;
+        assert_eq!(computer.memory.shared_pages(&snapshot.memory), 2);
+        assert_eq!(snapshot.memory[1025], 0);
+
+        computer.restore(&snapshot);
+        assert_eq!(computer.memory.shared_pages(&snapshot.memory), 3);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{Instruction, Mode};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Debug};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+/// Receives every instruction a computer executes once it's been given to
+/// `IntcodeComputer::set_tracer`.
+pub trait Tracer: Debug + Send {
+    fn record(&mut self, step: &Step);
+}
+
+/// An executed instruction along with what its parameters resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub ip: usize,
+    pub relative_base: isize,
+    pub instruction: Instruction,
+    /// The value each parameter read, except for a written parameter where it's the address.
+    pub values: Vec<isize>,
+}
+
+impl Step {
+    /// The memory addresses the step touched, including the instruction itself.
+    pub fn addresses(&self) -> impl Iterator<Item = isize> + '_ {
+        let code = self.ip as isize..(self.ip + self.instruction.size()) as isize;
+        let params = self
+            .instruction
+            .params()
+            .iter()
+            .filter_map(|param| match param.mode {
+                Mode::Position => Some(param.value),
+                Mode::Immediate => None,
+                Mode::Relative => Some(param.value + self.relative_base),
+            });
+        code.chain(params)
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values = self.values.iter().map(|v| v.to_string());
+        write!(
+            f,
+            "{:04}: {:<24} ; rb={} {}",
+            self.ip,
+            self.instruction.to_string(),
+            self.relative_base,
+            values.collect::<Vec<_>>().join(", ")
+        )
+    }
+}
+
+/// Writes one line per step, e.g. `0004: ADD [100], #1 -> [100]   ; rb=0 7, 1, 100`.
+#[derive(Debug)]
+pub struct TraceWriter<W: Write + Debug + Send> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write + Debug + Send> TraceWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Flushes the trace, reporting the first error hit while writing it.
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write + Debug + Send> Tracer for TraceWriter<W> {
+    fn record(&mut self, step: &Step) {
+        if self.error.is_none()
+            && let Err(error) = writeln!(self.writer, "{step}")
+        {
+            self.error = Some(error);
+        }
+    }
+}
+
+/// Aggregates a run: instructions executed per opcode, how often each `ip` ran and the highest
+/// address touched.
+#[derive(Debug, Default, Clone)]
+pub struct Profile {
+    pub steps: u64,
+    pub opcodes: BTreeMap<&'static str, u64>,
+    /// Executions and size of the instruction at each `ip`
+    ips: HashMap<usize, (u64, usize)>,
+    pub peak_address: isize,
+}
+
+/// A run of consecutive instructions that executed the same number of times, i.e. a loop body or
+/// straight-line code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HotRange {
+    pub start: usize,
+    /// One past the last instruction's final cell
+    pub end: usize,
+    pub executions: u64,
+    /// Instructions executed in the range over the whole run
+    pub steps: u64,
+}
+
+impl Profile {
+    /// Instruction ranges sorted by the steps spent in them, hottest first.
+    pub fn hot_ranges(&self) -> Vec<HotRange> {
+        let mut ips: Vec<_> = self
+            .ips
+            .iter()
+            .map(|(&ip, &(n, size))| (ip, n, size))
+            .collect();
+        ips.sort_unstable();
+
+        let mut ranges: Vec<HotRange> = Vec::new();
+        for (ip, executions, size) in ips {
+            match ranges.last_mut() {
+                Some(range) if range.end == ip && range.executions == executions => {
+                    range.end += size;
+                    range.steps += executions;
+                }
+                _ => ranges.push(HotRange {
+                    start: ip,
+                    end: ip + size,
+                    executions,
+                    steps: executions,
+                }),
+            }
+        }
+        ranges.sort_by_key(|range| (std::cmp::Reverse(range.steps), range.start));
+        ranges
+    }
+}
+
+impl Tracer for Profile {
+    fn record(&mut self, step: &Step) {
+        self.steps += 1;
+        *self.opcodes.entry(step.instruction.mnemonic()).or_default() += 1;
+        self.ips
+            .entry(step.ip)
+            .or_insert((0, step.instruction.size()))
+            .0 += 1;
+        self.peak_address = step.addresses().fold(self.peak_address, isize::max);
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} instructions executed", self.steps)?;
+        writeln!(f, "Peak address: {}", self.peak_address)?;
+
+        writeln!(f, "Per opcode:")?;
+        let mut opcodes: Vec<_> = self.opcodes.iter().collect();
+        opcodes.sort_by_key(|&(mnemonic, n)| (std::cmp::Reverse(n), mnemonic));
+        for (mnemonic, n) in opcodes {
+            let share = 100.0 * *n as f64 / self.steps as f64;
+            writeln!(f, "  {mnemonic:<4} {n:>12} {share:>6.2}%")?;
+        }
+
+        writeln!(f, "Hottest ranges:")?;
+        for range in self.hot_ranges().iter().take(10) {
+            writeln!(
+                f,
+                "  {:04}..{:04} {:>12} steps ({}x)",
+                range.start, range.end, range.steps, range.executions
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Lets a tracer be read back after it's been combined with another.
+impl<T: Tracer + ?Sized> Tracer for Arc<Mutex<T>> {
+    fn record(&mut self, step: &Step) {
+        self.lock().unwrap().record(step);
+    }
+}
+
+impl<A: Tracer, B: Tracer> Tracer for (A, B) {
+    fn record(&mut self, step: &Step) {
+        self.0.record(step);
+        self.1.record(step);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntcodeComputer, assemble};
+
+    #[test]
+    fn test_examples() {
+        let program = assemble(
+            "\
+loop: add [count], #1 -> [count]
+      lt [count], 3, [rb+20]
+      jt [rb+20], loop
+      out [count]
+      hlt
+count: data 0
+",
+        )
+        .unwrap();
+        let tracer = Arc::new(Mutex::new((
+            Profile::default(),
+            TraceWriter::new(Vec::new()),
+        )));
+        let mut computer = IntcodeComputer::new(&program);
+        computer.set_tracer(Some(tracer.clone()));
+        computer.run_to_halt().unwrap();
+        computer.set_tracer(None);
+
+        let (profile, writer) = Arc::into_inner(tracer).unwrap().into_inner().unwrap();
+        assert_eq!(profile.steps, 11);
+        assert_eq!(profile.opcodes["ADD"], 3);
+        assert_eq!(profile.opcodes["HLT"], 1);
+        assert_eq!(profile.peak_address, 20);
+        assert_eq!(
+            profile.hot_ranges()[0],
+            HotRange {
+                start: 0,
+                end: 11,
+                executions: 3,
+                steps: 9
+            }
+        );
+
+        let trace = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(trace.lines().count(), 11);
+        assert_eq!(
+            trace.lines().next().unwrap(),
+            "0000: ADD [14], #1 -> [14]     ; rb=0 0, 1, 14"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::{Error, Result, bail};
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let diffs = jolt_differences(&parse_chain(input)?)?;
+    Ok(diffs[1] * diffs[3])
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(arrangements_dfs_memo(
+        &parse_chain(input)?,
+        0,
+        &mut HashMap::default(),
+    ))
+}
+
+fn parse_chain(input: &str) -> Result<Vec<usize>> {
+    let mut adapters = input
+        .lines()
+        .map(|l| l.parse().map_err(Error::from))
+        .collect::<Result<Vec<_>>>()?;
+    sort_chain(&mut adapters);
+    Ok(adapters)
+}
+
+/// Sort adapters and append outlet (0) and device (max(adapters) + 3) joltages
+fn sort_chain(adapters: &mut Vec<usize>) {
+    adapters.sort_unstable();
+    adapters.insert(0, 0);
+    let built_in = adapters.last().unwrap() + 3;
+    adapters.push(built_in);
+}
+
+fn jolt_differences(adapters: &[usize]) -> Result<[usize; 4]> {
+    let mut diffs = [0; 4];
+    for pair in adapters.windows(2) {
+        let diff = pair[1] - pair[0];
+        if diff > 3 {
+            bail!(
+                "Difference {} too large from {} and {}",
+                diff,
+                pair[0],
+                pair[1]
+            );
+        }
+        diffs[diff] += 1;
+    }
+
+    Ok(diffs)
+}
+
+/// Recursive DFS through the search tree of all arrangements of adapters with memoization:
+/// * current - index into adapters (node in tree)
+/// * memo - adapter index (node) -> # of arrangements (reachable leaves from that node)
+fn arrangements_dfs_memo(
+    adapters: &[usize],
+    current: usize,
+    memo: &mut HashMap<usize, usize>,
+) -> usize {
+    if current == adapters.len() - 1 {
+        return 1;
+    }
+
+    if let Some(&cached) = memo.get(&current) {
+        return cached;
+    }
+
+    let mut arrangements = 0;
+    for i in current + 1..adapters.len() {
+        if adapters[i] - adapters[current] > 3 {
+            break;
+        }
+        arrangements += arrangements_dfs_memo(adapters, i, memo);
+    }
+    memo.insert(current, arrangements);
+    arrangements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let adapters = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4";
+        assert_eq!(part1(adapters).unwrap(), 7 * 5);
+        assert_eq!(part2(adapters).unwrap(), 8);
+
+        let adapters = "\
+28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n\
+4\n2\n34\n10\n3";
+        assert_eq!(part1(adapters).unwrap(), 22 * 10);
+        assert_eq!(part2(adapters).unwrap(), 19208);
+    }
+}
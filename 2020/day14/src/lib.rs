@@ -0,0 +1,145 @@
+use anyhow::{Error, Result, bail};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(run(&parse_input(input)?, Version::V1))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(run(&parse_input(input)?, Version::V2))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    input.lines().map(str::parse).collect()
+}
+
+fn run(program: &[Instruction], version: Version) -> usize {
+    let mut memory = HashMap::new();
+    let mut mask = Mask::default();
+    let apply = match version {
+        Version::V1 => apply_v1,
+        Version::V2 => apply_v2,
+    };
+
+    for instruction in program.iter() {
+        match instruction {
+            Instruction::Mask(m) => {
+                mask = m.clone();
+            }
+            Instruction::Mem(addr, val) => apply(&mut memory, &mask, *addr, *val),
+        }
+    }
+
+    memory.values().sum()
+}
+
+fn apply_v1(memory: &mut HashMap<usize, usize>, mask: &Mask, addr: usize, value: usize) {
+    memory.insert(addr, (mask.ones | value) & mask.zeros);
+}
+
+fn apply_v2(memory: &mut HashMap<usize, usize>, mask: &Mask, addr: usize, value: usize) {
+    let addr = addr | mask.ones;
+    let mut addresses = vec![addr];
+    for &bit in &mask.floating {
+        let one_mask = 1 << bit;
+        let zero_mask = !one_mask;
+        addresses = addresses
+            .iter()
+            .flat_map(|&a| [a | one_mask, a & zero_mask])
+            .collect();
+    }
+
+    for &addr in addresses.iter() {
+        memory.insert(addr, value);
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mask(Mask),
+    Mem(usize, usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Mask {
+    ones: usize,
+    zeros: usize,
+    floating: Vec<usize>,
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.split(&[' ', '[', ']']).collect();
+        match tokens[0] {
+            "mask" => Ok(Instruction::Mask(tokens[2].parse()?)),
+            "mem" => Ok(Instruction::Mem(tokens[1].parse()?, tokens[4].parse()?)),
+            _ => bail!("Unrecognized instruction: {s}"),
+        }
+    }
+}
+
+impl FromStr for Mask {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (mut zeros, mut ones) = (usize::MAX, 0);
+        let mut floating = Vec::new();
+        for (i, bit) in s.trim().chars().rev().enumerate() {
+            match bit {
+                'X' => floating.push(i),
+                '1' => ones |= 1 << i,
+                '0' => zeros &= !(1 << i),
+                _ => bail!("Unrecognized bit: {bit}"),
+            }
+        }
+        Ok(Self {
+            ones,
+            zeros,
+            floating,
+        })
+    }
+}
+
+impl Default for Mask {
+    fn default() -> Self {
+        let (ones, zeros) = (usize::MAX, usize::MAX);
+        let floating = Vec::new();
+        Self {
+            ones,
+            zeros,
+            floating,
+        }
+    }
+}
+
+enum Version {
+    V1,
+    V2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let program = "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0
+";
+        assert_eq!(part1(program).unwrap(), 165);
+
+        let program = "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1
+";
+        assert_eq!(part2(program).unwrap(), 208);
+    }
+}
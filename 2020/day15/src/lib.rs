@@ -0,0 +1,83 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(play(&parse_input(input)?, 2020))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(play(&parse_input(input)?, 30_000_000))
+}
+
+pub fn parse_input(line: &str) -> Result<GameState> {
+    let mut numbers = HashMap::new();
+    let mut last = 0;
+    for (i, n) in line.trim().split(',').enumerate() {
+        last = n.parse()?;
+        numbers.insert(last, i + 1);
+    }
+    numbers.remove(&last);
+
+    Ok(GameState::new(numbers, last))
+}
+
+#[derive(Debug, Clone)]
+pub struct GameState {
+    /// number -> last turn number was spoken
+    numbers: HashMap<usize, usize>,
+    /// last number spoken
+    last: usize,
+    turn: usize,
+}
+
+impl GameState {
+    fn new(numbers: HashMap<usize, usize>, last: usize) -> GameState {
+        let turn = numbers.len() + 2;
+        Self {
+            numbers,
+            last,
+            turn,
+        }
+    }
+
+    fn turn(&mut self) {
+        let prev_last = self.last;
+
+        if let Some(&prev_turn) = self.numbers.get(&self.last) {
+            self.last = self.turn - prev_turn - 1;
+        } else {
+            self.last = 0;
+        }
+
+        self.numbers.insert(prev_last, self.turn - 1);
+        self.turn += 1;
+    }
+}
+
+fn play(state: &GameState, rounds: usize) -> usize {
+    let mut state = state.clone();
+    (state.turn..=rounds).for_each(|_| state.turn());
+    state.last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        for (starting, spoken) in [
+            ("0,3,6", 436),
+            ("1,3,2", 1),
+            ("2,1,3", 10),
+            ("1,2,3", 27),
+            ("2,3,1", 78),
+            ("3,2,1", 438),
+            ("3,1,2", 1836),
+        ] {
+            assert_eq!(part1(starting).unwrap(), spoken);
+        }
+
+        assert_eq!(part2("0,3,6").unwrap(), 175594);
+    }
+}
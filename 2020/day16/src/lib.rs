@@ -0,0 +1,180 @@
+use anyhow::{Error, Result, bail};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (rules, _my_ticket, other_tickets) = parse_input(input)?;
+    Ok(error_rate(&rules, &other_tickets))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (rules, my_ticket, other_tickets) = parse_input(input)?;
+    departure_product(&rules, &my_ticket, &other_tickets)
+}
+
+pub fn parse_input(input: &str) -> Result<(Rules, Ticket, Vec<Ticket>)> {
+    let mut lines = input.lines();
+
+    let mut rules = Rules::new();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        let tokens: Vec<_> = line.trim().split(':').collect();
+        let ranges: Vec<_> = tokens[1].split_ascii_whitespace().collect();
+        rules.insert(
+            tokens[0].to_string(),
+            vec![parse_range(ranges[0])?, parse_range(ranges[2])?],
+        );
+    }
+
+    // your ticket:
+    lines.next().unwrap();
+    let your_ticket = parse_ticket(lines.next().unwrap())?;
+    lines.next().unwrap();
+
+    // nearby tickets:
+    lines.next().unwrap();
+    let mut other_tickets = Vec::new();
+    for line in lines.by_ref() {
+        other_tickets.push(parse_ticket(line)?);
+    }
+
+    Ok((rules, your_ticket, other_tickets))
+}
+
+fn parse_range(s: &str) -> Result<RangeInclusive<usize>> {
+    let tokens: Vec<_> = s.trim().split('-').collect();
+    Ok(tokens[0].parse()?..=tokens[1].parse()?)
+}
+
+fn parse_ticket(s: &str) -> Result<Ticket> {
+    s.split(",")
+        .map(|x| x.parse().map_err(Error::from))
+        .collect()
+}
+
+type Rules = HashMap<String, Vec<RangeInclusive<usize>>>;
+
+type Ticket = Vec<usize>;
+
+fn error_rate(rules: &Rules, tickets: &[Ticket]) -> usize {
+    tickets
+        .iter()
+        .flatten()
+        .filter(|&&field| none_apply(rules, field))
+        .sum()
+}
+
+fn departure_product(
+    rules: &Rules,
+    your_ticket: &Ticket,
+    other_tickets: &[Ticket],
+) -> Result<usize> {
+    let valid_ticket_indices = (0..other_tickets.len())
+        .filter(|&t| {
+            !other_tickets[t]
+                .iter()
+                .any(|&field| none_apply(rules, field))
+        })
+        .collect::<Vec<_>>();
+
+    let rule_names = rules.keys().cloned().collect::<Vec<_>>();
+    let mut candidates =
+        vec![rule_names.iter().cloned().collect::<HashSet<_>>(); your_ticket.len()];
+
+    for column_idx in 0..your_ticket.len() {
+        for rule_name in &rule_names {
+            let ranges = &rules[rule_name];
+            let valid_for_nearby = valid_ticket_indices.iter().all(|&ticket_idx| {
+                let value = other_tickets[ticket_idx][column_idx];
+                ranges.iter().any(|range| range.contains(&value))
+            });
+            let valid_for_mine = ranges
+                .iter()
+                .any(|range| range.contains(&your_ticket[column_idx]));
+
+            if !(valid_for_nearby && valid_for_mine) {
+                candidates[column_idx].remove(rule_name);
+            }
+        }
+    }
+
+    let mut resolved: Vec<Option<String>> = vec![None; your_ticket.len()];
+    while resolved.iter().any(|field| field.is_none()) {
+        let mut progress = false;
+        for i in 0..candidates.len() {
+            if resolved[i].is_some() || candidates[i].len() != 1 {
+                continue;
+            }
+            let field = candidates[i].iter().next().unwrap().clone();
+            resolved[i] = Some(field.clone());
+            for (j, candidate_set) in candidates.iter_mut().enumerate() {
+                if i != j {
+                    candidate_set.remove(&field);
+                }
+            }
+            progress = true;
+        }
+
+        if !progress {
+            bail!("Could not resolve all field positions");
+        }
+    }
+
+    Ok(resolved
+        .iter()
+        .enumerate()
+        .filter_map(|(i, field)| {
+            field
+                .as_ref()
+                .and_then(|name| name.starts_with("departure").then_some(your_ticket[i]))
+        })
+        .product())
+}
+
+fn none_apply(rules: &Rules, field: usize) -> bool {
+    !rules
+        .values()
+        .any(|ranges| ranges.iter().any(|range| range.contains(&field)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let notes = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+";
+        assert_eq!(part1(notes).unwrap(), 71);
+
+        // The example has no departure fields, so rename two of them to get a product out of it
+        let notes = "\
+departure class: 0-1 or 4-19
+row: 0-5 or 8-19
+departure seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9
+";
+        assert_eq!(part2(notes).unwrap(), 12 * 13);
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+type Rules = HashMap<String, Vec<Vec<String>>>;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (rules, messages) = parse_input(input);
+    Ok(match0(&rules, &messages))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (mut rules, messages) = parse_input(input);
+    rules.insert(
+        "8".into(),
+        vec![vec!["42".into()], vec!["42".into(), "8".into()]],
+    );
+    rules.insert(
+        "11".into(),
+        vec![
+            vec!["42".into(), "31".into()],
+            vec!["42".into(), "11".into(), "31".into()],
+        ],
+    );
+    Ok(match0(&rules, &messages))
+}
+
+pub fn parse_input(input: &str) -> (Rules, Vec<String>) {
+    let mut lines = input.lines();
+
+    let mut rules = HashMap::new();
+    for line in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+
+        let mut sub_rules = Vec::new();
+        let mut sub_rule = Vec::new();
+        let tokens: Vec<_> = line.trim().split(": ").collect();
+        let rule_num = tokens[0].to_string();
+        for matches in tokens[1].split_ascii_whitespace() {
+            if matches == "|" {
+                sub_rules.push(sub_rule);
+                sub_rule = Vec::new();
+            } else {
+                sub_rule.push(matches.to_string());
+            }
+        }
+        sub_rules.push(sub_rule);
+        rules.insert(rule_num, sub_rules);
+    }
+
+    let messages = lines.map(str::to_string).collect();
+
+    (rules, messages)
+}
+
+fn match0(rules: &Rules, messages: &[String]) -> usize {
+    messages
+        .iter()
+        .filter(|m| match_rule(rules, "0", 0, m.as_bytes(), &mut HashMap::new()).contains(&m.len()))
+        .count()
+}
+
+fn match_rule(
+    rules: &Rules,
+    rule_id: &str,
+    pos: usize,
+    msg: &[u8],
+    memo: &mut HashMap<(String, usize), Vec<usize>>,
+) -> Vec<usize> {
+    let cache_key = (rule_id.to_string(), pos);
+    if let Some(cached) = memo.get(&cache_key) {
+        return cached.clone();
+    }
+
+    let out = if let Some(expected) = terminal_char(rules.get(rule_id).unwrap()) {
+        if pos < msg.len() && msg[pos] == expected {
+            vec![pos + 1]
+        } else {
+            Vec::new()
+        }
+    } else {
+        let mut out = Vec::new();
+        for alt in rules.get(rule_id).unwrap() {
+            let mut frontier = vec![pos];
+            for next_rule in alt {
+                let mut next_frontier = Vec::new();
+                for p in frontier {
+                    next_frontier.extend(match_rule(rules, next_rule, p, msg, memo));
+                }
+                frontier = next_frontier;
+                if frontier.is_empty() {
+                    break;
+                }
+            }
+            out.extend(frontier);
+        }
+        out.sort_unstable();
+        out.dedup();
+        out
+    };
+
+    memo.insert(cache_key, out.clone());
+    out
+}
+
+fn terminal_char(rule: &[Vec<String>]) -> Option<u8> {
+    if rule.len() != 1 || rule[0].len() != 1 {
+        return None;
+    }
+    let token = &rule[0][0];
+    token
+        .strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .filter(|t| t.len() == 1)
+        .map(|t| t.as_bytes()[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let notes = "\
+0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb
+";
+        assert_eq!(part1(notes).unwrap(), 2);
+
+        let notes = "\
+42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+";
+        assert_eq!(part1(notes).unwrap(), 3);
+        assert_eq!(part2(notes).unwrap(), 12);
+    }
+}
@@ -0,0 +1,69 @@
+use anyhow::{Error, Result};
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.iter().filter(|p| p.is_valid()).count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?
+        .iter()
+        .filter(|p| p.is_valid_xor())
+        .count())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<PasswordRecord>> {
+    input.lines().map(str::parse).collect()
+}
+
+#[derive(Debug)]
+pub struct PasswordRecord {
+    password: Vec<char>,
+    constraint: (char, (usize, usize)),
+}
+
+impl PasswordRecord {
+    fn is_valid(&self) -> bool {
+        let (ch, range) = self.constraint;
+        let count = self.password.iter().filter(|&&c| c == ch).count();
+        count >= range.0 && count <= range.1
+    }
+
+    fn is_valid_xor(&self) -> bool {
+        let (ch, range) = self.constraint;
+        (self.password[range.0 - 1] == ch) ^ (self.password[range.1 - 1] == ch)
+    }
+}
+
+impl FromStr for PasswordRecord {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.trim().split_ascii_whitespace().collect();
+
+        let password = tokens[2].chars().collect();
+
+        let range = tokens[0].split('-').collect::<Vec<&str>>();
+        let range = (range[0].parse()?, range[1].parse()?);
+        let ch = tokens[1].chars().next().unwrap();
+        let constraint = (ch, range);
+
+        Ok(PasswordRecord {
+            password,
+            constraint,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 2);
+        assert_eq!(part2(EXAMPLE).unwrap(), 1);
+    }
+}
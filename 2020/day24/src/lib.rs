@@ -0,0 +1,151 @@
+use anyhow::Result;
+use std::str::Chars;
+
+const GRID_SIZE: usize = 1_000;
+const MIDDLE: (isize, isize) = (GRID_SIZE as isize / 2, GRID_SIZE as isize / 2);
+
+pub fn part1(input: &str) -> Result<usize> {
+    let tiles = lay_tiles(&parse_input(input));
+    Ok(tiles.iter().flatten().filter(|&&v| v).count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(live_exhibit(lay_tiles(&parse_input(input))))
+}
+
+pub fn parse_input(input: &str) -> Vec<Vec<Direction>> {
+    let mut all_directions = Vec::new();
+
+    for line in input.lines() {
+        let mut chars = line.trim().chars();
+
+        let mut directions = Vec::new();
+        while let Some(dir) = Direction::parse_dir(&mut chars) {
+            directions.push(dir);
+        }
+        all_directions.push(directions);
+    }
+
+    all_directions
+}
+
+// -> black tiles after following the directions
+fn lay_tiles(tile_directions: &[Vec<Direction>]) -> Vec<Vec<bool>> {
+    let mut tiles = vec![vec![false; GRID_SIZE]; GRID_SIZE];
+    for directions in tile_directions.iter() {
+        let (mut x, mut y) = MIDDLE;
+        directions
+            .iter()
+            .for_each(|dir| dir.apply((&mut x, &mut y)));
+
+        assert!(x >= 0 && y >= 0 && x < GRID_SIZE as isize && y < GRID_SIZE as isize);
+        let (x, y) = (x as usize, y as usize);
+        tiles[x][y] = !tiles[x][y];
+    }
+
+    tiles
+}
+
+fn live_exhibit(mut tiles: Vec<Vec<bool>>) -> usize {
+    for _ in 0..100 {
+        let mut new = tiles.clone();
+        for (x, row) in tiles.iter().enumerate() {
+            for (y, &t) in row.iter().enumerate() {
+                let adj = adjacent_black(x, y, &tiles);
+                if (t && (adj == 0 || adj > 2)) || (!t && adj == 2) {
+                    new[x][y] = !new[x][y];
+                }
+            }
+        }
+        tiles = new;
+    }
+
+    tiles.iter().flatten().filter(|&&v| v).count()
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    fn parse_dir(chars: &mut Chars) -> Option<Self> {
+        let c = chars.next()?;
+        match c {
+            'e' => Some(Direction::East),
+            'w' => Some(Direction::West),
+            's' => match chars.next()? {
+                'w' => Some(Direction::SouthWest),
+                'e' => Some(Direction::SouthEast),
+                _ => None,
+            },
+            'n' => match chars.next()? {
+                'w' => Some(Direction::NorthWest),
+                'e' => Some(Direction::NorthEast),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn apply(&self, (x, y): (&mut isize, &mut isize)) {
+        match self {
+            Direction::East => *x += 1,
+            Direction::SouthEast => {
+                *y += 1;
+            }
+            Direction::SouthWest => {
+                *x -= 1;
+                *y += 1;
+            }
+            Direction::West => *x -= 1,
+            Direction::NorthWest => {
+                *y -= 1;
+            }
+            Direction::NorthEast => {
+                *x += 1;
+                *y -= 1;
+            }
+        }
+    }
+}
+
+fn adjacent_black(x: usize, y: usize, tiles: &[Vec<bool>]) -> usize {
+    let (x, y) = (x as isize, y as isize);
+    [
+        (x + 1, y),
+        (x, y + 1),
+        (x - 1, y + 1),
+        (x - 1, y),
+        (x, y - 1),
+        (x + 1, y - 1),
+    ]
+    .into_iter()
+    .filter_map(|(x, y)| {
+        if x >= 0 && y >= 0 && x < GRID_SIZE as isize && y < GRID_SIZE as isize {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    })
+    .filter(|&(x, y)| tiles[x][y])
+    .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        // "esew" flips the tile immediately south-east, "nwwswee" the reference tile itself
+        assert_eq!(part1("esew\nnwwswee").unwrap(), 2);
+        assert_eq!(part1("esew\nse").unwrap(), 0);
+        assert_eq!(part1("nwwswee\nnwwswee").unwrap(), 0);
+    }
+}
@@ -0,0 +1,57 @@
+use anyhow::{Result, anyhow, bail};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (card, door) = parse_input(input)?;
+    encryption_key(card, door)
+}
+
+pub fn parse_input(input: &str) -> Result<(usize, usize)> {
+    let mut lines = input.lines();
+    let mut take_num = || -> Result<usize> {
+        Ok(lines
+            .next()
+            .ok_or_else(|| anyhow!("Expected number"))?
+            .trim()
+            .parse::<usize>()?)
+    };
+    Ok((take_num()?, take_num()?))
+}
+
+fn encryption_key(card: usize, door: usize) -> Result<usize> {
+    let (c_loop, d_loop) = (find_loop_size(card), find_loop_size(door));
+    let c_key = calc_encryption_key(door, c_loop);
+    let d_key = calc_encryption_key(card, d_loop);
+    if c_key == d_key {
+        Ok(c_key)
+    } else {
+        bail!("Keys don't match")
+    }
+}
+
+fn find_loop_size(pub_key: usize) -> usize {
+    let mut val = 1;
+    let mut loop_size = 0;
+    loop {
+        loop_size += 1;
+        val = (val * 7) % 20201227;
+        if val == pub_key {
+            return loop_size;
+        }
+    }
+}
+
+fn calc_encryption_key(subject: usize, loop_size: usize) -> usize {
+    (0..loop_size).fold(1, |val, _| (val * subject) % 20201227)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(find_loop_size(5764801), 8);
+        assert_eq!(find_loop_size(17807724), 11);
+        assert_eq!(part1("5764801\n17807724").unwrap(), 14897079);
+    }
+}
@@ -0,0 +1,74 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input).trees_hit(1, 3))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let map = parse_input(input);
+    Ok([(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)]
+        .iter()
+        .map(|&(xd, yd)| map.trees_hit(xd, yd))
+        .product())
+}
+
+pub fn parse_input(input: &str) -> Map {
+    let cells = input
+        .lines()
+        .map(|l| l.trim().chars().map(|ch| ch == '#').collect())
+        .collect();
+    Map { cells }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    /// true -> tree, false -> open
+    cells: Vec<Vec<bool>>,
+}
+
+impl Map {
+    fn trees_hit(&self, xd: usize, yd: usize) -> usize {
+        let mut trees = 0;
+        let (mut x, mut y) = (0, 0);
+        let cols = self.cells[0].len();
+        let rows = self.cells.len();
+
+        while x < rows {
+            y %= cols;
+
+            if self.cells[x][y] {
+                trees += 1;
+            }
+
+            x += xd;
+            y += yd;
+        }
+
+        trees
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 7);
+        assert_eq!(part2(EXAMPLE).unwrap(), 336);
+    }
+}
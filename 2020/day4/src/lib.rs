@@ -0,0 +1,197 @@
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input).iter().filter(|p| p.valid()).count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)
+        .iter()
+        .filter(|p| p.valid_strict().is_ok())
+        .count())
+}
+
+pub fn parse_input(input: &str) -> Vec<Passport> {
+    aoc_common::sections(input)
+        .into_iter()
+        .map(|section| {
+            let mut passport = Passport::default();
+            for field in section.split_whitespace() {
+                let key_value: Vec<_> = field.split(':').collect();
+                passport
+                    .fields
+                    .insert(key_value[0].to_string(), key_value[1].to_string());
+            }
+            passport
+        })
+        .collect()
+}
+
+#[derive(Debug, Default)]
+pub struct Passport {
+    fields: HashMap<String, String>,
+}
+
+const REQUIRED_FIELDS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+impl Passport {
+    fn valid(&self) -> bool {
+        REQUIRED_FIELDS
+            .iter()
+            .all(|&req| self.fields.contains_key(req))
+    }
+
+    fn valid_strict(&self) -> Result<()> {
+        if !self.valid() {
+            bail!("Invalid passport: not all fields provided");
+        }
+
+        for &field in REQUIRED_FIELDS {
+            let val = &self.fields[field].trim();
+            if val.is_empty() {
+                bail!("Field {field} not given value")
+            }
+
+            match field {
+                "byr" => valid_year(val, 1920..=2002)?,
+                "iyr" => valid_year(val, 2010..=2020)?,
+                "eyr" => valid_year(val, 2020..=2030)?,
+                "hgt" => valid_height(val)?,
+                "hcl" => valid_hair_color(val)?,
+                "ecl" => valid_eye_color(val)?,
+                "pid" => valid_passport_id(val)?,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn valid_year(year: &str, range: RangeInclusive<usize>) -> Result<()> {
+    if year.len() != 4 {
+        bail!("Year '{}' must be 4 digits", year);
+    }
+
+    if !range.contains(&year.parse()?) {
+        bail!("Year not in range: {range:?}");
+    }
+
+    Ok(())
+}
+
+fn valid_height(height: &str) -> Result<()> {
+    if let Some(height) = height.strip_suffix("cm") {
+        let height: i32 = height.parse()?;
+        if !(150..=193).contains(&height) {
+            bail!("Invalid height: {height}");
+        }
+    } else if let Some(height) = height.strip_suffix("in") {
+        let height: i32 = height.parse()?;
+        if !(59..=76).contains(&height) {
+            bail!("Invalid height: {height}");
+        }
+    } else {
+        bail!("Invalid height: {height}");
+    }
+
+    Ok(())
+}
+
+fn valid_hair_color(color: &str) -> Result<()> {
+    if !color.starts_with("#") {
+        bail!("Color must start with \"#\"");
+    }
+
+    if color.chars().count() != 7 {
+        bail!("Color must be 6 digits");
+    }
+
+    if !color
+        .chars()
+        .skip(1)
+        .all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+    {
+        bail!("Color must contain only hex digits")
+    }
+
+    Ok(())
+}
+
+fn valid_eye_color(color: &str) -> Result<()> {
+    if !matches!(color, "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth") {
+        bail!("Invalid color: {color}");
+    }
+
+    Ok(())
+}
+
+fn valid_passport_id(pid: &str) -> Result<()> {
+    if pid.len() != 9 {
+        bail!("PID must be 9 digits");
+    }
+
+    pid.parse::<usize>()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let passports = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+        assert_eq!(part1(passports).unwrap(), 2);
+
+        let invalid = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+        assert_eq!(part2(invalid).unwrap(), 0);
+
+        let valid = "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+";
+        assert_eq!(part2(valid).unwrap(), 4);
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::{Result, bail};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let seat_ids = sorted_seat_ids(input);
+    Ok(seat_ids[seat_ids.len() - 1])
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    for seats in sorted_seat_ids(input).windows(2) {
+        let (s1, s2) = (seats[0], seats[1]);
+        if s2 == s1 + 2 {
+            return Ok(s1 + 1);
+        }
+    }
+    bail!("No gap found between seat IDs")
+}
+
+fn sorted_seat_ids(input: &str) -> Vec<usize> {
+    let mut seat_ids: Vec<usize> = input.lines().map(|p| p.seat_id()).collect();
+    seat_ids.sort_unstable();
+    seat_ids
+}
+
+trait BoardingPass {
+    fn seat_id(&self) -> usize;
+}
+
+impl BoardingPass for str {
+    // Apparently "binary space partitioning" the problem is describing amounts to representing the
+    // row/col selection as one 10-bit binary number. Multiplying row num (7 bits) by 8 is a shift
+    // by 3, making room for the last 3 bits of the column.
+    fn seat_id(&self) -> usize {
+        let mut id = 0;
+        let mut pow_2 = 1;
+        for s in self.chars().rev() {
+            match s {
+                'B' | 'R' => {
+                    id += pow_2;
+                }
+                _ => {}
+            }
+            pow_2 <<= 1;
+        }
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!("FBFBBFFRLR".seat_id(), 357);
+        assert_eq!("BFFFBBFRRR".seat_id(), 567);
+        assert_eq!("FFFBBBFRRR".seat_id(), 119);
+        assert_eq!("BBFFBBFRLL".seat_id(), 820);
+        assert_eq!(part1("BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL").unwrap(), 820);
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input).unique_sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input).common_sum())
+}
+
+pub fn parse_input(input: &str) -> Vec<Group> {
+    let mut groups = Vec::new();
+    let mut group = Group::default();
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            groups.push(group);
+            group = Group::default();
+            continue;
+        }
+
+        let answers = line.trim().chars().collect();
+        group.answers.push(answers);
+    }
+    groups.push(group);
+
+    groups
+}
+
+#[derive(Debug, Default)]
+pub struct Group {
+    /// answers[i] = person i's 'yes' answers
+    answers: Vec<HashSet<char>>,
+}
+
+impl Group {
+    fn unique_answers(&self) -> HashSet<char> {
+        self.answers.iter().flatten().copied().collect()
+    }
+
+    fn common_answers(&self) -> HashSet<char> {
+        self.answers
+            .iter()
+            .skip(1)
+            .fold(self.answers[0].clone(), |acc, x| {
+                acc.intersection(x).copied().collect()
+            })
+    }
+}
+
+trait Stats {
+    fn unique_sum(&self) -> usize;
+    fn common_sum(&self) -> usize;
+}
+
+impl Stats for Vec<Group> {
+    fn unique_sum(&self) -> usize {
+        self.iter().map(|g| g.unique_answers().len()).sum()
+    }
+
+    fn common_sum(&self) -> usize {
+        self.iter().map(|g| g.common_answers().len()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 11);
+        assert_eq!(part2(EXAMPLE).unwrap(), 6);
+    }
+}
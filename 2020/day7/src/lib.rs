@@ -0,0 +1,112 @@
+use anyhow::Result;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.paths_to("shiny gold"))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?.total_bags("shiny gold", 1) - 1)
+}
+
+pub fn parse_input(input: &str) -> Result<BagGraph> {
+    let mut adj_list = HashMap::new();
+    for line in input.lines() {
+        let tokens: Vec<_> = line.trim().split_ascii_whitespace().collect();
+
+        let bag: String = tokens[0..=1].to_vec().join(" ");
+
+        adj_list.insert(bag.clone(), HashSet::new());
+        if line.contains("no other bags") {
+            continue;
+        }
+        for i in 4..tokens.len() {
+            if tokens[i].contains("bag") {
+                let (amount, color) = (
+                    tokens[i - 3].parse()?,
+                    tokens[i - 2..=(i - 1)].to_vec().join(" "),
+                );
+                adj_list.get_mut(&bag).unwrap().insert((color, amount));
+            }
+        }
+    }
+
+    Ok(BagGraph { adj_list })
+}
+
+#[derive(Debug)]
+pub struct BagGraph {
+    /// Container -> contains with count
+    adj_list: HashMap<String, HashSet<(String, usize)>>,
+}
+
+impl BagGraph {
+    fn paths_to(&self, color: &str) -> usize {
+        let mut paths = 0;
+        let mut queue = VecDeque::from(vec![color]);
+        let mut visited = HashSet::new();
+        visited.insert(color);
+
+        while let Some(bag) = queue.pop_front() {
+            let contains = self
+                .adj_list
+                .iter()
+                .filter(|(_container, contained)| contained.iter().any(|(b, _count)| b == bag));
+            for (container, _contained) in contains.into_iter() {
+                if visited.insert(container) {
+                    paths += 1;
+                    queue.push_back(container);
+                }
+            }
+        }
+
+        paths
+    }
+
+    fn total_bags(&self, color: &str, count: usize) -> usize {
+        let contained = self.adj_list.get(color).unwrap();
+        if contained.is_empty() {
+            count
+        } else {
+            contained
+                .iter()
+                .map(|(bag, c)| count * self.total_bags(bag, *c))
+                .sum::<usize>()
+                + count
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 4);
+        assert_eq!(part2(EXAMPLE).unwrap(), 32);
+
+        let nested = "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+";
+        assert_eq!(part2(nested).unwrap(), 126);
+    }
+}
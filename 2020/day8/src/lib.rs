@@ -0,0 +1,131 @@
+use anyhow::{Error, Result, bail};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<isize> {
+    let mut computer = Computer::new(parse_input(input)?);
+    computer.execute_until_repeat();
+    Ok(computer.acc)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let program = parse_input(input)?;
+    for (i, ins) in program.iter().enumerate() {
+        let mut program = program.clone();
+        if let Instruction::Jmp(j) = ins {
+            program[i] = Instruction::Nop(*j);
+        } else if let Instruction::Nop(n) = ins {
+            program[i] = Instruction::Jmp(*n);
+        } else {
+            continue;
+        }
+
+        let mut computer = Computer::new(program);
+        if computer.execute_until_term() {
+            return Ok(computer.acc);
+        }
+    }
+
+    bail!("No single instruction swap lets the program terminate")
+}
+
+pub fn parse_input(input: &str) -> Result<Program> {
+    input.lines().map(str::parse).collect()
+}
+
+type Program = Vec<Instruction>;
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Acc(isize),
+    Jmp(isize),
+    Nop(isize),
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.trim().split(" ").collect();
+        let val = tokens[1].parse()?;
+        match tokens[0] {
+            "acc" => Ok(Instruction::Acc(val)),
+            "jmp" => Ok(Instruction::Jmp(val)),
+            "nop" => Ok(Instruction::Nop(val)),
+            _ => bail!("Invalid instruction: {s}"),
+        }
+    }
+}
+
+struct Computer {
+    ip: usize,
+    acc: isize,
+    program: Program,
+}
+
+impl Computer {
+    fn new(program: Program) -> Self {
+        Self {
+            ip: 0,
+            acc: 0,
+            program,
+        }
+    }
+
+    fn execute_until_repeat(&mut self) {
+        let mut executed = HashSet::new();
+        while executed.insert(self.ip) {
+            self.execute_ins();
+        }
+    }
+
+    fn execute_until_term(&mut self) -> bool {
+        let mut ins_executed = 0;
+        while self.ip != self.program.len() {
+            self.execute_ins();
+
+            // If we toil away for too long, bail and try changing another instruction
+            ins_executed += 1;
+            if ins_executed == 1000 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn execute_ins(&mut self) {
+        match self.program[self.ip] {
+            Instruction::Acc(a) => {
+                self.acc += a;
+                self.ip += 1;
+            }
+            Instruction::Jmp(j) => {
+                self.ip = ((self.ip as isize) + j) as usize;
+            }
+            Instruction::Nop(_n) => self.ip += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 5);
+        assert_eq!(part2(EXAMPLE).unwrap(), 8);
+    }
+}
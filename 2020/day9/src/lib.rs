@@ -0,0 +1,92 @@
+use anyhow::{Error, Result, bail};
+
+pub const PREAMBLE: usize = 25;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, PREAMBLE)
+}
+
+pub fn part1_with(input: &str, preamble: usize) -> Result<usize> {
+    first_invalid(&parse_input(input)?, preamble)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, PREAMBLE)
+}
+
+pub fn part2_with(input: &str, preamble: usize) -> Result<usize> {
+    let numbers = parse_input(input)?;
+    encryption_weakness(&numbers, first_invalid(&numbers, preamble)?)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    input
+        .lines()
+        .map(|l| l.parse().map_err(Error::from))
+        .collect()
+}
+
+fn first_invalid(numbers: &[usize], preamble: usize) -> Result<usize> {
+    for (i, &n) in numbers.iter().enumerate().skip(preamble) {
+        if !two_sum(&numbers[i - preamble..i], n) {
+            return Ok(n);
+        }
+    }
+    bail!("Part 1 not found")
+}
+
+fn two_sum(numbers: &[usize], target: usize) -> bool {
+    for i in 0..numbers.len() - 1 {
+        for j in i + 1..numbers.len() {
+            if numbers[i] + numbers[j] == target {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn encryption_weakness(numbers: &[usize], target: usize) -> Result<usize> {
+    for w_size in 2..numbers.len() {
+        for window in numbers.windows(w_size) {
+            if window.iter().sum::<usize>() == target {
+                return Ok(window.iter().min().unwrap() + window.iter().max().unwrap());
+            }
+        }
+    }
+    bail!("Part 2 not found")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, 5).unwrap(), 127);
+        assert_eq!(part2_with(EXAMPLE, 5).unwrap(), 62);
+    }
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_increases(&parse_input(input)?))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let windows: Vec<_> = parse_input(input)?
+        .windows(3)
+        .map(|w| w.iter().sum::<usize>())
+        .collect();
+    Ok(count_increases(&windows))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    aoc_common::parse_lines(input)
+}
+
+fn count_increases(sonar_report: &[usize]) -> usize {
+    sonar_report.windows(2).filter(|w| w[1] > w[0]).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 7);
+        assert_eq!(part2(EXAMPLE).unwrap(), 5);
+    }
+}
@@ -0,0 +1,78 @@
+use anyhow::Result;
+
+/// Return either OK with remaining stack or error with syntax error score
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(input.lines().filter_map(|n| syntax_eval(n).err()).sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut scores: Vec<_> = input
+        .lines()
+        .filter_map(|n| syntax_eval(n).ok())
+        .map(|stack| close_score(&stack))
+        .collect();
+    scores.sort();
+    Ok(scores[scores.len() / 2])
+}
+
+fn syntax_eval(n: &str) -> Result<Vec<char>, usize> {
+    let mut stack = Vec::new();
+    for c in n.trim().chars() {
+        match c {
+            '(' | '{' | '[' | '<' => stack.push(c),
+            close => {
+                let open = stack.pop().unwrap_or('x');
+                let s = match (open, close) {
+                    ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>') => 0,
+                    (_, ')') => 3,
+                    (_, ']') => 57,
+                    (_, '}') => 1197,
+                    (_, '>') => 25137,
+                    _ => panic!("Unhandled case: ({open}, {close})"),
+                };
+                if s != 0 {
+                    return Err(s);
+                }
+            }
+        }
+    }
+
+    Ok(stack)
+}
+
+fn close_score(stack: &[char]) -> usize {
+    stack.iter().rev().fold(0, |score, open| {
+        score * 5
+            + match open {
+                '(' => 1,
+                '[' => 2,
+                '{' => 3,
+                '<' => 4,
+                _ => panic!("Unhandled open: {open}"),
+            }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 26397);
+        assert_eq!(part2(EXAMPLE).unwrap(), 288957);
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut grid = parse_input(input)?;
+    Ok((0..100).map(|_| grid.step()).sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut grid = parse_input(input)?;
+    let mut step = 0;
+    let total = grid.cells.len() * grid.cells[0].len();
+    loop {
+        step += 1;
+        let flashes = grid.step();
+        if flashes == total {
+            return Ok(step);
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid> {
+    let cells = input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, l)| {
+            l.chars()
+                .enumerate()
+                .map(|(col_idx, c)| {
+                    c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                        anyhow!(
+                            "Invalid digit '{c}' at line {}, column {}",
+                            line_idx + 1,
+                            col_idx + 1
+                        )
+                    })
+                })
+                .collect::<Result<Vec<u8>>>()
+        })
+        .collect::<Result<Vec<Vec<u8>>>>()?;
+    Ok(Grid { cells })
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    cells: Vec<Vec<u8>>,
+}
+
+impl Grid {
+    fn step(&mut self) -> usize {
+        let mut flashed = HashSet::new();
+        let mut to_flash = Vec::new();
+        for (i, row) in self.cells.iter_mut().enumerate() {
+            for (j, o) in row.iter_mut().enumerate() {
+                *o += 1;
+                if *o > 9 {
+                    to_flash.push((i, j));
+                    flashed.insert((i, j));
+                }
+            }
+        }
+
+        while let Some((i, j)) = to_flash.pop() {
+            for (di, dj) in [
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ] {
+                if let Some((i, j)) = self.in_bounds((i as isize + di, j as isize + dj)) {
+                    let adj = &mut self.cells[i][j];
+                    if *adj == 9 && flashed.insert((i, j)) {
+                        to_flash.push((i, j));
+                    }
+                    *adj += 1;
+                }
+            }
+        }
+
+        flashed.iter().for_each(|&(i, j)| {
+            self.cells[i][j] = 0;
+        });
+
+        flashed.len()
+    }
+
+    fn in_bounds(&self, (i, j): (isize, isize)) -> Option<(usize, usize)> {
+        if i < 0
+            || i >= self.cells.len() as isize
+            || j < 0
+            || j >= self.cells[i as usize].len() as isize
+        {
+            None
+        } else {
+            Some((i as usize, j as usize))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 1656);
+        assert_eq!(part2(EXAMPLE).unwrap(), 195);
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let graph = parse_input(input)?;
+    Ok(graph.path_count_dfs("end", &mut Context::new("start", Filter::UniqueSmall)))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let graph = parse_input(input)?;
+    Ok(graph.path_count_dfs("end", &mut Context::new("start", Filter::DupeSmall)))
+}
+
+pub fn parse_input(input: &str) -> Result<Graph> {
+    let mut adj_list: HashMap<String, Vec<String>> = HashMap::new();
+    for (line_no, line) in input.lines().enumerate() {
+        let (from, to) = line
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("Invalid input line(L{}): {}", line_no + 1, line))?;
+
+        adj_list
+            .entry(from.to_string())
+            .or_default()
+            .push(to.to_string());
+        adj_list
+            .entry(to.to_string())
+            .or_default()
+            .push(from.to_string());
+    }
+
+    Ok(Graph { adj_list })
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    adj_list: HashMap<String, Vec<String>>,
+}
+
+struct Context {
+    current: Vec<String>,
+    used: HashMap<String, usize>,
+    filter: Filter,
+}
+
+enum Filter {
+    UniqueSmall,
+    DupeSmall,
+}
+
+trait CaveExt {
+    fn is_small_cave(&self) -> bool;
+}
+
+impl CaveExt for str {
+    fn is_small_cave(&self) -> bool {
+        self.chars().all(|c| c.is_ascii_lowercase())
+    }
+}
+
+impl CaveExt for String {
+    fn is_small_cave(&self) -> bool {
+        self.as_str().is_small_cave()
+    }
+}
+
+impl Context {
+    fn new(start: &str, filter: Filter) -> Self {
+        let current = vec![start.to_string()];
+        let used = HashMap::from([(start.to_string(), 1)]);
+
+        Self {
+            current,
+            used,
+            filter,
+        }
+    }
+
+    fn head(&self) -> &String {
+        self.current.last().unwrap()
+    }
+
+    fn push(&mut self, to: String) {
+        self.current.push(to.clone());
+        self.used.entry(to).and_modify(|c| *c += 1).or_insert(1);
+    }
+
+    fn pop(&mut self) {
+        let to = self.current.pop().unwrap();
+        self.used.entry(to).and_modify(|c| *c -= 1);
+    }
+
+    fn filter_out(&self, to: &String) -> bool {
+        let small_cave = to.is_small_cave();
+        let to_visited = self.used.get(to).copied().unwrap_or(0);
+
+        match self.filter {
+            Filter::UniqueSmall => small_cave && to_visited > 0,
+            Filter::DupeSmall => {
+                let other_small_dupe = self
+                    .used
+                    .iter()
+                    .any(|(c, &v)| c != to && v > 1 && c.is_small_cave());
+                ((to == "start" || to == "end") && to_visited > 0)
+                    || (small_cave && to_visited > 1)
+                    || (small_cave && to_visited == 1 && other_small_dupe)
+            }
+        }
+    }
+}
+
+impl Graph {
+    fn path_count_dfs(&self, end: &str, current: &mut Context) -> usize {
+        if current.head() == end {
+            return 1;
+        }
+
+        let mut path_count = 0;
+        for to in self.adj_list.get(current.head()).unwrap_or(&vec![]) {
+            if current.filter_out(to) {
+                continue;
+            }
+
+            current.push(to.clone());
+            path_count += self.path_count_dfs(end, current);
+            current.pop();
+        }
+
+        path_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let small = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
+        assert_eq!(part1(small).unwrap(), 10);
+        assert_eq!(part2(small).unwrap(), 36);
+
+        let larger = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+";
+        assert_eq!(part1(larger).unwrap(), 19);
+        assert_eq!(part2(larger).unwrap(), 103);
+    }
+}
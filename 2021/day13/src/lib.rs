@@ -0,0 +1,160 @@
+use anyhow::{Error, Result};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (mut points, folds) = parse_input(input)?;
+    points.fold(&folds[0]);
+    Ok(points.len())
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    let (mut points, folds) = parse_input(input)?;
+    folds.iter().for_each(|fold| points.fold(fold));
+    Ok(points.render())
+}
+
+pub fn parse_input(input: &str) -> Result<(HashSet<Point>, Vec<Fold>)> {
+    let mut lines = input.lines();
+
+    let mut points = HashSet::new();
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        points.insert(line.parse()?);
+    }
+
+    let mut folds = Vec::new();
+    for line in lines.by_ref() {
+        folds.push(line.parse()?);
+    }
+
+    Ok((points, folds))
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| Error::msg("invalid point"))?;
+        let (x, y) = (x.parse()?, y.parse()?);
+        Ok(Point { x, y })
+    }
+}
+
+trait FoldPaper {
+    fn fold(&mut self, fold: &Fold);
+}
+
+impl FoldPaper for HashSet<Point> {
+    fn fold(&mut self, fold: &Fold) {
+        *self = self.iter().map(|p| fold.apply(p)).collect();
+    }
+}
+
+trait Render {
+    fn render(&self) -> String;
+}
+
+impl Render for HashSet<Point> {
+    fn render(&self) -> String {
+        let (max_x, max_y) = self
+            .iter()
+            .fold((0, 0), |(max_x, max_y), p| (max_x.max(p.x), max_y.max(p.y)));
+        let mut rendered = String::new();
+        for y in 0..=max_y {
+            rendered.push('\n');
+            for x in 0..=max_x {
+                if self.contains(&Point { x, y }) {
+                    rendered.push('#');
+                } else {
+                    rendered.push(' ');
+                }
+            }
+        }
+        rendered
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+impl FromStr for Fold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.split(&[' ', '=']).collect();
+        let value = tokens[3].parse()?;
+        match tokens[2] {
+            "x" => Ok(Fold::X(value)),
+            "y" => Ok(Fold::Y(value)),
+            _ => Err(Error::msg("invalid fold")),
+        }
+    }
+}
+
+impl Fold {
+    fn apply(&self, point: &Point) -> Point {
+        let (x, y) = match *self {
+            Fold::X(v) if point.x > v => (v - (point.x - v), point.y),
+            Fold::Y(v) if point.y > v => (point.x, v - (point.y - v)),
+            _ => (point.x, point.y),
+        };
+        Point { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 17);
+        assert_eq!(
+            part2(EXAMPLE).unwrap(),
+            "
+#####
+#   #
+#   #
+#   #
+#####"
+        );
+    }
+}
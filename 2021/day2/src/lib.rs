@@ -0,0 +1,68 @@
+use anyhow::{Error, Result};
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<isize> {
+    let course = parse_input(input)?;
+    let (h, d) = course.iter().fold((0, 0), |(h, d), dir| match dir {
+        Direction::Forward(u) => (h + u, d),
+        Direction::Down(u) => (h, d + u),
+        Direction::Up(u) => (h, d - u),
+    });
+    Ok(h * d)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    let course = parse_input(input)?;
+    let (_a, h, d) = course.iter().fold((0, 0, 0), |(a, h, d), dir| match dir {
+        Direction::Down(u) => (a + u, h, d),
+        Direction::Up(u) => (a - u, h, d),
+        Direction::Forward(u) => (a, h + u, d + a * u),
+    });
+    Ok(h * d)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Direction>> {
+    input.lines().map(str::parse).collect()
+}
+
+#[derive(Debug)]
+pub enum Direction {
+    Forward(isize),
+    Down(isize),
+    Up(isize),
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.trim().split_ascii_whitespace().collect();
+        let units = tokens[1].parse()?;
+        match tokens[0] {
+            "forward" => Ok(Direction::Forward(units)),
+            "down" => Ok(Direction::Down(units)),
+            "up" => Ok(Direction::Up(units)),
+            _ => Err(Error::msg("Invalid direction")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 150);
+        assert_eq!(part2(EXAMPLE).unwrap(), 900);
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::{Result, anyhow};
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (numbers, mut boards) = parse_input(input)?;
+
+    for number in numbers {
+        for board in boards.iter_mut() {
+            board.mark(number);
+
+            if board.check_bingo() {
+                return Ok(board.score(number));
+            }
+        }
+    }
+
+    Err(anyhow!("No first winning board found"))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (numbers, mut boards) = parse_input(input)?;
+    let len = boards.len();
+
+    let mut winners = HashSet::new();
+    for number in numbers {
+        for (idx, board) in boards.iter_mut().enumerate() {
+            board.mark(number);
+
+            if board.check_bingo() {
+                winners.insert(idx);
+
+                if winners.len() == len {
+                    return Ok(board.score(number));
+                }
+            }
+        }
+    }
+
+    Err(anyhow!("No last winning board found"))
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<Board>)> {
+    let mut lines = input.lines();
+
+    let numbers = lines.next().ok_or_else(|| anyhow!("No numbers given"))?;
+    let numbers = numbers
+        .trim()
+        .split(',')
+        .map(|n| n.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let _blank = lines.next().ok_or_else(|| anyhow!("Expected blank line"))?;
+
+    let mut boards = Vec::new();
+    let mut spaces = Vec::new();
+    for line in lines {
+        if line.trim().is_empty() {
+            boards.push(Board { spaces });
+            spaces = Vec::new();
+            continue;
+        }
+
+        let line = line
+            .trim()
+            .split_ascii_whitespace()
+            .map(|n| n.parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()?;
+        spaces.push(line.into_iter().map(|num| (num, false)).collect());
+    }
+    boards.push(Board { spaces });
+
+    Ok((numbers, boards))
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    spaces: Vec<Vec<(usize, bool)>>,
+}
+
+impl Board {
+    fn mark(&mut self, number: usize) {
+        self.spaces.iter_mut().flatten().for_each(|(n, marked)| {
+            if *n == number {
+                *marked = true
+            }
+        });
+    }
+
+    fn check_bingo(&self) -> bool {
+        if self
+            .spaces
+            .iter()
+            .any(|row| row.iter().all(|&(_, marked)| marked))
+        {
+            return true;
+        }
+
+        for col in 0..self.spaces[0].len() {
+            if self.spaces.iter().all(|row| row[col].1) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn score(&self, last_num: usize) -> usize {
+        self.spaces
+            .iter()
+            .flatten()
+            .filter_map(|&(n, m)| if !m { Some(n) } else { None })
+            .sum::<usize>()
+            * last_num
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 4512);
+        assert_eq!(part2(EXAMPLE).unwrap(), 1924);
+    }
+}
@@ -0,0 +1,141 @@
+use anyhow::{Error, Result, anyhow};
+use std::ops::AddAssign;
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_overlaps(&parse_input(input)?, true))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(count_overlaps(&parse_input(input)?, false))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<LineSegment>> {
+    input.lines().map(str::parse).collect()
+}
+
+fn count_overlaps(line_segments: &[LineSegment], filter_diag: bool) -> usize {
+    let line_segments: Vec<_> = if filter_diag {
+        line_segments
+            .iter()
+            .filter(|&ls| ls.is_vertical() || ls.is_horizontal())
+            .collect()
+    } else {
+        line_segments.iter().collect()
+    };
+
+    let (max_y, max_x) =
+        line_segments
+            .iter()
+            .fold((isize::MIN, isize::MIN), |(max_y, max_x), seg| {
+                (
+                    max_y.max(seg.start.y).max(seg.end.y),
+                    max_x.max(seg.start.x).max(seg.end.x),
+                )
+            });
+    let (max_y, max_x) = (max_y as usize, max_x as usize);
+    let mut grid = vec![vec![0; max_x + 1]; max_y + 1];
+
+    for segment in line_segments.iter() {
+        let mut current = segment.start;
+        let diff = segment.diff();
+        loop {
+            grid[current.y as usize][current.x as usize] += 1;
+
+            if current == segment.end {
+                break;
+            }
+
+            current += diff;
+        }
+    }
+
+    grid.iter().flatten().filter(|&&l| l > 1).count()
+}
+
+#[derive(Debug)]
+pub struct LineSegment {
+    start: Point,
+    end: Point,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl FromStr for LineSegment {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (start, end) = s
+            .trim()
+            .split_once(" -> ")
+            .ok_or_else(|| anyhow!("Invalid LineSegment format: {s}"))?;
+        let (start, end) = (start.parse()?, end.parse()?);
+        Ok(LineSegment { start, end })
+    }
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (x, y) = s
+            .trim()
+            .split_once(",")
+            .ok_or_else(|| anyhow!("Invalid Point format: {s}"))?;
+        let (x, y) = (x.parse()?, y.parse()?);
+        Ok(Point { x, y })
+    }
+}
+
+impl LineSegment {
+    fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    fn diff(&self) -> Point {
+        let (y, x) = (
+            self.end.y.cmp(&self.start.y) as isize,
+            self.end.x.cmp(&self.start.x) as isize,
+        );
+        Point { x, y }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        self.y += rhs.y;
+        self.x += rhs.x;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 5);
+        assert_eq!(part2(EXAMPLE).unwrap(), 12);
+    }
+}
@@ -0,0 +1,47 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(simulate(&parse_input(input)?, 80))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(simulate(&parse_input(input)?, 256))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    aoc_common::numbers(input)
+}
+
+fn simulate(fish: &[usize], days: usize) -> usize {
+    let mut fish_counts = [0; 9];
+    fish.iter().for_each(|&f| fish_counts[f] += 1);
+
+    (0..days).for_each(|_| {
+        let mut new_counts = [0; 9];
+        fish_counts.iter().enumerate().for_each(|(t, &count)| {
+            if t == 0 {
+                new_counts[6] += count;
+                new_counts[8] += count;
+            } else {
+                new_counts[t - 1] += count;
+            }
+        });
+        fish_counts = new_counts;
+    });
+
+    fish_counts.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(simulate(&parse_input(EXAMPLE).unwrap(), 18), 26);
+        assert_eq!(part1(EXAMPLE).unwrap(), 5934);
+        assert_eq!(part2(EXAMPLE).unwrap(), 26984457539);
+    }
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(min_fuel(&parse_input(input)?, |a, p| a.abs_diff(p)))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(min_fuel(&parse_input(input)?, |a, p| {
+        // 1 + 2 + 3 + ... n
+        let n = a.abs_diff(p);
+        n * (n + 1) / 2
+    }))
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    aoc_common::numbers(input)
+}
+
+fn min_fuel<F>(positions: &[usize], align_cost: F) -> usize
+where
+    F: Fn(usize, usize) -> usize,
+{
+    let (min, max) = positions
+        .iter()
+        .fold((usize::MAX, usize::MIN), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        });
+
+    (min..=max)
+        .map(|align_to| positions.iter().map(|&p| align_cost(align_to, p)).sum())
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 37);
+        assert_eq!(part2(EXAMPLE).unwrap(), 168);
+    }
+}
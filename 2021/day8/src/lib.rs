@@ -0,0 +1,115 @@
+use anyhow::{Result, bail};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?
+        .iter()
+        .flat_map(|(_s, d)| d)
+        .filter(|d| matches!(d.count_ones(), 2 | 3 | 4 | 7))
+        .count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_input(input)?
+        .iter()
+        .map(|(signals, digits)| (decode(signals), digits))
+        .map(|(decoded, digits)| {
+            digits
+                .iter()
+                .fold(0, |acc, &d| acc * 10 + eval(d, &decoded))
+        })
+        .sum())
+}
+
+type Input = Vec<(Vec<u8>, Vec<u8>)>;
+
+pub fn parse_input(input: &str) -> Result<Input> {
+    input
+        .lines()
+        .map(|l| {
+            let Some((signals, digits)) = l.trim().split_once(" | ") else {
+                bail!("Missing delimiter ' | '")
+            };
+            Ok((parse_masks(signals)?, parse_masks(digits)?))
+        })
+        .collect()
+}
+
+fn parse_masks(s: &str) -> Result<Vec<u8>> {
+    s.split_ascii_whitespace().map(to_bitmask).collect()
+}
+
+fn to_bitmask(s: &str) -> Result<u8> {
+    let mut mask = 0u8;
+    for c in s.chars() {
+        let v = c as usize;
+        if !(97..=103).contains(&v) {
+            bail!("Invalid character in digit: {c}");
+        }
+        mask |= 1 << (v - 97);
+    }
+    Ok(mask)
+}
+
+fn decode(patterns: &[u8]) -> [u8; 7] {
+    let one = patterns.iter().find(|p| p.count_ones() == 2).unwrap();
+    let seven = patterns.iter().find(|p| p.count_ones() == 3).unwrap();
+    let four = patterns.iter().find(|p| p.count_ones() == 4).unwrap();
+    let eight = patterns.iter().find(|p| p.count_ones() == 7).unwrap();
+    let a = one ^ seven;
+    let bd = one ^ four;
+    let eg = eight ^ (one | four | seven);
+    let cf = seven & one;
+    let adg = patterns
+        .iter()
+        .filter(|p| p.count_ones() == 5)
+        .fold(u8::MAX, |acc, &p| acc & p);
+    let d = adg & bd;
+    let g = adg & eg;
+    let b = bd & !d;
+    let e = eg & !g;
+    let abfg = patterns
+        .iter()
+        .filter(|p| p.count_ones() == 6)
+        .fold(u8::MAX, |acc, &p| acc & p);
+    let f = abfg & cf;
+    let c = cf & !f;
+    [a, b, c, d, e, f, g]
+}
+
+fn eval(scrambled_mask: u8, decoded: &[u8; 7]) -> usize {
+    let mut mask: u8 = 0;
+    for (segment, &wire) in decoded.iter().enumerate() {
+        if (scrambled_mask & wire) != 0 {
+            mask |= 1 << segment;
+        }
+    }
+
+    match mask {
+        0b01110111 => 0,
+        0b00100100 => 1,
+        0b01011101 => 2,
+        0b01101101 => 3,
+        0b00101110 => 4,
+        0b01101011 => 5,
+        0b01111011 => 6,
+        0b00100101 => 7,
+        0b01111111 => 8,
+        0b01101111 => 9,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    #[test]
+    fn test_examples() {
+        // Every output digit here has five segments, so none are easy to spot
+        assert_eq!(part1(EXAMPLE).unwrap(), 0);
+        assert_eq!(part2(EXAMPLE).unwrap(), 5353);
+    }
+}
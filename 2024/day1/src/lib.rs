@@ -0,0 +1,62 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<u64> {
+    let (mut list_one, mut list_two) = parse(input);
+    list_one.sort_unstable();
+    list_two.sort_unstable();
+    let total: u64 = list_one
+        .into_iter()
+        .zip(list_two)
+        .map(|(n1, n2)| n1.abs_diff(n2))
+        .sum();
+    Ok(total)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    let (list_one, list_two) = parse(input);
+    let mut counts = HashMap::new();
+    for n in list_two {
+        *counts.entry(n).or_insert(0) += 1;
+    }
+    let total: u64 = list_one
+        .iter()
+        .map(|n1| n1 * counts.get(n1).unwrap_or(&0))
+        .sum();
+    Ok(total)
+}
+
+fn parse(input: &str) -> (Vec<u64>, Vec<u64>) {
+    let mut list_one = Vec::new();
+    let mut list_two = Vec::new();
+    for line in input.lines() {
+        let nums: Vec<u64> = line
+            .split_ascii_whitespace()
+            .take(2)
+            .flat_map(|n| n.parse::<u64>())
+            .collect();
+        list_one.push(nums[0]);
+        list_two.push(nums[1]);
+    }
+    (list_one, list_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 11);
+        assert_eq!(part2(EXAMPLE).unwrap(), 31);
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let grid = parse(input)?;
+    Ok(trailheads(&grid)
+        .map(|nines| nines.iter().collect::<HashSet<_>>().len())
+        .sum())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let grid = parse(input)?;
+    Ok(trailheads(&grid).map(|nines| nines.len()).sum())
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<u8>>> {
+    input
+        .lines()
+        .map(|l| {
+            l.chars()
+                .map(|c| c.to_digit(10).map(|h| h as u8).context("Invalid height"))
+                .collect()
+        })
+        .collect()
+}
+
+/// For each trailhead, the 9 reached at the end of every hiking trail starting there.
+fn trailheads(grid: &[Vec<u8>]) -> impl Iterator<Item = Vec<(usize, usize)>> {
+    grid.iter().enumerate().flat_map(move |(i, row)| {
+        row.iter()
+            .enumerate()
+            .filter(|&(_, &height)| height == 0)
+            .map(move |(j, _)| {
+                let mut nines = Vec::new();
+                trails(grid, &mut nines, (i, j));
+                nines
+            })
+    })
+}
+
+fn trails(grid: &[Vec<u8>], nines: &mut Vec<(usize, usize)>, current_pos: (usize, usize)) {
+    let current_val = grid[current_pos.0][current_pos.1];
+    if current_val == 9 {
+        nines.push(current_pos);
+        return;
+    }
+
+    for &[x_offset, y_offset] in ORIENTATIONS {
+        let new_pos = (
+            current_pos.0 as isize + x_offset,
+            current_pos.1 as isize + y_offset,
+        );
+
+        if new_pos.0 >= 0
+            && new_pos.0 < grid.len() as isize
+            && new_pos.1 >= 0
+            && new_pos.1 < grid[0].len() as isize
+        {
+            let new_pos = (new_pos.0 as usize, new_pos.1 as usize);
+            if grid[new_pos.0][new_pos.1] == (current_val + 1) {
+                trails(grid, nines, new_pos);
+            }
+        }
+    }
+}
+
+const ORIENTATIONS: [&[isize; 2]; 4] = [&[-1, 0], &[0, 1], &[1, 0], &[0, -1]];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 36);
+        assert_eq!(part2(EXAMPLE).unwrap(), 81);
+    }
+}
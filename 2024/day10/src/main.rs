@@ -0,0 +1,5 @@
+use anyhow::Result;
+
+fn main() -> Result<()> {
+    aoc_common::run(aoc2024_day10::part1, aoc2024_day10::part2)
+}
@@ -1,22 +1,20 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::io::stdin;
-
-fn main() -> Result<()> {
-    let mut stones = String::new();
-    stdin().read_line(&mut stones)?;
-    let stones: Vec<u64> = stones
-        .trim()
-        .split_ascii_whitespace()
-        .map(|s| s.parse())
-        .flatten()
-        .collect();
 
-    let mut memo: HashMap<u64, HashMap<u64, u64>> = HashMap::new();
-    let total: u64 = stones.iter().map(|s| blink(75, *s, &mut memo)).sum();
-    println!("{}", total);
+pub fn part1(input: &str) -> Result<u64> {
+    count_stones(input, 25)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    count_stones(input, 75)
+}
+
+fn count_stones(input: &str, blinks: u64) -> Result<u64> {
+    let stones: Vec<u64> = aoc_common::numbers(input)?;
 
-    Ok(())
+    let mut memo: HashMap<u64, HashMap<u64, u64>> = HashMap::new();
+    let total: u64 = stones.iter().map(|s| blink(blinks, *s, &mut memo)).sum();
+    Ok(total)
 }
 
 // DFS + memoization
@@ -30,17 +28,26 @@ fn blink(times: u64, stone: u64, memo: &mut HashMap<u64, HashMap<u64, u64>>) ->
     let mut total = 0;
     if stone == 0 {
         total += blink(times - 1, 1, memo);
-    } else if stone.to_string().len() % 2 == 0 {
+    } else if stone.to_string().len().is_multiple_of(2) {
         let stone = stone.to_string();
         total += blink(times - 1, stone[..stone.len() / 2].parse().unwrap(), memo);
         total += blink(times - 1, stone[(stone.len() / 2)..].parse().unwrap(), memo);
     } else {
         total += blink(times - 1, stone * 2024, memo);
     }
-    memo.entry(stone)
-        .and_modify(|m| {
-            m.insert(times, total);
-        })
-        .or_insert(HashMap::from([(times, total)]));
+    memo.entry(stone).or_default().insert(times, total);
     total
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "125 17";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 55312);
+        assert_eq!(part2(EXAMPLE).unwrap(), 65601038650482);
+    }
+}
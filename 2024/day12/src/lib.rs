@@ -1,12 +1,16 @@
+use anyhow::Result;
 use std::collections::HashSet;
-use std::io::stdin;
 
-fn main() {
-    let mut grid: Vec<Vec<char>> = stdin()
-        .lines()
-        .flatten()
-        .map(|l| l.chars().collect())
-        .collect();
+pub fn part1(input: &str) -> Result<u64> {
+    Ok(regions(input).iter().map(Region::price).sum())
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    Ok(regions(input).iter().map(Region::bulk_price).sum())
+}
+
+fn regions(input: &str) -> Vec<Region> {
+    let grid = aoc_common::grid(input);
 
     let mut visited: HashSet<(usize, usize)> = HashSet::new();
     let mut regions = Vec::new();
@@ -14,12 +18,11 @@ fn main() {
         for j in 0..grid[i].len() {
             let start = (i, j);
             if !visited.contains(&start) {
-                regions.push(define_region(start, &mut grid, &mut visited));
+                regions.push(define_region(start, &grid, &mut visited));
             }
         }
     }
-    let total: u64 = regions.iter().map(|r| r.price()).sum();
-    println!("{}", total);
+    regions
 }
 
 #[derive(Debug)]
@@ -49,6 +52,11 @@ impl Region {
         self.plants.push(plant);
     }
 
+    fn price(&self) -> u64 {
+        let perimeter: u64 = self.plants.iter().map(|p| p.perimeter as u64).sum();
+        perimeter * (self.plants.len() as u64)
+    }
+
     fn get_plant(&self, loc: (isize, isize)) -> Option<&Plant> {
         if loc.0 < 0 || loc.1 < 0 {
             return None;
@@ -58,7 +66,7 @@ impl Region {
     }
 
     // A very gross and long inside & outside corner check noting that # corners == # sides
-    fn price(&self) -> u64 {
+    fn bulk_price(&self) -> u64 {
         let mut num_sides = 0;
         for (x, y) in self.plants.iter().map(|plant| plant.loc) {
             let (x, y) = (x as isize, y as isize);
@@ -140,7 +148,7 @@ impl Region {
 
 fn define_region(
     start: (usize, usize),
-    grid: &mut Vec<Vec<char>>,
+    grid: &[Vec<char>],
     visited: &mut HashSet<(usize, usize)>,
 ) -> Region {
     let mut region = Region::default();
@@ -179,3 +187,29 @@ fn define_region(
 }
 
 const ORIENTATIONS: [&[isize; 2]; 4] = [&[0, 1], &[1, 0], &[0, -1], &[-1, 0]];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1("AAAA\nBBCD\nBBCC\nEEEC").unwrap(), 140);
+        assert_eq!(part1(EXAMPLE).unwrap(), 1930);
+        assert_eq!(part2("AAAA\nBBCD\nBBCC\nEEEC").unwrap(), 80);
+        assert_eq!(part2(EXAMPLE).unwrap(), 1206);
+    }
+}
@@ -7,7 +7,7 @@ use mathru::{
     matrix, vector,
 };
 use regex::Regex;
-use std::io::{Lines, StdinLock};
+use std::str::Lines;
 
 // This can be cast as a matrix / linear algebra problem.
 // Given:
@@ -24,16 +24,25 @@ use std::io::{Lines, StdinLock};
 //      B = [[X], [Y]]
 // Solve for X in: AX = B
 // See https://rustmath.gitlab.io/mathru/documentation/algebra/linear/matrix/#linear-system-resolution
-fn main() -> Result<()> {
-    let mut lines = std::io::stdin().lines();
+pub fn part1(input: &str) -> Result<u64> {
+    tokens(input, 0.0)
+}
+
+/// Part 2's prizes are 10000000000000 further away on both axes.
+pub fn part2(input: &str) -> Result<u64> {
+    tokens(input, 10000000000000.0)
+}
+
+fn tokens(input: &str, prize_offset: f64) -> Result<u64> {
+    let mut lines = input.lines();
     let parser = Parser::new()?;
     let mut total = 0;
     loop {
         let (ax, ay) = get_coords(&mut lines, &parser)?;
         let (bx, by) = get_coords(&mut lines, &parser)?;
         let (px, py) = get_coords(&mut lines, &parser)?;
-        let px = px + 10000000000000.0;
-        let py = py + 10000000000000.0;
+        let px = px + prize_offset;
+        let py = py + prize_offset;
 
         let a = matrix![ax, bx; ay, by];
         let b: Vector<f64> = vector![px; py];
@@ -48,18 +57,16 @@ fn main() -> Result<()> {
             total += (a_presses * 3.0 + b_presses) as u64;
         }
 
-        if let None = lines.next() {
+        if lines.next().is_none() {
             break;
         }
     }
-    println!("{}", total);
-
-    Ok(())
+    Ok(total)
 }
 
-fn get_coords(lines: &mut Lines<StdinLock>, parser: &Parser) -> Result<(f64, f64)> {
+fn get_coords(lines: &mut Lines, parser: &Parser) -> Result<(f64, f64)> {
     if let Some(res) = lines.next() {
-        parser.parse(res?)
+        parser.parse(res)
     } else {
         Err(Error::msg("Line expected"))
     }
@@ -85,3 +92,32 @@ impl Parser {
         Ok((x.parse()?, y.parse()?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 480);
+        assert_eq!(part2(EXAMPLE).unwrap(), 875318608908);
+    }
+}
@@ -1,35 +1,66 @@
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::cmp::max;
-use std::{env, io};
 
-fn main() -> Result<()> {
-    // Size is given in width(y) by height(X) but it makes more sense in my head flipped.
-    let args: Vec<String> = env::args().collect();
-    let grid_size: (usize, usize) = if args.len() >= 2 {
-        (args[2].parse()?, args[1].parse()?)
-    } else {
-        (103, 101)
-    };
+/// Size is given in width(y) by height(X) but it makes more sense in my head flipped.
+pub const GRID_SIZE: (usize, usize) = (103, 101);
 
-    let parser = Parser::new()?;
-    let mut robots = Vec::new();
-    for line in io::stdin().lines() {
-        robots.push(parser.parse(&line?)?);
+pub fn part1(input: &str) -> Result<u64> {
+    part1_with(input, GRID_SIZE)
+}
+
+pub fn part1_with(input: &str, grid_size: (usize, usize)) -> Result<u64> {
+    let mut robots = parse(input)?;
+
+    for _ in 0..100 {
+        robots.iter_mut().for_each(|r| r.advance(grid_size));
+    }
+
+    let bisects = (grid_size.0 / 2, grid_size.1 / 2);
+    let mut quads = [0, 0, 0, 0];
+    for r in robots.iter() {
+        if r.coords.0 < bisects.0 {
+            if r.coords.1 < bisects.1 {
+                quads[0] += 1;
+            } else if r.coords.1 > bisects.1 {
+                quads[1] += 1;
+            }
+        } else if r.coords.0 > bisects.0 {
+            if r.coords.1 < bisects.1 {
+                quads[2] += 1;
+            } else if r.coords.1 > bisects.1 {
+                quads[3] += 1;
+            }
+        }
     }
+    let safety_factor: u64 = quads.iter().product();
+    Ok(safety_factor)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, GRID_SIZE)
+}
+
+pub fn part2_with(input: &str, grid_size: (usize, usize)) -> Result<usize> {
+    let mut robots = parse(input)?;
 
     let mut i = 0;
     loop {
         i += 1;
         robots.iter_mut().for_each(|r| r.advance(grid_size));
         if is_tree(&robots) {
-            println!("{i} seconds:");
-            print_robots(grid_size, &robots);
-            break;
+            return Ok(i);
         }
     }
+}
 
-    Ok(())
+fn parse(input: &str) -> Result<Vec<Robot>> {
+    let parser = Parser::new()?;
+    let mut robots = Vec::new();
+    for line in input.lines() {
+        robots.push(parser.parse(line)?);
+    }
+    Ok(robots)
 }
 
 // Instead of looking for an exact tree shape, look for tree height/trunk.
@@ -71,40 +102,6 @@ fn largest_contiguous_seq(x_coords: &[usize]) -> usize {
     largest
 }
 
-#[test]
-fn test_largest_contiguous_seq() {
-    assert_eq!(largest_contiguous_seq(&[1, 2, 3, 7]), 3);
-    assert_eq!(
-        largest_contiguous_seq(&[1, 2, 3, 7, 8, 9, 10, 11, 12, 13]),
-        7
-    );
-    assert_eq!(largest_contiguous_seq(&[1, 3, 5, 7]), 1);
-    assert_eq!(
-        largest_contiguous_seq(&[
-            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23
-        ]),
-        23
-    );
-}
-
-fn print_robots(grid_size: (usize, usize), robots: &[Robot]) {
-    for i in 0..grid_size.0 {
-        for j in 0..grid_size.1 {
-            let mut sum = 0;
-            robots
-                .iter()
-                .filter(|r| r.coords == (i, j))
-                .for_each(|_| sum += 1);
-            if sum >= 1 {
-                print!("{sum} ");
-            } else {
-                print!(". ");
-            }
-        }
-        println!();
-    }
-}
-
 struct Parser {
     regex: Regex,
 }
@@ -149,7 +146,7 @@ impl Robot {
     fn wrap_add(val: usize, vel: isize, grid_limit: usize) -> usize {
         let new_val = val as isize + vel;
         if new_val < 0 {
-            grid_limit - new_val.abs() as usize
+            grid_limit - new_val.unsigned_abs()
         } else if new_val > (grid_limit - 1) as isize {
             new_val as usize - (grid_limit - 1) - 1
         } else {
@@ -157,3 +154,44 @@ impl Robot {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, (11, 7)).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_largest_contiguous_seq() {
+        assert_eq!(largest_contiguous_seq(&[1, 2, 3, 7]), 3);
+        assert_eq!(
+            largest_contiguous_seq(&[1, 2, 3, 7, 8, 9, 10, 11, 12, 13]),
+            7
+        );
+        assert_eq!(largest_contiguous_seq(&[1, 3, 5, 7]), 1);
+        assert_eq!(
+            largest_contiguous_seq(&[
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23
+            ]),
+            23
+        );
+    }
+}
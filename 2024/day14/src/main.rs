@@ -0,0 +1,19 @@
+use anyhow::Result;
+use aoc_common::Config;
+
+fn main() -> Result<()> {
+    // Size is given in width(y) by height(X) but it makes more sense in my head flipped.
+    let config = Config::load(2024, 14)?;
+    let (default_height, default_width) = aoc2024_day14::GRID_SIZE;
+    let grid_size = (
+        config.get_or("height", default_height)?,
+        config.get_or("width", default_width)?,
+    );
+
+    let input = aoc_common::read_input()?;
+
+    aoc_common::print_answer(1, aoc2024_day14::part1_with(&input, grid_size)?);
+    aoc_common::print_answer(2, aoc2024_day14::part2_with(&input, grid_size)?);
+
+    Ok(())
+}
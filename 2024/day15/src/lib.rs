@@ -1,57 +1,47 @@
-use anyhow::{anyhow, Context, Result};
-use std::io;
+use anyhow::{Context, Result, anyhow};
 use std::ops::{Index, IndexMut};
 
-fn main() -> Result<()> {
-    let mut lines = io::stdin().lines();
+pub fn part1(input: &str) -> Result<usize> {
+    let (mut warehouse, moves) = parse(input, |c| vec![c])?;
+    play_moves(&mut warehouse, &moves)?;
+    Ok(warehouse.gps_total('O'))
+}
+
+/// Everything except the robot is twice as wide.
+pub fn part2(input: &str) -> Result<usize> {
+    let (mut warehouse, moves) = parse(input, |c| match c {
+        'O' => vec!['[', ']'],
+        '@' => vec!['@', '.'],
+        _ => vec![c, c],
+    })?;
+    play_moves(&mut warehouse, &moves)?;
+    Ok(warehouse.gps_total('['))
+}
+
+fn parse(input: &str, tile: impl Fn(char) -> Vec<char>) -> Result<(Warehouse, Vec<char>)> {
+    let mut lines = input.lines();
 
     let mut warehouse = Warehouse::default();
     loop {
-        let line = lines.next().context("Failed to read line")??;
+        let line = lines.next().context("Failed to read line")?;
         if line.trim().is_empty() {
             break;
         }
-        let mut row = Vec::new();
-        for c in line.chars() {
-            let mut to_append = if c == 'O' {
-                vec!['[', ']']
-            } else if c == '@' {
-                vec!['@', '.']
-            } else {
-                vec![c, c]
-            };
-            row.append(&mut to_append);
-        }
-        warehouse.push(row);
+        warehouse.push(line.chars().flat_map(&tile).collect());
     }
 
     let mut moves = Vec::new();
     for line in lines {
-        line?.trim().chars().for_each(|c| moves.push(c));
+        line.trim().chars().for_each(|c| moves.push(c));
     }
-
-    // print_warehouse(&warehouse.grid);
-    play_moves(&mut warehouse, &moves)?;
-    // print_warehouse(&warehouse.grid);
-
-    let total: usize = warehouse
-        .grid
-        .iter()
-        .enumerate()
-        .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, &c)| (i, j, c)))
-        .filter(|(_i, _j, c)| *c == '[')
-        .map(|(i, j, _c)| 100 * i + j)
-        .sum();
-    println!("{}", total);
-
-    Ok(())
+    Ok((warehouse, moves))
 }
 
 #[allow(dead_code)]
-fn print_warehouse(warehouse: &Vec<Vec<char>>) {
-    for i in 0..warehouse.len() {
-        for j in 0..warehouse[i].len() {
-            print!("{}", warehouse[i][j]);
+fn print_warehouse(warehouse: &[Vec<char>]) {
+    for row in warehouse.iter() {
+        for c in row.iter() {
+            print!("{}", c);
         }
         println!();
     }
@@ -85,8 +75,8 @@ fn play_moves(warehouse: &mut Warehouse, moves: &[char]) -> Result<()> {
         let next = warehouse[next_coords];
         match next {
             '#' => continue,
-            '[' | ']' => {
-                let boxes = get_boxes(&warehouse, next_coords, orient);
+            'O' | '[' | ']' => {
+                let boxes = get_boxes(warehouse, next_coords, orient);
                 if !boxes.is_empty() {
                     for &b in boxes.iter().rev() {
                         let next = (
@@ -114,7 +104,7 @@ fn play_moves(warehouse: &mut Warehouse, moves: &[char]) -> Result<()> {
 }
 
 // Search for all boxes via BFS in the given orientation from the starting location.
-// Vertical orientations must expand the search to include the full width of the box.
+// Vertical orientations must expand the search to include the full width of wide boxes.
 fn get_boxes(
     warehouse: &Warehouse,
     start: (usize, usize),
@@ -127,10 +117,10 @@ fn get_boxes(
         let next = queue.remove(0);
         let next_space = warehouse[next];
         match next_space {
-            '[' | ']' => {
+            'O' | '[' | ']' => {
                 boxes.push(next);
                 // If we're moving up or down, we need to account for width of the whole box pushing adjacent boxes in other columns
-                if orient.0 != 0 {
+                if orient.0 != 0 && next_space != 'O' {
                     let pair = if next_space == '[' {
                         (next.0, next.1 + 1)
                     } else {
@@ -168,6 +158,17 @@ impl Warehouse {
     fn push(&mut self, row: Vec<char>) {
         self.grid.push(row);
     }
+
+    /// Sums the GPS coordinates of every box, located by its (left) edge `box_edge`.
+    fn gps_total(&self, box_edge: char) -> usize {
+        self.grid
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, &c)| (i, j, c)))
+            .filter(|(_i, _j, c)| *c == box_edge)
+            .map(|(i, j, _c)| 100 * i + j)
+            .sum()
+    }
 }
 
 impl Index<(usize, usize)> for Warehouse {
@@ -183,3 +184,39 @@ impl IndexMut<(usize, usize)> for Warehouse {
         &mut self.grid[index.0][index.1]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const EXAMPLE_2: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE_1).unwrap(), 2028);
+        assert_eq!(part2(EXAMPLE_2).unwrap(), 618);
+    }
+}
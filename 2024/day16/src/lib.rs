@@ -1,27 +1,33 @@
 use anyhow::{Context, Result};
-use std::cmp::{min, Ordering};
+use std::cmp::{Ordering, min};
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::io::BufRead;
 use std::ops::{Add, Index};
 
-fn main() -> Result<()> {
-    let mut maze = Maze::default();
-    for line in std::io::stdin().lock().lines() {
-        maze.push(line?.trim().chars().collect());
-    }
-    let start = maze.find('S').context("Start not found")?;
-    let _end = maze.find('E').context("End not found")?;
+pub fn part1(input: &str) -> Result<u64> {
+    let (score, _paths) = best_paths(input)?;
+    Ok(score)
+}
 
-    let (score, paths) = maze.shortest_path_bfs(start).context("Path not found")?;
+pub fn part2(input: &str) -> Result<usize> {
+    let (_score, paths) = best_paths(input)?;
     let mut tiles = HashSet::new();
     for path in paths.into_iter() {
         for point in path.into_iter() {
             tiles.insert(point);
         }
     }
-    println!("{score}: {}", tiles.len());
+    Ok(tiles.len())
+}
+
+fn best_paths(input: &str) -> Result<(u64, HashSet<Vec<Point>>)> {
+    let mut maze = Maze::default();
+    for line in input.lines() {
+        maze.push(line.trim().chars().collect());
+    }
+    let start = maze.find('S').context("Start not found")?;
+    let _end = maze.find('E').context("End not found")?;
 
-    Ok(())
+    maze.shortest_path_bfs(start).context("Path not found")
 }
 
 #[derive(Default)]
@@ -104,7 +110,7 @@ struct Path {
 
 impl Path {
     fn coords(&self) -> Point {
-        self.points.last().unwrap().clone()
+        *self.points.last().unwrap()
     }
 }
 
@@ -118,7 +124,7 @@ impl PartialEq<Self> for Path {
 
 impl PartialOrd<Self> for Path {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(other.cmp(&self))
+        Some(self.cmp(other))
     }
 }
 
@@ -198,3 +204,32 @@ impl Orient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 7036);
+        assert_eq!(part2(EXAMPLE).unwrap(), 45);
+    }
+}
@@ -1,6 +1,5 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{Context, Result, anyhow, bail};
 use regex::Regex;
-use std::io::BufRead;
 // 3-bit computer:
 // * 3 registers - Not limited to 3 bits. Each can hold any integer: A, B, C.
 // * 8 instructions each with operand: [3-bits] [3-bits] -> opcode operand
@@ -35,13 +34,108 @@ use std::io::BufRead;
 //      * The numerator is still read from the A register.
 //    * 7 -> cdv: works exactly like the adv instruction except that the result is stored in the C register.
 //      * The numerator is still read from the A register.
-fn main() -> Result<()> {
-    let mut lines = std::io::stdin().lock().lines();
+pub fn part1(input: &str) -> Result<String> {
+    let (registers, program) = parse(input)?;
+    let outputs: Vec<String> = run(registers, &program)?
+        .iter()
+        .map(u64::to_string)
+        .collect();
+    Ok(outputs.join(","))
+}
+
+// Solving this for the general case is...beyond my reach. But solving for my personalized input
+// is absolutely possible!
+//
+// Here's my program/input: 2,4,1,7,7,5,0,3,4,4,1,7,5,5,3,0
+//
+// If we Hand-disassemble it, we get:
+// -------------------------------------------------------------------------------------------------
+// do {
+//   b = a % 8                 // 2,4 -> 000 - 111 -> This tells us what the lowest 3 bits of COULD BE.
+//                             // Solve what they could be for this iteration, add them to a (a <<= 3), then try to solve for the next iteration.
+//   b ^= 7                    // 1,7 -> bbb ^ 111
+//   c = a / (2^b)             // 7,5 -> aaa....aaa >> (2^b)
+//   a /= 8                    // 0,3 (a /= 8 = a >> 3) -> aaa...aaa >> 3
+//   b ^= c                    // 4,4 ->
+//   b ^= 7                    // 1,7
+//   out(b % 8)                // 5,5
+// } while (while a % 8 != 0); // 3,0
+// -------------------------------------------------------------------------------------------------
+//
+// * Every iteration of the loop is:
+//   * Taking AND CONSUMING the lowest 3 bits of A (B = A % 8, ..., A /= 8)
+//   * Then doing a gnarly computation based on those 3 bits
+//   * And outputting an expression based on those 3 bits (B ^= 7, C = A / (2^B), ... out(B % 8))
+//
+// To solve:
+// * Start with A = 0: the last run of the loop is a 3,0 check/jnz instruction
+// * Work backwards through each program/output value
+// * For that value, figure out a value of B = A % 8 (0-7) that works for the output value
+// * Shift A left by 3 bits, tack on B -> that's the new A (or previous rather :)).
+// * Solve for the next (originally previous) output value.
+// * Backtrack if we eventually find no value of B works.
+//
+// Rather than hard-coding that disassembly, each candidate A is run through the computer and its
+// output compared against the end of the program, so this works for any program of the same shape.
+pub fn part2(input: &str) -> Result<u64> {
+    let (registers, program) = parse(input)?;
+    next(0, &registers, &program)?.context("No value of A outputs the program")
+}
+
+// A is BIG. We MUST use a 64 bit value to track it.
+fn next(a: u64, registers: &Registers, program: &[u8]) -> Result<Option<u64>> {
+    let a = a << 3;
+    for a_lower_3 in 0..=7 {
+        let a_prev = a | a_lower_3;
+        // The program halts once A is 0, so it can't have been 0 before an iteration
+        if a_prev == 0 {
+            continue;
+        }
+        let outputs = run(
+            Registers {
+                reg_a: a_prev,
+                ..*registers
+            },
+            program,
+        )?;
+        if !program.ends_with_outputs(&outputs) {
+            continue;
+        }
+        if outputs.len() == program.len() {
+            return Ok(Some(a_prev));
+        }
+        if let Some(a) = next(a_prev, registers, program)? {
+            return Ok(Some(a));
+        }
+    }
+
+    Ok(None)
+}
+
+trait EndsWithOutputs {
+    fn ends_with_outputs(&self, outputs: &[u64]) -> bool;
+}
+
+impl EndsWithOutputs for [u8] {
+    fn ends_with_outputs(&self, outputs: &[u64]) -> bool {
+        outputs.len() <= self.len()
+            && self[self.len() - outputs.len()..]
+                .iter()
+                .zip(outputs)
+                .all(|(&p, &o)| p as u64 == o)
+    }
+}
+
+fn parse(input: &str) -> Result<(Registers, Vec<u8>)> {
+    let [register_section, program_section] = aoc_common::sections(input)[..] else {
+        bail!("Expected registers and a program");
+    };
+    let mut lines = register_section.lines();
 
     let reg_regex = Regex::new(r"Register ([ABC]): ([0-9]+)")?;
     let mut registers = Registers::default();
     for _ in 0..3 {
-        let line = lines.next().context("Failed to read line")??;
+        let line = lines.next().context("Failed to read line")?;
         let (_full, [reg, val]) = reg_regex
             .captures(line.as_ref())
             .context("Register regex doesn't match")?
@@ -53,13 +147,10 @@ fn main() -> Result<()> {
             _ => return Err(anyhow!("Unknown register {}", reg)),
         }
     }
-    dbg!(&registers);
-
-    let _blank = lines.next().context("Failed to read line")??;
 
     let prog_regex = Regex::new(r"Program: (.*)$")?;
     let mut program: Vec<u8> = vec![];
-    let prog_line = lines.next().context("Failed to read program")??;
+    let prog_line = program_section.trim();
     let (_full, [prog]) = prog_regex
         .captures(prog_line.as_ref())
         .context("Program regex doesn't match")?
@@ -67,10 +158,13 @@ fn main() -> Result<()> {
     for token in prog.split(",") {
         program.push(token.parse()?);
     }
-    dbg!(&program);
     // Make immutable after parsing for safety
     let program = program;
 
+    Ok((registers, program))
+}
+
+fn run(mut registers: Registers, program: &[u8]) -> Result<Vec<u64>> {
     let mut outputs = Vec::new();
     loop {
         if registers.ip >= program.len() {
@@ -79,23 +173,22 @@ fn main() -> Result<()> {
 
         let instruction = Instruction::new(program[registers.ip], program[registers.ip + 1])?;
         if let Some(output) = instruction.exec(&mut registers) {
-            outputs.push(output.to_string());
+            outputs.push(output);
         }
     }
-    println!("{}", outputs.join(","));
-
-    Ok(())
+    Ok(outputs)
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Copy, Debug, Default)]
 struct Registers {
-    reg_a: u32,
-    reg_b: u32,
-    reg_c: u32,
+    reg_a: u64,
+    reg_b: u64,
+    reg_c: u64,
     ip: usize,
 }
 
 #[derive(Debug)]
+#[allow(clippy::upper_case_acronyms)]
 enum Instruction {
     ADV(u8),
     BXL(u8),
@@ -126,21 +219,19 @@ impl Instruction {
         }
     }
 
-    fn exec(&self, registers: &mut Registers) -> Option<u32> {
+    fn exec(&self, registers: &mut Registers) -> Option<u64> {
         let mut output = None;
 
         match self {
             Instruction::ADV(operand) => {
-                let num = registers.reg_a;
-                let denom = 2u32.pow(operand.combo(&registers));
-                registers.reg_a = num / denom;
+                registers.reg_a = operand.divide(registers);
             }
             Instruction::BXL(operand) => {
-                let literal = *operand as u32;
+                let literal = *operand as u64;
                 registers.reg_b ^= literal;
             }
             Instruction::BST(operand) => {
-                registers.reg_b = operand.combo(&registers) % 8;
+                registers.reg_b = operand.combo(registers) % 8;
             }
             Instruction::JNZ(operand) => {
                 if registers.reg_a != 0 {
@@ -152,17 +243,13 @@ impl Instruction {
                 registers.reg_b ^= registers.reg_c;
             }
             Instruction::OUT(operand) => {
-                output = Some(operand.combo(&registers) % 8);
+                output = Some(operand.combo(registers) % 8);
             }
             Instruction::BDV(operand) => {
-                let num = registers.reg_a;
-                let denom = 2u32.pow(operand.combo(&registers));
-                registers.reg_b = num / denom;
+                registers.reg_b = operand.divide(registers);
             }
             Instruction::CDV(operand) => {
-                let num = registers.reg_a;
-                let denom = 2u32.pow(operand.combo(&registers));
-                registers.reg_c = num / denom;
+                registers.reg_c = operand.divide(registers);
             }
         }
 
@@ -173,13 +260,21 @@ impl Instruction {
 }
 
 trait Combo {
-    fn combo(&self, registers: &Registers) -> u32;
+    fn combo(&self, registers: &Registers) -> u64;
+
+    /// A divided by 2^combo, i.e. shifted right by combo, leaving nothing once every bit's gone.
+    fn divide(&self, registers: &Registers) -> u64 {
+        u32::try_from(self.combo(registers))
+            .ok()
+            .and_then(|shift| registers.reg_a.checked_shr(shift))
+            .unwrap_or(0)
+    }
 }
 
 impl Combo for u8 {
-    fn combo(&self, registers: &Registers) -> u32 {
+    fn combo(&self, registers: &Registers) -> u64 {
         match self {
-            0..=3 => *self as u32,
+            0..=3 => *self as u64,
             4 => registers.reg_a,
             5 => registers.reg_b,
             6 => registers.reg_c,
@@ -188,3 +283,30 @@ impl Combo for u8 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const QUINE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(part2(QUINE).unwrap(), 117440);
+    }
+}
@@ -1,34 +1,43 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{Context, Result, anyhow, bail};
 use std::cmp::min;
 use std::collections::{HashMap, VecDeque};
-use std::env;
 use std::fmt::{Display, Formatter};
-use std::io::{stdin, BufRead};
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
+pub const MAX_DIM: usize = 70;
+pub const BYTES_TO_TAKE: usize = 1024;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, MAX_DIM, BYTES_TO_TAKE)
+}
+
+pub fn part1_with(input: &str, max_dim: usize, bytes_to_take: usize) -> Result<usize> {
+    let bytes = parse(input)?;
+    if bytes.len() < bytes_to_take {
+        bail!("Expected at least {} bytes", bytes_to_take);
+    }
 
-    let max_dim: usize = args.get(1).context("No square dim specified")?.parse()?;
     // Note the "+ 1". If the range is [0,70], we need a Grid of size 71
     let mut grid = Grid::new(max_dim + 1);
+    grid.corrupt_until(&bytes[..bytes_to_take]);
 
     let start = Point::new(0, 0);
     let end = Point::new(max_dim, max_dim);
+    let min_steps = grid
+        .bfs(start, end)
+        .context(format!("No path from {:?} to {:?} in grid", start, end))?;
+    Ok(min_steps - 1)
+}
 
-    let mut bytes = Vec::new();
-    for line in stdin().lock().lines() {
-        let line = line?;
-        let mut coords = Vec::new();
-        for token in line.split(",") {
-            coords.push(token.parse()?);
-            if coords.len() > 2 {
-                return Err(anyhow!("More than two coordinates supplied: {}", &line));
-            }
-        }
-        // Coordinates given in X,Y: X is distance from left edge; Y distance from top edge
-        let point = Point::new(coords[1], coords[0]);
-        bytes.push(point);
-    }
+pub fn part2(input: &str) -> Result<String> {
+    part2_with(input, MAX_DIM)
+}
+
+pub fn part2_with(input: &str, max_dim: usize) -> Result<String> {
+    let bytes = parse(input)?;
+    // Note the "+ 1". If the range is [0,70], we need a Grid of size 71
+    let mut grid = Grid::new(max_dim + 1);
+    let start = Point::new(0, 0);
+    let end = Point::new(max_dim, max_dim);
 
     // Binary search for the path-breaking byte
     let mut low = 0;
@@ -39,11 +48,10 @@ fn main() -> Result<()> {
         grid.clear();
         grid.corrupt_until(&bytes[0..mid]);
 
-        if grid.bfs(start, end) {
+        if grid.bfs(start, end).is_some() {
             grid.corrupt(&bytes[mid]);
-            if !grid.bfs(start, end) {
-                println!("{}", &bytes[mid]);
-                return Ok(());
+            if grid.bfs(start, end).is_none() {
+                return Ok(bytes[mid].to_string());
             } else {
                 low = mid + 1;
             }
@@ -55,6 +63,23 @@ fn main() -> Result<()> {
     Err(anyhow!("No bytes broke paths to the exit"))
 }
 
+fn parse(input: &str) -> Result<Vec<Point>> {
+    let mut bytes = Vec::new();
+    for line in input.lines() {
+        let mut coords = Vec::new();
+        for token in line.split(",") {
+            coords.push(token.parse()?);
+            if coords.len() > 2 {
+                return Err(anyhow!("More than two coordinates supplied: {}", &line));
+            }
+        }
+        // Coordinates given in X,Y: X is distance from left edge; Y distance from top edge
+        let point = Point::new(coords[1], coords[0]);
+        bytes.push(point);
+    }
+    Ok(bytes)
+}
+
 #[derive(Debug)]
 struct Grid {
     cells: Vec<Vec<bool>>,
@@ -86,9 +111,10 @@ impl Grid {
         self.cells[point.x][point.y]
     }
 
-    fn bfs(&self, start: Point, end: Point) -> bool {
+    /// The number of points on the shortest path from start to end, if there is one.
+    fn bfs(&self, start: Point, end: Point) -> Option<usize> {
         if self.is_corrupt(&start) || self.is_corrupt(&end) {
-            return false;
+            return None;
         }
 
         let mut queue = VecDeque::new();
@@ -98,8 +124,9 @@ impl Grid {
         while let Some(path) = queue.pop_front() {
             let next = path.last();
 
+            // Paths come off the queue shortest first
             if next == end {
-                return true;
+                return Some(path.length());
             }
 
             for neighbor in self.neighbors(&next) {
@@ -119,7 +146,7 @@ impl Grid {
                 .or_insert(path.length());
         }
 
-        false
+        None
     }
 
     // Return all non-corrupt neighbors that are in bounds.
@@ -176,10 +203,49 @@ impl Path {
     }
 
     fn last(&self) -> Point {
-        self.points.last().unwrap().clone()
+        *self.points.last().unwrap()
     }
 
     fn contains(&self, point: &Point) -> bool {
-        self.points.contains(&point)
+        self.points.contains(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, 6, 12).unwrap(), 22);
+        assert_eq!(part2_with(EXAMPLE, 6).unwrap(), "6,1");
     }
 }
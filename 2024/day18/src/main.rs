@@ -0,0 +1,18 @@
+use anyhow::Result;
+use aoc_common::Config;
+
+fn main() -> Result<()> {
+    let config = Config::load(2024, 18)?;
+    let max_dim = config.get_or("max_dim", aoc2024_day18::MAX_DIM)?;
+    let bytes_to_take = config.get_or("bytes_to_take", aoc2024_day18::BYTES_TO_TAKE)?;
+
+    let input = aoc_common::read_input()?;
+
+    aoc_common::print_answer(
+        1,
+        aoc2024_day18::part1_with(&input, max_dim, bytes_to_take)?,
+    );
+    aoc_common::print_answer(2, aoc2024_day18::part2_with(&input, max_dim)?);
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (towels, designs) = parse(input)?;
+    let mut memo: HashMap<Vec<char>, usize> = HashMap::new();
+    let possible = designs
+        .iter()
+        .filter(|d| possible_arrange(d, &towels, &mut memo) > 0)
+        .count();
+    Ok(possible)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (towels, designs) = parse(input)?;
+    // Save time by storing computed possibilities for given arrangements
+    let mut memo: HashMap<Vec<char>, usize> = HashMap::new();
+    let possible: usize = designs
+        .iter()
+        .map(|d| possible_arrange(d, &towels, &mut memo))
+        .sum();
+    Ok(possible)
+}
+
+type Towels = Vec<Vec<char>>;
+
+fn parse(input: &str) -> Result<(Towels, Vec<Vec<char>>)> {
+    let mut lines = input.lines();
+
+    let towel_line = lines.next().context("No available towels given")?;
+    let towels: Towels = towel_line
+        .split(", ")
+        .map(|t| t.chars().collect())
+        .collect();
+
+    let _blank = lines.next().context("Expected blank line")?;
+
+    let mut designs: Vec<Vec<char>> = Vec::new();
+    for line in lines {
+        designs.push(line.chars().collect());
+    }
+    Ok((towels, designs))
+}
+
+fn possible_arrange(
+    design: &[char],
+    avail_towels: &Towels,
+    memo: &mut HashMap<Vec<char>, usize>,
+) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+
+    if let Some(cached) = memo.get(design) {
+        return *cached;
+    }
+
+    let mut possible = 0;
+    for to_try in avail_towels.iter() {
+        let towel_len = to_try.len();
+        let des_len = design.len();
+        if des_len >= towel_len && design[0..towel_len].eq(&to_try[..]) {
+            possible += possible_arrange(&design[towel_len..], avail_towels, memo);
+        }
+    }
+    memo.insert(design.to_vec(), possible);
+
+    possible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 6);
+        assert_eq!(part2(EXAMPLE).unwrap(), 16);
+    }
+}
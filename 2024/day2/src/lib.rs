@@ -1,10 +1,17 @@
 use anyhow::Result;
-use std::io;
 
-fn main() -> Result<()> {
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(input
+        .lines()
+        .map(parse_line)
+        .filter(|nums| is_safe(nums))
+        .count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
     let mut safe_count = 0;
-    for line in io::stdin().lines() {
-        let nums = parse_line(line?);
+    for line in input.lines() {
+        let nums = parse_line(line);
         for skip in 0..nums.len() {
             let nums = skipped(&nums, skip);
             if is_safe(&nums) {
@@ -13,13 +20,12 @@ fn main() -> Result<()> {
             }
         }
     }
-    println!("{}", safe_count);
-    Ok(())
+    Ok(safe_count)
 }
 
-fn parse_line(line: String) -> Vec<u64> {
+fn parse_line(line: &str) -> Vec<u64> {
     line.split_ascii_whitespace()
-        .map(|n| n.parse().unwrap())
+        .flat_map(|n| n.parse::<u64>())
         .collect()
 }
 
@@ -34,7 +40,9 @@ fn skipped(nums: &[u64], skip: usize) -> Vec<u64> {
 
 fn is_safe(nums: &[u64]) -> bool {
     let mut iter = nums.windows(2);
-    let first_two = iter.next().unwrap();
+    let Some(first_two) = iter.next() else {
+        return false;
+    };
     let mut safe = false;
     if in_tolerance(first_two) {
         let inc = increasing(first_two);
@@ -46,14 +54,34 @@ fn is_safe(nums: &[u64]) -> bool {
             }
         }
     }
-    return safe;
+    safe
 }
 
 fn in_tolerance(nums: &[u64]) -> bool {
     let diff = nums[0].abs_diff(nums[1]);
-    diff >= 1 && diff <= 3
+    (1..=3).contains(&diff)
 }
 
 fn increasing(nums: &[u64]) -> bool {
     nums[0] < nums[1]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 2);
+        assert_eq!(part2(EXAMPLE).unwrap(), 4);
+    }
+}
@@ -1,35 +1,46 @@
-use anyhow::{anyhow, Context, Result};
-use std::env;
-use std::io::BufRead;
+use anyhow::{Context, Result, anyhow};
 use std::ops::Index;
 
-fn main() -> Result<()> {
+pub const TO_SAVE: usize = 100;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, TO_SAVE)
+}
+
+pub fn part1_with(input: &str, to_save: usize) -> Result<usize> {
+    count_cheats(input, 2, to_save)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, TO_SAVE)
+}
+
+pub fn part2_with(input: &str, to_save: usize) -> Result<usize> {
+    count_cheats(input, 20, to_save)
+}
+
+/// Counts the cheats lasting up to `cheat_length` picoseconds that save at least `to_save`.
+fn count_cheats(input: &str, cheat_length: usize, to_save: usize) -> Result<usize> {
     let mut track: Vec<Vec<char>> = Vec::new();
-    for line in std::io::stdin().lock().lines() {
-        track.push(line?.trim().chars().collect());
+    for line in input.lines() {
+        track.push(line.trim().chars().collect());
     }
     let track = Track::new(track)?;
 
     let fair_path = track.find_fair_path();
 
-    let args = env::args().collect::<Vec<String>>();
-    let to_save: usize = match args.get(1) {
-        Some(c) => c.parse()?,
-        None => 100,
-    };
-
     // Go through each node on the fair path and consider the cheats for that node by scanning
     // forward in the remaining nodes in the fair path. We can "cheat" to that node if a coordinate
-    // diff says we're <= 20 picoseconds away.
+    // diff says we're <= cheat_length picoseconds away.
     //
-    // NOTE: This will have valid moves show up as cheats if we were looking to save <= 20
-    // picoseconds but good thing we're looking for >= 100 :)
+    // NOTE: This will have valid moves show up as cheats if we were looking to save <=
+    // cheat_length picoseconds but good thing we're looking for >= 100 :)
     let mut count = 0;
     for (cheat_from_idx, cheat_from) in fair_path.points.iter().enumerate() {
         for (cheat_to_idx, cheat_to) in fair_path.points.iter().enumerate().skip(cheat_from_idx + 1)
         {
             let cheat_distance = cheat_from.absolute_diff(cheat_to);
-            if cheat_distance <= 20 {
+            if cheat_distance <= cheat_length {
                 let savings = (cheat_to_idx - cheat_from_idx) - cheat_distance;
                 if savings >= to_save {
                     count += 1;
@@ -37,9 +48,7 @@ fn main() -> Result<()> {
             }
         }
     }
-    println!("{} cheats saved at least {}", count, to_save);
-
-    Ok(())
+    Ok(count)
 }
 
 struct Track {
@@ -156,3 +165,34 @@ impl Point {
         self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, 64).unwrap(), 1);
+        assert_eq!(part1_with(EXAMPLE, 20).unwrap(), 5);
+        assert_eq!(part2_with(EXAMPLE, 76).unwrap(), 3);
+        assert_eq!(part2_with(EXAMPLE, 74).unwrap(), 7);
+    }
+}
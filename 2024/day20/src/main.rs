@@ -0,0 +1,14 @@
+use anyhow::Result;
+use aoc_common::Config;
+
+fn main() -> Result<()> {
+    let config = Config::load(2024, 20)?;
+    let to_save = config.get_or("to_save", aoc2024_day20::TO_SAVE)?;
+
+    let input = aoc_common::read_input()?;
+
+    aoc_common::print_answer(1, aoc2024_day20::part1_with(&input, to_save)?);
+    aoc_common::print_answer(2, aoc2024_day20::part2_with(&input, to_save)?);
+
+    Ok(())
+}
@@ -1,19 +1,53 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::env;
-use std::io::BufRead;
 
-fn main() -> Result<()> {
+// Num keypad <-- Robot 1 (Dir keypad) <-Robot 2 (Dir keypad) <- Robot 3 (Dir keypad) <- User
+// Algorithm:
+// * Find the shortest paths for the numpad
+// * For each of these paths: find the shortest paths on the dir pad.
+// * For each of these paths: find the shortest paths on the next dir pad.
+// * etc.
+// * Compute the complexity based on the final dir pad.
+// Finding shortest paths for a sequence of input can be a greedy DFS between each pair of input keys
+//
+// NOTE: The robots can't use empty gaps on key/dir pads in their pathing. It doesn't really make
+//       sense for the user to do so either.
+//
+// +---+---+---+
+// | 7 | 8 | 9 |
+// +---+---+---+
+// | 4 | 5 | 6 |
+// +---+---+---+
+// | 1 | 2 | 3 |
+// +---+---+---+
+//     | 0 | A |
+//     +---+---+
+//
+// Directional keypad:
+//     +---+---+     XXXXX
+//     | ^ | A | --> XX^AX
+// +---+---+---+     X<v>X
+// | < | v | > |     XXXXX
+// +---+---+---+
+pub const ROBOT_DIRPAD_COUNT: usize = 25;
+
+pub fn part1(input: &str) -> Result<usize> {
+    complexities(input, 2)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, ROBOT_DIRPAD_COUNT)
+}
+
+pub fn part2_with(input: &str, robot_dirpad_count: usize) -> Result<usize> {
+    complexities(input, robot_dirpad_count)
+}
+
+fn complexities(input: &str, robot_dirpad_count: usize) -> Result<usize> {
     let mut codes = Vec::new();
-    for line in std::io::stdin().lock().lines() {
-        codes.push(line?.trim().chars().collect::<Vec<char>>());
+    for line in input.lines() {
+        codes.push(line.trim().chars().collect::<Vec<char>>());
     }
-
-    let args: Vec<String> = env::args().collect();
-    let robot_dirpad_count = args
-        .get(1)
-        .expect("Must give a dirpad count")
-        .parse::<usize>()?;
     let mut keypads = Vec::with_capacity(robot_dirpad_count + 1);
     keypads.push(Keypad::new_numeric());
     (0..robot_dirpad_count).for_each(|_| keypads.push(Keypad::new_directional()));
@@ -24,11 +58,9 @@ fn main() -> Result<()> {
 
     let sum = codes
         .iter()
-        .map(|code| Keypad::complexity(&code, &mut keypads[..], &mut cache))
+        .map(|code| Keypad::complexity(code, &mut keypads[..], &mut cache))
         .sum::<usize>();
-    println!("{sum}");
-
-    Ok(())
+    Ok(sum)
 }
 
 struct Keypad {
@@ -95,7 +127,7 @@ impl Keypad {
         keypads: &[Keypad],
         cache: &mut HashMap<(Vec<char>, usize), usize>,
     ) -> usize {
-        if keypads.len() == 0 {
+        if keypads.is_empty() {
             return sequence.len();
         }
 
@@ -203,11 +235,7 @@ impl Diagonal {
         let (fx, fy) = from;
         let (tx, ty) = to;
         if fx > tx {
-            if fy > ty {
-                Self::UpLeft
-            } else {
-                Self::UpRight
-            }
+            if fy > ty { Self::UpLeft } else { Self::UpRight }
         } else {
             if fy > ty {
                 Self::DownLeft
@@ -217,3 +245,23 @@ impl Diagonal {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 126384);
+        // The puzzle only gives an answer for two robots
+        assert_eq!(part2_with(EXAMPLE, 2).unwrap(), 126384);
+    }
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use aoc_common::Config;
+
+fn main() -> Result<()> {
+    let config = Config::load(2024, 21)?;
+    let robot_dirpad_count =
+        config.get_or("robot_dirpad_count", aoc2024_day21::ROBOT_DIRPAD_COUNT)?;
+
+    let input = aoc_common::read_input()?;
+
+    aoc_common::print_answer(1, aoc2024_day21::part1(&input)?);
+    aoc_common::print_answer(2, aoc2024_day21::part2_with(&input, robot_dirpad_count)?);
+
+    Ok(())
+}
@@ -1,17 +1,29 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::io::BufRead;
+
+pub fn part1(input: &str) -> Result<u64> {
+    let mut secrets: Vec<u64> = aoc_common::parse_lines(input)?;
+
+    for _ in 0..2000 {
+        for s in &mut secrets {
+            *s = evolve(*s);
+        }
+    }
+
+    let sum = secrets.iter().sum::<u64>();
+    Ok(sum)
+}
 
 // This is a bit slow (~6s) but it gets the job done.
 // * Process the price change sequence for each buyer and keep track of what the value is the first
 //   time a sequence is encountered.
 // * Accumulate the value in the map for all buyers for that given sequence.
 // * The largest value in the shared map is the best sell.
-fn main() -> Result<()> {
-    let mut secrets: Vec<PriceChanges> = Vec::new();
-    for line in std::io::stdin().lock().lines() {
-        secrets.push(PriceChanges::new(line?.trim().parse()?));
-    }
+pub fn part2(input: &str) -> Result<u64> {
+    let mut secrets: Vec<PriceChanges> = aoc_common::parse_lines::<u64>(input)?
+        .into_iter()
+        .map(PriceChanges::new)
+        .collect();
 
     // Map of price seq -> the highest sell
     let mut seq_map: HashMap<(i64, i64, i64, i64), u64> = HashMap::new();
@@ -25,9 +37,7 @@ fn main() -> Result<()> {
             let val = sequence[3].1;
             let sequence = (sequence[0].0, sequence[1].0, sequence[2].0, sequence[3].0);
             // Monkey sells the hiding spot the first time it sees the sequence
-            if !local_seq_map.contains_key(&sequence) {
-                local_seq_map.insert(sequence, val);
-            }
+            local_seq_map.entry(sequence).or_insert(val);
         }
         local_seq_map.into_iter().for_each(|(seq, val)| {
             seq_map.entry(seq).and_modify(|v| *v += val).or_insert(val);
@@ -35,9 +45,18 @@ fn main() -> Result<()> {
     }
 
     let max = *seq_map.values().max().unwrap();
-    println!("{max}");
+    Ok(max)
+}
+
+fn evolve(value: u64) -> u64 {
+    let mut value = value;
+    value = mix_prune(value, value * 64);
+    value = mix_prune(value, value / 32);
+    mix_prune(value, value * 2048)
+}
 
-    Ok(())
+fn mix_prune(secret: u64, mix_with: u64) -> u64 {
+    (secret ^ mix_with) % 16777216
 }
 
 struct PriceChanges {
@@ -50,20 +69,13 @@ impl PriceChanges {
     fn new(init_value: u64) -> Self {
         let mut ret = Self {
             prev: init_value,
-            value: Self::evolve(init_value),
+            value: evolve(init_value),
             window: [(0, 0); 4],
         };
         (0..4).for_each(|_| ret.advance_seq());
         ret
     }
 
-    fn evolve(value: u64) -> u64 {
-        let mut value = value;
-        value = Self::mix_prune(value, value * 64);
-        value = Self::mix_prune(value, value / 32);
-        Self::mix_prune(value, value * 2048)
-    }
-
     fn advance_seq(&mut self) {
         for i in 0..self.window.len() - 1 {
             self.window[i] = self.window[i + 1];
@@ -74,11 +86,7 @@ impl PriceChanges {
         );
 
         self.prev = self.value;
-        self.value = Self::evolve(self.value);
-    }
-
-    fn mix_prune(secret: u64, mix_with: u64) -> u64 {
-        (secret ^ mix_with) % 16777216
+        self.value = evolve(self.value);
     }
 }
 
@@ -90,3 +98,17 @@ impl Iterator for PriceChanges {
         Some(self.window)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str = "1\n10\n100\n2024";
+    const EXAMPLE_2: &str = "1\n2\n3\n2024";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE_1).unwrap(), 37327623);
+        assert_eq!(part2(EXAMPLE_2).unwrap(), 23);
+    }
+}
@@ -1,13 +1,26 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use std::collections::{HashMap, HashSet};
-use std::io::BufRead;
 
-fn main() -> Result<()> {
+pub fn part1(input: &str) -> Result<usize> {
+    let graph = parse(input)?;
+    Ok(graph.t_triangles().len())
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    let graph = parse(input)?;
+    let largest = graph.largest_strongly_connected_component();
+    let mut password: Vec<String> = largest.iter().cloned().collect();
+    password.sort();
+    Ok(password.join(","))
+}
+
+fn parse(input: &str) -> Result<Graph> {
     let mut adj_list = HashMap::new();
-    for line in std::io::stdin().lock().lines() {
-        let line = line?;
+    for line in input.lines() {
         let from_to = line.split('-').collect::<Vec<&str>>();
-        assert_eq!(from_to.len(), 2, "Invalid input line");
+        if from_to.len() != 2 {
+            bail!("Invalid input line: {line}");
+        }
         for (i, j) in [(0, 1), (1, 0)] {
             adj_list
                 .entry(from_to[i].to_string())
@@ -15,14 +28,7 @@ fn main() -> Result<()> {
                 .push(from_to[j].to_string());
         }
     }
-    let graph = Graph::new(adj_list);
-
-    let largest = graph.largest_strongly_connected_component();
-    let mut password: Vec<String> = largest.iter().map(|e| e.clone()).collect();
-    password.sort();
-    println!("{}", password.join(","));
-
-    Ok(())
+    Ok(Graph::new(adj_list))
 }
 
 struct Graph {
@@ -34,6 +40,28 @@ impl Graph {
         Self { adj_list }
     }
 
+    /// Every set of three interconnected computers where at least one name starts with a t.
+    fn t_triangles(&self) -> HashSet<String> {
+        let mut components: HashSet<String> = HashSet::new();
+
+        for (from, tos) in &self.adj_list {
+            if from.starts_with('t') {
+                for i in 0..tos.len() - 1 {
+                    for j in i + 1..tos.len() {
+                        if self.adj_list[&tos[i]].contains(&tos[j]) {
+                            let mut joined =
+                                [from.to_string(), tos[i].to_string(), tos[j].to_string()];
+                            joined.sort();
+                            components.insert(joined.join("-"));
+                        }
+                    }
+                }
+            }
+        }
+
+        components
+    }
+
     fn largest_strongly_connected_component(&self) -> HashSet<String> {
         let mut visited = HashSet::new();
         let mut largest_component = HashSet::new();
@@ -68,3 +96,49 @@ impl Graph {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 7);
+        assert_eq!(part2(EXAMPLE).unwrap(), "co,de,ka,ta");
+    }
+}
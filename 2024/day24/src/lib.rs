@@ -0,0 +1,221 @@
+use anyhow::{Context, Result, anyhow, bail};
+use std::collections::HashSet;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (known_wires, mut connections) = parse(input)?;
+    let mut known_wires = KnownWires::new(known_wires);
+
+    while !connections.is_empty() {
+        let to_solve: HashSet<_> = connections
+            .extract_if(|c| known_wires.contains(&c.in_wire1) && known_wires.contains(&c.in_wire2))
+            .collect();
+        if to_solve.is_empty() {
+            bail!("No solution found");
+        }
+        for gate in to_solve.into_iter() {
+            let in1 = known_wires.get(&gate.in_wire1).unwrap().value;
+            let in2 = known_wires.get(&gate.in_wire2).unwrap().value;
+            let output = gate.run(in1, in2);
+            known_wires.insert(output);
+        }
+    }
+
+    let mut zvals: Vec<_> = known_wires
+        .iter()
+        .filter(|w| w.label.starts_with('z'))
+        .map(|w| (w.label.clone(), w.value))
+        .collect();
+    zvals.sort_by(|a, b| b.0.cmp(&a.0));
+    let bit_string: String = zvals.iter().map(|(_l, v)| v.to_string()).collect();
+    let num = usize::from_str_radix(&bit_string, 2)?;
+    Ok(num)
+}
+
+#[allow(clippy::if_same_then_else)]
+pub fn part2(input: &str) -> Result<String> {
+    let (_, connections) = parse(input)?;
+    let highest_z = connections
+        .iter()
+        .map(|c| &c.out_wire)
+        .filter(|w| w.starts_with('z'))
+        .max()
+        .context("No z wires")?;
+
+    // Check all gates are valid gates in a ripple-carry adder. See:
+    // * https://en.wikipedia.org/wiki/Adder_(electronics)#Ripple-carry_adder
+    // * https://en.wikipedia.org/wiki/File:Halfadder.gif
+    // * https://en.wikipedia.org/wiki/File:Fulladder.gif
+    // https://www.reddit.com/r/adventofcode/comments/1hl698z/comment/m3kt1je/?utm_source=share&utm_medium=web3x&utm_name=web3xcss&utm_term=1&utm_content=share_button
+    let mut wrong = HashSet::new();
+    let params = ['x', 'y', 'z'];
+    for conn in connections.iter() {
+        if conn.out_wire.starts_with("z")
+            && conn.operator != Operator::XOR
+            && &conn.out_wire != highest_z
+        {
+            wrong.insert(conn.out_wire.clone());
+        } else if conn.operator == Operator::XOR
+            && !params.contains(&conn.out_wire.chars().next().unwrap())
+            && !params.contains(&conn.in_wire1.chars().next().unwrap())
+            && !params.contains(&conn.in_wire2.chars().next().unwrap())
+        {
+            wrong.insert(conn.out_wire.clone());
+        } else if conn.operator == Operator::AND && conn.in_wire1 != "x00" && conn.in_wire2 != "x00"
+        {
+            for sub_conn in connections.iter() {
+                if (conn.out_wire == sub_conn.in_wire1 || conn.out_wire == sub_conn.in_wire2)
+                    && sub_conn.operator != Operator::OR
+                {
+                    wrong.insert(conn.out_wire.clone());
+                }
+            }
+        } else if conn.operator == Operator::XOR {
+            for sub_conn in connections.iter() {
+                if (conn.out_wire == sub_conn.in_wire1 || conn.out_wire == sub_conn.in_wire2)
+                    && sub_conn.operator == Operator::OR
+                {
+                    wrong.insert(conn.out_wire.clone());
+                }
+            }
+        }
+    }
+
+    let mut sorted: Vec<String> = wrong.into_iter().collect();
+    sorted.sort();
+    let sorted: String = sorted.join(",");
+    Ok(sorted)
+}
+
+fn parse(input: &str) -> Result<(HashSet<Wire>, HashSet<Gate>)> {
+    let [init, gates] = aoc_common::sections(input)[..] else {
+        bail!("Expected initial wires and gates");
+    };
+
+    let mut known_wires = HashSet::new();
+    for init_line in init.lines() {
+        let (label, value) = init_line
+            .split_once(": ")
+            .with_context(|| format!("Invalid wire: {init_line}"))?;
+        known_wires.insert(Wire::new(label.to_string(), value.parse()?));
+    }
+
+    let mut connections = HashSet::new();
+    for gate_line in gates.lines() {
+        let (inputs, out_wire) = gate_line
+            .split_once(" -> ")
+            .with_context(|| format!("Invalid gate: {gate_line}"))?;
+        let inputs: Vec<_> = inputs.split_ascii_whitespace().collect();
+        connections.insert(Gate::from(&inputs, out_wire)?);
+    }
+
+    Ok((known_wires, connections))
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct Wire {
+    label: String,
+    value: u8,
+}
+
+impl Wire {
+    fn new(label: String, value: u8) -> Self {
+        Self { label, value }
+    }
+}
+
+#[derive(Debug)]
+struct KnownWires {
+    wires: HashSet<Wire>,
+}
+
+impl KnownWires {
+    fn new(known_wires: HashSet<Wire>) -> Self {
+        Self { wires: known_wires }
+    }
+
+    fn contains(&self, wire_label: &str) -> bool {
+        self.wires.iter().any(|w| w.label == wire_label)
+    }
+
+    fn get(&self, wire: &str) -> Option<&Wire> {
+        self.wires.iter().find(|w| w.label == wire)
+    }
+
+    fn insert(&mut self, wire: Wire) {
+        self.wires.insert(wire);
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &Wire> {
+        self.wires.iter()
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct Gate {
+    in_wire1: String,
+    in_wire2: String,
+    out_wire: String,
+    operator: Operator,
+}
+
+impl Gate {
+    fn from(input_parts: &[&str], out_wire: &str) -> Result<Self> {
+        if input_parts.len() != 3 {
+            return Err(anyhow!("Expected three parts"));
+        }
+        let (in_wire1, in_wire2) = (input_parts[0].to_string(), input_parts[2].to_string());
+        let operator = match input_parts[1] {
+            "AND" => Operator::AND,
+            "OR" => Operator::OR,
+            "XOR" => Operator::XOR,
+            _ => return Err(anyhow!("Unknown gate: {}", input_parts[1])),
+        };
+        let out_wire = out_wire.to_string();
+        Ok(Self {
+            in_wire1,
+            in_wire2,
+            out_wire,
+            operator,
+        })
+    }
+
+    fn run(&self, in1: u8, in2: u8) -> Wire {
+        let val = match self.operator {
+            Operator::AND => in1 & in2,
+            Operator::OR => in1 | in2,
+            Operator::XOR => in1 ^ in2,
+        };
+        Wire::new(self.out_wire.clone(), val)
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+enum Operator {
+    AND,
+    OR,
+    XOR,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 4);
+    }
+}
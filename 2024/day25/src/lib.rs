@@ -1,15 +1,17 @@
 use anyhow::Result;
-use std::io::BufRead;
 
-fn main() -> Result<()> {
+pub fn part1(input: &str) -> Result<u64> {
     let mut keys = Vec::new();
     let mut locks = Vec::new();
-    let mut lines = std::io::stdin().lock().lines();
+    // Chain on a blank line so the last schematic gets flushed too
+    let lines = input.lines().chain([""]);
 
     let mut buffer: Vec<Vec<char>> = Vec::new();
-    while let Some(line) = lines.next() {
-        let line = line?;
+    for line in lines {
         if line.trim().is_empty() {
+            if buffer.is_empty() {
+                continue;
+            }
             match Type::from_grid(&buffer) {
                 Type::Lock(lock) => locks.push(lock),
                 Type::Key(key) => keys.push(key),
@@ -23,14 +25,16 @@ fn main() -> Result<()> {
     let mut fits = 0;
     for lock in &locks {
         for key in &keys {
-            if lock.iter().zip(key.iter()).all(|(lh, kh)| lh + kh <= lock.len()) {
+            if lock
+                .iter()
+                .zip(key.iter())
+                .all(|(lh, kh)| lh + kh <= lock.len())
+            {
                 fits += 1;
             }
         }
     }
-    println!("{fits}");
-
-    Ok(())
+    Ok(fits)
 }
 
 enum Type {
@@ -39,11 +43,11 @@ enum Type {
 }
 
 impl Type {
-    fn from_grid(grid: &Vec<Vec<char>>) -> Self {
+    fn from_grid(grid: &[Vec<char>]) -> Self {
         let mut heights = vec![0; grid[0].len()];
-        for i in 0..grid.len() {
-            for j in 0..grid[i].len() {
-                if grid[i][j] == '#' {
+        for row in grid.iter() {
+            for (j, &c) in row.iter().enumerate() {
+                if c == '#' {
                     heights[j] += 1;
                 }
             }
@@ -55,4 +59,56 @@ impl Type {
             Type::Key(heights)
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 3);
+    }
+}
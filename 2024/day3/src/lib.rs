@@ -0,0 +1,60 @@
+use anyhow::{Result, bail};
+use regex::Regex;
+
+const MUL: &str = r"mul\(([0-9]{1,3}),([0-9]{1,3})\)";
+
+pub fn part1(input: &str) -> Result<u64> {
+    let pattern = Regex::new(MUL)?;
+    let mut total = 0;
+    for line in input.lines() {
+        for capture in pattern.captures_iter(line) {
+            total += multiply(&capture)?;
+        }
+    }
+    Ok(total)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    // Use multiple patterns since unbalanced capture groups aren't supported
+    let ins_pattern = Regex::new(r"((mul)\([0-9]{1,3},[0-9]{1,3}\))|((do)\(\))|((don)'t\(\))")?;
+    let mult_pattern = Regex::new(MUL)?;
+    let mut total = 0;
+    let mut mult_enabled = true;
+    for line in input.lines() {
+        for capture in ins_pattern.captures_iter(line) {
+            let (full, [_, instruction]) = capture.extract();
+            match instruction {
+                "do" => mult_enabled = true,
+                "don" => mult_enabled = false,
+                "mul" => {
+                    if mult_enabled {
+                        total += multiply(&mult_pattern.captures(full).unwrap())?;
+                    }
+                }
+                _ => bail!("Unrecognized instruction: {}", instruction),
+            }
+        }
+    }
+    Ok(total)
+}
+
+fn multiply(capture: &regex::Captures) -> Result<u64> {
+    let (_full, [n1, n2]) = capture.extract();
+    Ok(n1.parse::<u64>()? * n2.parse::<u64>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const EXAMPLE_2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE_1).unwrap(), 161);
+        assert_eq!(part2(EXAMPLE_2).unwrap(), 48);
+    }
+}
@@ -0,0 +1,124 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    let word_search = aoc_common::grid(input);
+
+    let mut total = 0;
+    for (row, line) in word_search.iter().enumerate() {
+        for (col, _letter) in line.iter().enumerate() {
+            total += search(&word_search, (row, col));
+        }
+    }
+    Ok(total)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let word_search = aoc_common::grid(input);
+
+    let mut total = 0;
+    for (row, line) in word_search.iter().enumerate() {
+        for (col, _letter) in line.iter().enumerate() {
+            if cross_check(&word_search, (row, col)) {
+                total += 1;
+            }
+        }
+    }
+    Ok(total)
+}
+
+fn search(word_search: &[Vec<char>], start: (usize, usize)) -> usize {
+    let mut found = 0;
+    if word_search[start.0][start.1] != 'X' {
+        return found;
+    }
+
+    for orient in ORIENTATIONS {
+        let mut word = String::with_capacity(4);
+        word.push('X');
+
+        let mut curr = (start.0 as isize, start.1 as isize);
+        for _ in 0..3 {
+            curr = (curr.0 + orient[0], curr.1 + orient[1]);
+            match letter_at(word_search, curr) {
+                Some(letter) => word.push(letter),
+                None => break,
+            }
+        }
+        if word == "XMAS" {
+            found += 1;
+        }
+    }
+    found
+}
+
+fn cross_check(word_search: &[Vec<char>], start: (usize, usize)) -> bool {
+    if word_search[start.0][start.1] != 'A' {
+        return false;
+    }
+
+    let mut cross_words: Vec<String> = Vec::with_capacity(2);
+
+    for cross_orient in CROSS_ORIENTATIONS.chunks(2) {
+        let mut word = String::with_capacity(3);
+        for co in cross_orient {
+            let curr = (start.0 as isize + co[0], start.1 as isize + co[1]);
+            match letter_at(word_search, curr) {
+                Some(letter) => word.push(letter),
+                None => break,
+            }
+        }
+
+        if word.len() == 2 {
+            word.insert(1, 'A');
+        }
+
+        cross_words.push(word);
+    }
+
+    cross_words.iter().all(|s| s == "MAS" || s == "SAM")
+}
+
+fn letter_at(word_search: &[Vec<char>], (x, y): (isize, isize)) -> Option<char> {
+    if x < 0 || y < 0 {
+        return None;
+    }
+    word_search.get(x as usize)?.get(y as usize).copied()
+}
+
+const ORIENTATIONS: [&[isize]; 8] = [
+    &[-1, -1],
+    &[-1, 0],
+    &[-1, 1],
+    &[0, 1],
+    &[1, 1],
+    &[1, 0],
+    &[1, -1],
+    &[0, -1],
+];
+
+// Pairwise in "cross" order: \, /
+const CROSS_ORIENTATIONS: [&[isize]; 4] = [&[-1, -1], &[1, 1], &[1, -1], &[-1, 1]];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 18);
+        assert_eq!(part2(EXAMPLE).unwrap(), 9);
+    }
+}
@@ -0,0 +1,123 @@
+use anyhow::Result;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::str::Lines;
+
+/// Map of page # to required preceding page #s
+type Rules = HashMap<u64, HashSet<u64>>;
+
+pub fn part1(input: &str) -> Result<u64> {
+    let mut lines = input.lines();
+    let rules = parse_rules(&mut lines);
+
+    let mut total = 0;
+    for updates in lines.map(parse_updates) {
+        if in_order(&updates, &rules) {
+            total += updates[updates.len() / 2];
+        }
+    }
+
+    Ok(total)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    let mut lines = input.lines();
+    let rules = parse_rules(&mut lines);
+    let empty_set = HashSet::new();
+
+    let mut total = 0;
+    for mut updates in lines.map(parse_updates) {
+        if !in_order(&updates, &rules) {
+            updates.sort_by(|p1, p2| {
+                if rules.get(p1).unwrap_or(&empty_set).contains(p2) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            });
+            total += updates[updates.len() / 2];
+        }
+    }
+
+    Ok(total)
+}
+
+fn parse_rules(lines: &mut Lines) -> Rules {
+    let mut rules: Rules = HashMap::new();
+    for l in lines {
+        if l.is_empty() {
+            break;
+        }
+
+        let page_nums: Vec<u64> = l
+            .split("|")
+            .flat_map(|page_num| page_num.parse::<u64>())
+            .collect();
+        rules.entry(page_nums[1]).or_default().insert(page_nums[0]);
+    }
+    rules
+}
+
+fn parse_updates(line: &str) -> Vec<u64> {
+    line.split(",").flat_map(|p| p.parse::<u64>()).collect()
+}
+
+fn in_order(pages: &[u64], rules: &Rules) -> bool {
+    let all_pages: HashSet<u64> = HashSet::from_iter(pages.iter().copied());
+    let mut processed = HashSet::new();
+    let mut in_order = true;
+    for p in pages {
+        if let Some(preceding) = rules.get(p)
+            && preceding
+                .iter()
+                .any(|prec| all_pages.contains(prec) && !processed.contains(prec))
+        {
+            in_order = false;
+            break;
+        }
+        processed.insert(*p);
+    }
+    in_order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 143);
+        assert_eq!(part2(EXAMPLE).unwrap(), 123);
+    }
+}
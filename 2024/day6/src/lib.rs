@@ -1,16 +1,34 @@
-use anyhow::Result;
-use std::io;
-
-fn main() -> Result<()> {
-    let mut lab: Vec<Vec<char>> = io::stdin()
-        .lines()
-        .map(|l| l.unwrap().chars().collect())
-        .collect();
-    let start = lab
+use anyhow::{Context, Result};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut lab = aoc_common::grid(input);
+    let mut current = find_start(&lab)?;
+
+    let mut direction = Direction::Up;
+    loop {
+        lab[current.0][current.1] = 'X';
+
+        let Some(next) = step(&lab, current, &direction) else {
+            break;
+        };
+        match lab[next.0][next.1] {
+            '#' => direction = direction.turn_right(),
+            _ => {
+                current = next;
+            }
+        }
+    }
+
+    let total: usize = lab
         .iter()
-        .enumerate()
-        .find_map(|(i, line)| line.iter().position(|&c| c == '^').map(|j| (i, j)))
-        .unwrap();
+        .map(|line| line.iter().filter(|&&c| c == 'X').count())
+        .sum();
+    Ok(total)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    let lab = aoc_common::grid(input);
+    let start = find_start(&lab)?;
 
     let mut total = 0;
     for i in 0..lab.len() {
@@ -25,29 +43,46 @@ fn main() -> Result<()> {
             }
         }
     }
-    println!("{}", total);
-    Ok(())
+    Ok(total)
+}
+
+fn find_start(lab: &[Vec<char>]) -> Result<(usize, usize)> {
+    lab.iter()
+        .enumerate()
+        .find_map(|(i, line)| line.iter().position(|&c| c == '^').map(|j| (i, j)))
+        .context("No guard (^) in the lab")
 }
 
-fn in_loop(lab: &mut Vec<Vec<char>>, start: &(usize, usize)) -> bool {
+/// The position one step in `direction` from `current`, if it's still inside the lab.
+fn step(
+    lab: &[Vec<char>],
+    current: (usize, usize),
+    direction: &Direction,
+) -> Option<(usize, usize)> {
+    let offset = direction.offset();
+    let next = (current.0 as isize + offset.0, current.1 as isize + offset.1);
+    if next.0 < 0
+        || next.0 > lab.len() as isize - 1
+        || next.1 < 0
+        || next.1 > lab[0].len() as isize - 1
+    {
+        return None;
+    }
+    Some((next.0 as usize, next.1 as usize))
+}
+
+fn in_loop(lab: &mut [Vec<char>], start: &(usize, usize)) -> bool {
     let mut direction = Direction::Up;
     let mut found_loop = false;
     let mut current = *start;
     loop {
-        if let None = Direction::from_label(&lab[current.0][current.1]) {
+        if Direction::from_label(&lab[current.0][current.1]).is_none() {
             lab[current.0][current.1] = direction.label();
         }
 
-        let offset = direction.offset();
-        let next = (current.0 as isize + offset.0, current.1 as isize + offset.1);
-        if next.0 < 0
-            || next.0 > lab.len() as isize - 1
-            || next.1 < 0
-            || next.1 > lab[0].len() as isize - 1
-        {
+        let Some(next) = step(lab, current, &direction) else {
             break;
-        }
-        let next = (next.0 as usize, next.1 as usize);
+        };
 
         if lab[next.0][next.1] == direction.label() {
             found_loop = true;
@@ -110,3 +145,27 @@ impl Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 41);
+        assert_eq!(part2(EXAMPLE).unwrap(), 6);
+    }
+}
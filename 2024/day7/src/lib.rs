@@ -0,0 +1,75 @@
+use anyhow::{Context, Result};
+
+pub fn part1(input: &str) -> Result<u64> {
+    total_calibration(input, &[Operation::Add, Operation::Mul])
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    total_calibration(input, &[Operation::Add, Operation::Mul, Operation::Cat])
+}
+
+fn total_calibration(input: &str, operations: &[Operation]) -> Result<u64> {
+    let mut result = 0;
+    for l in input.lines() {
+        let (equation, operands) = l.split_once(":").context("Missing ':'")?;
+        let total: u64 = equation.parse()?;
+        let operands: Vec<u64> = aoc_common::numbers(operands)?;
+
+        let (&accum, operands) = operands.split_first().context("No operands")?;
+
+        if calibrate(total, accum, operands, operations) {
+            result += total;
+        }
+    }
+    Ok(result)
+}
+
+fn calibrate(total: u64, accum: u64, operands: &[u64], operations: &[Operation]) -> bool {
+    if operands.is_empty() || accum > total {
+        return accum == total;
+    }
+
+    let (&op, operands) = operands.split_first().unwrap();
+    operations
+        .iter()
+        .any(|operation| calibrate(total, operation.apply(accum, op), operands, operations))
+}
+
+enum Operation {
+    Add,
+    Mul,
+    Cat,
+}
+
+impl Operation {
+    fn apply(&self, op1: u64, op2: u64) -> u64 {
+        match self {
+            Operation::Add => op1 + op2,
+            Operation::Mul => op1 * op2,
+            Operation::Cat => [op1.to_string(), op2.to_string()].join("").parse().unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 3749);
+        assert_eq!(part2(EXAMPLE).unwrap(), 11387);
+    }
+}
@@ -1,15 +1,32 @@
 use anyhow::Result;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::io::stdin;
 use std::ops::{Add, Sub};
 
-fn main() -> Result<()> {
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(antinode_locations(input, |a, b, max_x, max_y| {
+        a.antinodes(b)
+            .into_iter()
+            .filter(|p| p.in_bounds(max_x, max_y))
+            .collect()
+    }))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(antinode_locations(input, Point::harmonics))
+}
+
+/// Counts the distinct in-bounds locations `antinodes` finds for every pair of same-frequency
+/// antennas.
+fn antinode_locations(
+    input: &str,
+    antinodes: impl Fn(&Point, &Point, isize, isize) -> Vec<Point>,
+) -> usize {
     let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
     let mut max_x = 0;
     let mut max_y = 0;
-    for (i, l) in stdin().lines().enumerate() {
-        for (j, c) in l?.chars().enumerate() {
+    for (i, l) in input.lines().enumerate() {
+        for (j, c) in l.chars().enumerate() {
             if c != '.' {
                 antennas
                     .entry(c)
@@ -26,14 +43,10 @@ fn main() -> Result<()> {
     let mut locations = HashSet::new();
     for positions in antennas.values() {
         for pair in positions.iter().combinations(2) {
-            for a in pair[0].antinodes(pair[1], max_x, max_y) {
-                locations.insert(a);
-            }
+            locations.extend(antinodes(pair[0], pair[1], max_x, max_y));
         }
     }
-    println!("{}", locations.len());
-
-    Ok(())
+    locations.len()
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -42,7 +55,7 @@ struct Point {
     y: isize,
 }
 
-impl<'a, 'b> Add<&'b Point> for &'a Point {
+impl<'b> Add<&'b Point> for &Point {
     type Output = Point;
 
     fn add(self, rhs: &'b Point) -> Self::Output {
@@ -53,7 +66,7 @@ impl<'a, 'b> Add<&'b Point> for &'a Point {
     }
 }
 
-impl<'a, 'b> Sub<&'b Point> for &'a Point {
+impl<'b> Sub<&'b Point> for &Point {
     type Output = Point;
 
     fn sub(self, rhs: &'b Point) -> Self::Output {
@@ -69,7 +82,13 @@ impl Point {
         Point { x, y }
     }
 
-    fn antinodes(&self, other: &Point, max_x: isize, max_y: isize) -> Vec<Point> {
+    fn antinodes(&self, other: &Point) -> [Point; 2] {
+        let diff = self - other;
+        [self + &diff, other - &diff]
+    }
+
+    /// Every in-bounds point in line with both antennas, including the antennas themselves.
+    fn harmonics(&self, other: &Point, max_x: isize, max_y: isize) -> Vec<Point> {
         let mut antinodes = Vec::new();
         let diff = self - other;
 
@@ -92,3 +111,29 @@ impl Point {
         self.x >= 0 && self.x <= max_x && self.y >= 0 && self.y <= max_y
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 14);
+        assert_eq!(part2(EXAMPLE).unwrap(), 34);
+    }
+}
@@ -0,0 +1,196 @@
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use std::iter;
+use std::iter::RepeatN;
+
+pub fn part1(input: &str) -> Result<u64> {
+    let disk_map = parse(input)?;
+    let total_size: u64 = disk_map.iter().map(|b| b.file_size as u64).sum();
+
+    let mut checksum = 0;
+    let mut start = disk_map.iter().flat_map(Block::expand);
+    let mut end = disk_map.iter().rev().flat_map(Block::data_only);
+    let mut pos = 0;
+    loop {
+        if pos == total_size {
+            break;
+        }
+
+        let s = start.next().unwrap();
+        match s {
+            Item::Data(id) => {
+                checksum += pos * id as u64;
+            }
+            Item::Free => {
+                let e = end.next().unwrap();
+                checksum += pos * e as u64;
+            }
+        }
+        pos += 1;
+    }
+
+    Ok(checksum)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut disk = Vec::new();
+    for block in parse(input)? {
+        disk.push(Segment {
+            size: block.file_size,
+            s_type: Type::Occ(block.id),
+        });
+        disk.push(Segment {
+            size: block.free_size,
+            s_type: Type::Free,
+        });
+    }
+    defrag(&mut disk);
+
+    let checksum: usize = disk
+        .iter()
+        .flat_map(Segment::expand)
+        .enumerate()
+        .map(|(pos, id)| pos * id)
+        .sum();
+    Ok(checksum)
+}
+
+fn parse(input: &str) -> Result<Vec<Block>> {
+    let mut disk_map = Vec::new();
+    for (id, mut chunk) in input.trim().bytes().chunks(2).into_iter().enumerate() {
+        let file_size = chunk.next().context("Empty disk map")? - b'0';
+        let free_size = chunk.next().unwrap_or(b'0') - b'0';
+        disk_map.push(Block {
+            id,
+            file_size,
+            free_size,
+        });
+    }
+    Ok(disk_map)
+}
+
+/// A file and the free space after it, as laid out in the disk map.
+#[derive(Debug)]
+struct Block {
+    id: ID,
+    file_size: u8,
+    free_size: u8,
+}
+
+type ID = usize;
+
+impl Block {
+    fn expand(&self) -> Expanded {
+        Expanded {
+            id: self.id,
+            data_blocks: self.file_size,
+            free_blocks: self.free_size,
+        }
+    }
+
+    fn data_only(&self) -> RepeatN<ID> {
+        iter::repeat_n(self.id, self.file_size as usize)
+    }
+}
+
+#[derive(Debug)]
+struct Expanded {
+    id: ID,
+    data_blocks: u8,
+    free_blocks: u8,
+}
+
+#[derive(Debug)]
+enum Item {
+    Data(ID),
+    Free,
+}
+
+impl Iterator for Expanded {
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data_blocks != 0 {
+            self.data_blocks -= 1;
+            Some(Item::Data(self.id))
+        } else if self.free_blocks != 0 {
+            self.free_blocks -= 1;
+            Some(Item::Free)
+        } else {
+            None
+        }
+    }
+}
+
+fn defrag(disk: &mut Vec<Segment>) {
+    for from in (0..disk.len()).rev() {
+        if let Type::Free = &disk[from].s_type {
+            continue;
+        }
+
+        let size = disk[from].size;
+        let to = (0..from).find(|&to| {
+            let t_segment = &disk[to];
+            matches!(t_segment.s_type, Type::Free) && t_segment.size >= size
+        });
+        if let Some(to) = to {
+            move_segment(disk, from, to);
+        }
+    }
+}
+
+fn move_segment(disk: &mut Vec<Segment>, from: usize, to: usize) {
+    let f_segment = disk.remove(from);
+    let mut t_segment = disk.remove(to);
+
+    t_segment.size -= f_segment.size;
+    let free_size = f_segment.size;
+    disk.insert(to, f_segment);
+    disk.insert(
+        from,
+        Segment {
+            size: free_size,
+            s_type: Type::Free,
+        },
+    );
+
+    if t_segment.size > 0 {
+        disk.insert(to + 1, t_segment);
+    }
+}
+
+/// A run of either one file's blocks or free space, for moving whole files around.
+#[derive(Debug)]
+struct Segment {
+    size: u8,
+    s_type: Type,
+}
+
+impl Segment {
+    fn expand(&self) -> RepeatN<ID> {
+        let id = match self.s_type {
+            Type::Occ(id) => id,
+            Type::Free => 0,
+        };
+        iter::repeat_n(id, self.size as usize)
+    }
+}
+
+#[derive(Debug)]
+enum Type {
+    Occ(ID),
+    Free,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 1928);
+        assert_eq!(part2(EXAMPLE).unwrap(), 2858);
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::{Error, Result, anyhow};
+use std::str::FromStr;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(passwords(input)?.0)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(passwords(input)?.1)
+}
+
+// -> (times the dial is left at 0, times the dial passes 0)
+fn passwords(input: &str) -> Result<(usize, usize)> {
+    let mut dial: isize = 50;
+    let mut p1_password: usize = 0;
+    let mut p2_password: usize = 0;
+
+    for line in input.lines() {
+        let Rotation { amount, direction } = parse_rotation(line)?;
+
+        for _ in 0..amount {
+            match direction {
+                Dir::Right => {
+                    dial += 1;
+                    if dial == 100 {
+                        dial = 0;
+                    }
+                }
+                Dir::Left => {
+                    dial -= 1;
+                    if dial == -1 {
+                        dial = 99;
+                    }
+                }
+            }
+
+            if dial == 0 {
+                p2_password += 1;
+            }
+        }
+
+        if dial == 0 {
+            p1_password += 1;
+        }
+    }
+
+    Ok((p1_password, p2_password))
+}
+
+fn parse_rotation(line: &str) -> Result<Rotation> {
+    let tokens: Vec<_> = line.trim().split(&['L', 'R']).collect();
+    Ok(Rotation {
+        amount: tokens[1].parse()?,
+        direction: line[..=0].parse()?,
+    })
+}
+
+struct Rotation {
+    amount: usize,
+    direction: Dir,
+}
+
+enum Dir {
+    Left,
+    Right,
+}
+
+impl FromStr for Dir {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "L" => Dir::Left,
+            "R" => Dir::Right,
+            _ => Err(anyhow!("Invalid direction: {}", &s))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 3);
+        assert_eq!(part2(EXAMPLE).unwrap(), 6);
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(invalid_id_sums(input)?.0)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(invalid_id_sums(input)?.1)
+}
+
+// -> (sum of IDs repeated twice, sum of IDs repeated any number of times)
+fn invalid_id_sums(input: &str) -> Result<(usize, usize)> {
+    let mut sum_p1 = 0;
+    let mut sum_p2 = 0;
+    for range in input.trim().split(',') {
+        let (start, end) = parse_range(range)?;
+        (start..=end).for_each(|id| {
+            let id_chars: Vec<_> = id.to_string().chars().collect();
+            if repeat_twice(&id_chars) {
+                sum_p1 += id;
+            }
+            if repeat_n_times(&id_chars) {
+                sum_p2 += id;
+            }
+        });
+    }
+    Ok((sum_p1, sum_p2))
+}
+
+fn parse_range(s: &str) -> Result<(usize, usize)> {
+    let tokens: Vec<_> = s.split('-').collect();
+    let error = || anyhow!("Range must include start and end: {}", s);
+    let start = tokens.first().ok_or_else(error)?.parse()?;
+    let end = tokens.get(1).ok_or_else(error)?.parse()?;
+    Ok((start, end))
+}
+
+// The ID is ONLY composed of the same sequence twice. So split the thing in half and check it.
+fn repeat_twice(id: &[char]) -> bool {
+    let mid = id.len() / 2;
+    id[0..mid].eq(&id[mid..])
+}
+
+// Now the sequence can be of any length but repeated enough times to compose the ID.
+// Check all possible sequences from 0 to half the ID's length (it can't be any larger).
+fn repeat_n_times(id: &[char]) -> bool {
+    let mid = id.len() / 2;
+    (1..=mid).any(|chunk_size| {
+        let seq = &id[0..chunk_size];
+        id.chunks(chunk_size).skip(1).all(|chunk| chunk == seq)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 1227775554);
+        assert_eq!(part2(EXAMPLE).unwrap(), 4174379265);
+    }
+}
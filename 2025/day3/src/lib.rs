@@ -0,0 +1,70 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_banks(input)?.max_joltage(2))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(parse_banks(input)?.max_joltage(12))
+}
+
+fn parse_banks(input: &str) -> Result<Vec<Vec<u8>>> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|d| d as u8)
+                        .ok_or_else(|| anyhow::anyhow!("Invalid digit: {}", c))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+trait MaxJoltage {
+    fn max_joltage(&self, to_select: usize) -> usize;
+}
+
+impl MaxJoltage for Vec<Vec<u8>> {
+    fn max_joltage(&self, to_select: usize) -> usize {
+        self.iter()
+            .map(|bank| max_joltage_rec(bank, to_select, String::new()))
+            .sum::<usize>()
+    }
+}
+
+// A greedy recursive algorithm for maximizing the "joltage".
+fn max_joltage_rec(bank: &[u8], to_select: usize, mut digits: String) -> usize {
+    if to_select == 0 {
+        return digits.parse().unwrap();
+    }
+
+    // Pick the max from the left of the bank while leaving enough room to select remaining batteries
+    let (i, selected) = bank[0..=(bank.len() - to_select)]
+        .iter()
+        .enumerate()
+        .max_by(|b1, b2| b1.1.cmp(b2.1).then_with(|| b2.0.cmp(&b1.0)))
+        .unwrap();
+    digits.push(char::from_digit(*selected as u32, 10).unwrap());
+    max_joltage_rec(&bank[i + 1..], to_select - 1, digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 357);
+        assert_eq!(part2(EXAMPLE).unwrap(), 3121910778619);
+    }
+}
@@ -0,0 +1,106 @@
+use anyhow::Result;
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(parse_grid(input).get_accessible().len())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let mut grid = parse_grid(input);
+    let mut to_remove = grid.get_accessible();
+    while !to_remove.is_empty() {
+        grid.remove_rolls(&to_remove);
+        to_remove = grid.get_accessible();
+    }
+    Ok(grid.total_removed)
+}
+
+fn parse_grid(input: &str) -> Grid {
+    Grid::new(input.lines().map(|line| line.chars().collect()).collect())
+}
+
+#[derive(Debug)]
+struct Grid {
+    cells: Vec<Vec<char>>,
+    total_removed: usize,
+}
+
+impl Grid {
+    fn new(cells: Vec<Vec<char>>) -> Self {
+        Self {
+            cells,
+            total_removed: 0,
+        }
+    }
+
+    fn get_accessible(&self) -> Vec<(usize, usize)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .flat_map(|(x, row)| row.iter().enumerate().map(move |(y, char)| (x, y, *char)))
+            .filter_map(|(x, y, cell)| if cell == '@' { Some((x, y)) } else { None })
+            .filter(|(x, y)| {
+                let adjacent = ORIENTATIONS
+                    .iter()
+                    .filter(|&[xd, yd]| {
+                        let (x, y) = (*x as isize + xd, *y as isize + yd);
+                        self.in_bounds((x, y)) && self.cells[x as usize][y as usize] == '@'
+                    })
+                    .count();
+                adjacent < 4
+            })
+            .collect()
+    }
+
+    fn in_bounds(&self, coords: (isize, isize)) -> bool {
+        let (x, y) = coords;
+        x >= 0
+            && x < self.cells.len() as isize
+            && y >= 0
+            && y < self.cells[x as usize].len() as isize
+    }
+
+    fn remove_rolls(&mut self, coords: &[(usize, usize)]) {
+        coords.iter().for_each(|&c| self.remove_roll(c));
+    }
+
+    fn remove_roll(&mut self, coords: (usize, usize)) {
+        let (x, y) = coords;
+        self.cells[x][y] = '.';
+        self.total_removed += 1;
+    }
+}
+
+const ORIENTATIONS: [[isize; 2]; 8] = [
+    [-1, -1],
+    [-1, 0],
+    [-1, 1],
+    [0, 1],
+    [1, 1],
+    [1, 0],
+    [1, -1],
+    [0, -1],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 13);
+        assert_eq!(part2(EXAMPLE).unwrap(), 43);
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use std::cmp::{max, min};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let (ranges, ingredients) = parse_input(input)?;
+    Ok(ingredients
+        .iter()
+        .filter(|i| ranges.iter().any(|r| r.contains(i)))
+        .count())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let (ranges, _ingredients) = parse_input(input)?;
+    let ranges = ranges.merge();
+    Ok(ranges.iter().map(|r| r.1 - r.0 + 1).sum())
+}
+
+#[allow(clippy::type_complexity)]
+pub fn parse_input(input: &str) -> Result<(Vec<(usize, usize)>, Vec<usize>)> {
+    let mut ranges = Vec::new();
+    let mut ids = Vec::new();
+    let mut lines = input.lines();
+
+    for line in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+        ranges.push(parse_range(line)?);
+    }
+
+    for line in lines.by_ref() {
+        ids.push(line.trim().parse()?);
+    }
+
+    Ok((ranges, ids))
+}
+
+fn parse_range(line: &str) -> Result<(usize, usize)> {
+    let tokens: Vec<_> = line.split('-').collect();
+    Ok((tokens[0].parse()?, tokens[1].parse()?))
+}
+
+// Custom Range trait since RangeInclusive isn't orderable/sortable
+trait Range<T> {
+    fn contains(&self, n: &T) -> bool;
+    fn intersect(&self, other: &Self) -> bool;
+}
+
+impl Range<usize> for (usize, usize) {
+    fn contains(&self, n: &usize) -> bool {
+        *n >= self.0 && *n <= self.1
+    }
+
+    fn intersect(&self, other: &Self) -> bool {
+        (self.0 >= other.0 && self.0 <= other.1)
+            || (self.1 >= other.0 && self.1 <= other.1)
+            || (other.0 >= self.0 && other.0 <= self.1)
+            || (other.1 >= self.0 && other.1 <= self.1)
+    }
+}
+
+// Add ability to sort then merge to eliminate range overlaps/redundancies
+trait Merge {
+    fn merge(self) -> Self;
+}
+
+impl Merge for Vec<(usize, usize)> {
+    fn merge(mut self) -> Self {
+        // Don't bother "merging" an empty Vec or a Vec of 1 element
+        if self.len() < 2 {
+            return self;
+        }
+
+        self.sort();
+        let mut new = Vec::new();
+        let mut iter = self.into_iter();
+        let mut prev = iter.next().unwrap();
+        for next in iter {
+            if prev.intersect(&next) {
+                prev = (min(prev.0, next.0), max(prev.1, next.1));
+            } else {
+                new.push(prev);
+                prev = next;
+            }
+        }
+        new.push(prev);
+        new
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 3);
+        assert_eq!(part2(EXAMPLE).unwrap(), 14);
+    }
+}
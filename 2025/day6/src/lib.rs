@@ -0,0 +1,85 @@
+use anyhow::{Result, anyhow};
+
+pub fn part1(input: &str) -> Result<usize> {
+    let mut numbers = Vec::new();
+
+    for line in input.lines() {
+        if line.starts_with("+") || line.starts_with("*") {
+            let mut total = 0;
+            for (col, op) in line.split_ascii_whitespace().enumerate() {
+                let iter = numbers.iter().map(|row: &Vec<usize>| row[col]);
+                match op {
+                    "+" => {
+                        total += iter.sum::<usize>();
+                    }
+                    "*" => {
+                        total += iter.product::<usize>();
+                    }
+                    _ => return Err(anyhow!("Invalid op: {}", op)),
+                }
+            }
+
+            return Ok(total);
+        } else {
+            let row: Result<Vec<usize>, _> = line
+                .split_ascii_whitespace()
+                .map(|n| n.parse::<usize>())
+                .collect();
+            numbers.push(row?);
+        }
+    }
+
+    Err(anyhow!("No operator line found"))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    let lines: Vec<_> = input.lines().collect();
+    let mut total = 0;
+    let mut col_nums: Vec<usize> = Vec::new();
+    let mut current_num = String::new();
+
+    // Go through lines from right to left, top to bottom.
+    for c in (0..lines[0].chars().count())
+        .rev()
+        .flat_map(|col| lines.iter().map(move |l| l.chars().nth(col).unwrap()))
+    {
+        if c == ' ' {
+            if !current_num.is_empty() {
+                col_nums.push(current_num.parse()?);
+                current_num.clear();
+            }
+        } else if c.is_ascii_digit() {
+            current_num.push(c);
+        } else if matches!(c, '*' | '+') {
+            // We may or may not have a number right above the operator (see the sample).
+            if !current_num.is_empty() {
+                col_nums.push(current_num.parse()?);
+                current_num.clear();
+            }
+            if c == '+' {
+                total += col_nums.iter().sum::<usize>();
+            } else {
+                total += col_nums.iter().product::<usize>();
+            }
+            col_nums.clear();
+        } else {
+            return Err(anyhow!("Unhandled character: {c}"));
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The column alignment (and trailing whitespace) is significant for part 2
+    const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 4277556);
+        assert_eq!(part2(EXAMPLE).unwrap(), 3263827);
+    }
+}
@@ -0,0 +1,92 @@
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(split_beam(&parse_manifold(input))?.0)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(split_beam(&parse_manifold(input))?.1)
+}
+
+fn parse_manifold(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+// -> (total splits, total paths)
+fn split_beam(manifold: &[Vec<char>]) -> Result<(usize, usize)> {
+    let start_col = manifold[0]
+        .iter()
+        .position(|&c| c == 'S')
+        .ok_or_else(|| anyhow!("Invalid input: no start ('S') found"))?;
+
+    // Map: col the beam is in -> #paths it carries
+    let mut beams: HashMap<usize, usize> = HashMap::new();
+    beams.insert(start_col, 1);
+    let mut total_splits = 0;
+
+    for row in manifold.iter().skip(1) {
+        // Get the splitting cols impacting the current beams
+        let splitters: HashSet<usize> = row
+            .iter()
+            .enumerate()
+            .filter_map(|(j, &c)| {
+                if beams.contains_key(&j) && c == '^' {
+                    Some(j)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        if splitters.is_empty() {
+            continue;
+        }
+
+        // Split the beam, carrying its paths forward.
+        // NOTE: this includes adding cumulative paths when two beams merge!
+        for splitter in splitters {
+            total_splits += 1;
+
+            let num_paths = beams.remove(&splitter).unwrap();
+            for split_col in [splitter - 1, splitter + 1] {
+                beams
+                    .entry(split_col)
+                    .and_modify(|p| *p += num_paths)
+                    .or_insert(num_paths);
+            }
+        }
+    }
+
+    Ok((total_splits, beams.values().sum::<usize>()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 21);
+        assert_eq!(part2(EXAMPLE).unwrap(), 40);
+    }
+}
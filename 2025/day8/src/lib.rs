@@ -0,0 +1,181 @@
+use anyhow::{Context, Error, Result};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+use std::str::FromStr;
+
+pub const CONNECTIONS: isize = 1000;
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, CONNECTIONS)
+}
+
+pub fn part1_with(input: &str, connections: isize) -> Result<usize> {
+    connect_circuits(input, connections)?
+        .0
+        .context("Circuits were fully connected before making all connections")
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(connect_circuits(input, CONNECTIONS)?.1)
+}
+
+// -> (product of the 3 largest circuits after the given connections, X product of the last pair)
+fn connect_circuits(input: &str, mut conn_to_make: isize) -> Result<(Option<usize>, usize)> {
+    let (total_boxes, mut box_heap) = parse_junction_boxes(input)?;
+    let mut largest_product = None;
+    let mut circuits: Vec<HashSet<JunctionBox>> = Vec::new();
+
+    loop {
+        let JunctionBoxPair { box1: b1, box2: b2 } = box_heap.pop().unwrap();
+        let x_prod = b1.coords.0 * b2.coords.0;
+        let c1 = circuits.iter().position(|c| c.contains(&b1));
+        let c2 = circuits.iter().position(|c| c.contains(&b2));
+
+        match (c1, c2) {
+            (Some(c1), None) => {
+                circuits[c1].insert(b2);
+            }
+            (None, Some(c2)) => {
+                circuits[c2].insert(b1);
+            }
+            (Some(c1), Some(c2)) => {
+                if c1 != c2 {
+                    let c2_boxes: Vec<_> = circuits[c2].drain().collect();
+                    for c in c2_boxes {
+                        circuits[c1].insert(c);
+                    }
+                    circuits.remove(c2);
+                }
+            }
+            (None, None) => {
+                let mut new_set = HashSet::new();
+                new_set.insert(b1);
+                new_set.insert(b2);
+                circuits.push(new_set);
+            }
+        }
+
+        conn_to_make -= 1;
+
+        let mut sizes: Vec<_> = circuits.iter().map(|c| c.len()).collect();
+        sizes.sort();
+
+        if conn_to_make == 0 {
+            largest_product = Some(sizes.iter().rev().take(3).product::<usize>());
+        }
+
+        if sizes.len() == 1 && sizes[0] == total_boxes {
+            return Ok((largest_product, x_prod));
+        }
+    }
+}
+
+fn parse_junction_boxes(input: &str) -> Result<(usize, BinaryHeap<JunctionBoxPair>)> {
+    let mut heap = BinaryHeap::new();
+    let mut boxes: Vec<JunctionBox> = Vec::new();
+
+    for line in input.lines() {
+        let new_box: JunctionBox = line.parse()?;
+        for existing_box in boxes.iter() {
+            heap.push(JunctionBoxPair::new(existing_box.clone(), new_box.clone()));
+        }
+        boxes.push(new_box);
+    }
+
+    Ok((boxes.len(), heap))
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct JunctionBox {
+    coords: (usize, usize, usize),
+}
+
+impl FromStr for JunctionBox {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let tokens: Vec<_> = s.split(",").collect();
+        Ok(JunctionBox::new(
+            tokens[0].parse()?,
+            tokens[1].parse()?,
+            tokens[2].parse()?,
+        ))
+    }
+}
+
+impl JunctionBox {
+    fn new(x: usize, y: usize, z: usize) -> Self {
+        Self { coords: (x, y, z) }
+    }
+
+    fn distance(&self, other: &Self) -> usize {
+        let (x1, y1, z1) = self.coords;
+        let (x2, y2, z2) = other.coords;
+        let dx = x1 as f64 - x2 as f64;
+        let dy = y1 as f64 - y2 as f64;
+        let dz = z1 as f64 - z2 as f64;
+        (dx * dx + dy * dy + dz * dz).sqrt() as usize
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct JunctionBoxPair {
+    box1: JunctionBox,
+    box2: JunctionBox,
+}
+
+impl JunctionBoxPair {
+    fn new(box1: JunctionBox, box2: JunctionBox) -> Self {
+        Self { box1, box2 }
+    }
+
+    fn distance(&self) -> usize {
+        self.box1.distance(&self.box2)
+    }
+}
+
+impl Ord for JunctionBoxPair {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance().cmp(&self.distance())
+    }
+}
+
+impl PartialOrd<Self> for JunctionBoxPair {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1_with(EXAMPLE, 10).unwrap(), 40);
+        assert_eq!(part2(EXAMPLE).unwrap(), 25272);
+    }
+}
@@ -0,0 +1,92 @@
+//! Where puzzle inputs live: `inputs/<year>/<day>.txt` in the repository root (gitignored), next to
+//! an optional `inputs/<year>/<day>.toml` of per-day parameters.
+
+use crate::read_input;
+use anyhow::{Context, Result, bail};
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::io::{IsTerminal, stdin};
+use std::path::{Path, PathBuf};
+
+/// The default inputs directory.
+pub fn inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs")
+}
+
+pub fn input_path(inputs: &Path, year: u16, day: u8) -> PathBuf {
+    inputs.join(year.to_string()).join(format!("{day}.txt"))
+}
+
+pub fn config_path(inputs: &Path, year: u16, day: u8) -> PathBuf {
+    inputs.join(year.to_string()).join(format!("{day}.toml"))
+}
+
+/// Reads a day's input from `<inputs>/<year>/<day>.txt`, falling back to stdin when there's no such
+/// file and the input is being piped in.
+pub fn load_input(inputs: &Path, year: u16, day: u8) -> Result<String> {
+    let path = input_path(inputs, year, day);
+    if path.exists() {
+        return fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()));
+    }
+    if stdin().is_terminal() {
+        bail!(
+            "No input for {year} day {day}: save it as {} or pipe it in on stdin",
+            path.display()
+        );
+    }
+    read_input()
+}
+
+/// Per-day parameters, for the puzzles whose examples use different values (grid sizes, step
+/// counts, ...) than the real input. Anything not set keeps the day's default, e.g.
+/// ```toml
+/// # inputs/2018/6.toml
+/// safe_threshold = 32
+/// ```
+#[derive(Debug, Default)]
+pub struct Config(toml::Table);
+
+impl Config {
+    /// Loads the day's config from the default inputs directory.
+    pub fn load(year: u16, day: u8) -> Result<Self> {
+        Self::load_from(&config_path(&inputs_dir(), year, day))
+    }
+
+    /// Loads a config file, which is empty if it doesn't exist.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Ok(Self(toml::from_str(&contents).with_context(|| {
+                    format!("Failed to parse {}", path.display())
+                })?))
+            }
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn get_or<T: DeserializeOwned>(&self, key: &str, default: T) -> Result<T> {
+        match self.0.get(key) {
+            Some(value) => value
+                .clone()
+                .try_into()
+                .with_context(|| format!("Invalid {key} in config: {value}")),
+            None => Ok(default),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config() {
+        let config = Config(toml::from_str("steps = 10\ngoal = [7, 4]\nlast = 'e'").unwrap());
+        assert_eq!(config.get_or("steps", 100).unwrap(), 10);
+        assert_eq!(config.get_or("goal", (31, 39)).unwrap(), (7, 4));
+        assert_eq!(config.get_or("last", 'p').unwrap(), 'e');
+        assert_eq!(config.get_or("missing", 5).unwrap(), 5);
+        assert!(config.get_or::<u8>("goal", 0).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+//! Input plumbing shared by every day: reading the puzzle input, splitting it up, parsing it and
+//! printing the answers.
+
+use anyhow::{Context, Error, Result};
+use std::fmt::Display;
+use std::io::{Read, stdin};
+use std::str::FromStr;
+
+mod inputs;
+
+pub use inputs::{Config, config_path, input_path, inputs_dir, load_input};
+
+/// Reads the whole puzzle input from stdin.
+pub fn read_input() -> Result<String> {
+    let mut input = String::new();
+    stdin()
+        .read_to_string(&mut input)
+        .context("Failed to read the puzzle input from stdin")?;
+    Ok(input)
+}
+
+/// Prints an answer as `Part <part>: <answer>`.
+pub fn print_answer(part: u8, answer: impl Display) {
+    println!("Part {part}: {answer}");
+}
+
+/// The `main` of a typical day: reads stdin and prints both parts.
+pub fn run<A: Display, B: Display>(
+    part1: fn(&str) -> Result<A>,
+    part2: fn(&str) -> Result<B>,
+) -> Result<()> {
+    let input = read_input()?;
+    print_answer(1, part1(&input)?);
+    print_answer(2, part2(&input)?);
+    Ok(())
+}
+
+/// The `main` of a day with a single part (day 25, or the odd hand-solved day).
+pub fn run_part<T: Display>(part: u8, solve: fn(&str) -> Result<T>) -> Result<()> {
+    let input = read_input()?;
+    print_answer(part, solve(&input)?);
+    Ok(())
+}
+
+/// Splits the input into its blank-line separated sections, without their trailing newlines.
+/// Lines of only whitespace count as blank, and runs of blank lines don't produce empty sections.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let (mut start, mut end) = (None, 0);
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(s) = start.take() {
+                sections.push(&input[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        sections.push(&input[s..end]);
+    }
+    sections
+}
+
+/// Reads the input as a grid of characters, one row per line.
+pub fn grid(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Parses a list of numbers separated by commas and/or whitespace (e.g. `"3,4,3,1,2"` or
+/// `"5 1 9 5"`).
+pub fn numbers<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token
+                .parse()
+                .map_err(Into::into)
+                .with_context(|| format!("Invalid number {token:?}"))
+        })
+        .collect()
+}
+
+/// Parses every non-blank line with `FromStr`, naming the offending line in any error.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Into<Error>,
+{
+    parse_lines_with(input, |line| line.parse().map_err(Into::into))
+}
+
+/// Parses every non-blank line with `parse`, naming the offending line in any error.
+pub fn parse_lines_with<T>(input: &str, parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse(line.trim()).with_context(|| format!("Line {}: {line:?}", i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(sections("a\nb\n\nc\n"), ["a\nb", "c"]);
+        assert_eq!(sections("\n\na\r\n  \r\n\r\nb\r\nc"), ["a", "b\r\nc"]);
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(numbers::<i32>("3,4, -3\n1 2").unwrap(), [3, 4, -3, 1, 2]);
+        let err = numbers::<u8>("1,x,3").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid number \"x\"");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines::<u32>("1\n\n2\n").unwrap(), [1, 2]);
+        let err = parse_lines::<u32>("1\n2\nthree\n").unwrap_err();
+        assert_eq!(err.to_string(), "Line 3: \"three\"");
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(grid("#.\n.#"), [['#', '.'], ['.', '#']]);
+    }
+}
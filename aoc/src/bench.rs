@@ -0,0 +1,184 @@
+use crate::registry::{self, Part, Solution};
+use anyhow::{Context, Result};
+use aoc_common::Config;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A year's timings, as written to `<year>.json`. Times are the fastest of `runs` in milliseconds.
+#[derive(Serialize, Deserialize)]
+struct YearTimings {
+    year: u16,
+    runs: u32,
+    days: Vec<DayTimings>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DayTimings {
+    day: u8,
+    /// Reading the input file.
+    read_ms: f64,
+    /// Parsing the input, for the days with a parse step. Their parts parse the input again
+    /// themselves, so the parse time is taken off each part's.
+    parse_ms: Option<f64>,
+    part1_ms: Option<f64>,
+    part2_ms: Option<f64>,
+    total_ms: f64,
+}
+
+pub fn bench(
+    year: Option<u16>,
+    day: Option<u8>,
+    runs: u32,
+    inputs: &Path,
+    output: &Path,
+) -> Result<()> {
+    let runs = runs.max(1);
+    let mut years: BTreeMap<u16, Vec<DayTimings>> = BTreeMap::new();
+    let selected = registry::SOLUTIONS
+        .iter()
+        .filter(|s| year.is_none_or(|y| y == s.year) && day.is_none_or(|d| d == s.day));
+    for solution in selected {
+        let (year, day) = (solution.year, solution.day);
+        let path = aoc_common::input_path(inputs, year, day);
+        if !path.exists() {
+            println!("MISSING {year} day {day}: no input file");
+            continue;
+        }
+        let timings = Config::load_from(&aoc_common::config_path(inputs, year, day))
+            .and_then(|config| time_day(solution, &path, &config, runs));
+        match timings {
+            Ok(timings) => years.entry(year).or_default().push(timings),
+            Err(e) => println!("FAIL    {year} day {day}: {e:#}"),
+        }
+    }
+
+    fs::create_dir_all(output).with_context(|| format!("Failed to create {}", output.display()))?;
+    for (year, days) in years {
+        let json_path = output.join(format!("{year}.json"));
+        // Keep the last run around to compare against before it's overwritten
+        let previous: Option<YearTimings> = fs::read_to_string(&json_path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok());
+
+        let timings = YearTimings { year, runs, days };
+        let table = render_table(&timings, previous.as_ref());
+        println!("{table}");
+
+        fs::write(output.join(format!("{year}.md")), &table)?;
+        fs::write(&json_path, serde_json::to_string_pretty(&timings)? + "\n")?;
+    }
+
+    Ok(())
+}
+
+fn time_day(solution: &Solution, input: &Path, config: &Config, runs: u32) -> Result<DayTimings> {
+    let read = fastest(runs, || {
+        fs::read_to_string(input).with_context(|| format!("Failed to read {}", input.display()))
+    })?;
+    let input = fs::read_to_string(input)?;
+
+    let parse = solution.parse.map(|parse| {
+        fastest(runs, || {
+            parse(&input);
+            Ok(())
+        })
+    });
+    let parse = parse.transpose()?;
+    let time_part = |part: Option<Part>| -> Result<Option<Duration>> {
+        let time = part.map(|run_part| fastest(runs, || run_part(&input, config)));
+        Ok(time
+            .transpose()?
+            .map(|time| time.saturating_sub(parse.unwrap_or_default())))
+    };
+    let part1 = time_part(solution.part(1)).context("Part 1")?;
+    let part2 = time_part(solution.part(2)).context("Part 2")?;
+
+    let total =
+        read + parse.unwrap_or_default() + part1.unwrap_or_default() + part2.unwrap_or_default();
+    Ok(DayTimings {
+        day: solution.day,
+        read_ms: millis(read),
+        parse_ms: parse.map(millis),
+        part1_ms: part1.map(millis),
+        part2_ms: part2.map(millis),
+        total_ms: millis(total),
+    })
+}
+
+/// Runs `f` `runs` times and returns the fastest, which is the least disturbed by anything else
+/// running on the machine.
+fn fastest<T>(runs: u32, mut f: impl FnMut() -> Result<T>) -> Result<Duration> {
+    let mut fastest = Duration::MAX;
+    for _ in 0..runs {
+        let start = Instant::now();
+        f()?;
+        fastest = fastest.min(start.elapsed());
+    }
+    Ok(fastest)
+}
+
+pub fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn render_table(timings: &YearTimings, previous: Option<&YearTimings>) -> String {
+    let previous_total = |day: u8| {
+        previous
+            .and_then(|p| p.days.iter().find(|d| d.day == day))
+            .map(|d| d.total_ms)
+    };
+
+    let mut table = String::new();
+    writeln!(table, "## {} (best of {})\n", timings.year, timings.runs).unwrap();
+    writeln!(
+        table,
+        "| Day | Read | Parse | Part 1 | Part 2 | Total | vs. last |"
+    )
+    .unwrap();
+    writeln!(
+        table,
+        "|----:|-----:|------:|-------:|-------:|------:|---------:|"
+    )
+    .unwrap();
+    for day in timings.days.iter() {
+        let change = match previous_total(day.day) {
+            Some(before) if before > 0.0 => {
+                format!("{:+.0}%", (day.total_ms - before) / before * 100.0)
+            }
+            _ => "-".to_string(),
+        };
+        writeln!(
+            table,
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            day.day,
+            format_ms(Some(day.read_ms)),
+            format_ms(day.parse_ms),
+            format_ms(day.part1_ms),
+            format_ms(day.part2_ms),
+            format_ms(Some(day.total_ms)),
+            change
+        )
+        .unwrap();
+    }
+    let total: f64 = timings.days.iter().map(|d| d.total_ms).sum();
+    writeln!(table, "| **All** | | | | | {} | |", format_ms(Some(total))).unwrap();
+    writeln!(
+        table,
+        "\nDays without a parse step (-) count parsing in their parts."
+    )
+    .unwrap();
+    table
+}
+
+fn format_ms(ms: Option<f64>) -> String {
+    match ms {
+        None => "-".to_string(),
+        Some(ms) if ms >= 1000.0 => format!("{:.2} s", ms / 1000.0),
+        Some(ms) if ms >= 1.0 => format!("{ms:.1} ms"),
+        Some(ms) => format!("{:.0} µs", ms * 1000.0),
+    }
+}
@@ -0,0 +1,219 @@
+use anyhow::{Context, Result, bail};
+use aoc_common::Config;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+mod bench;
+mod registry;
+mod scaffold;
+mod verify;
+
+/// The repository root, where the workspace, `answers.toml` and the `bench` results live.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solution
+    Run {
+        year: u16,
+        day: u8,
+        /// Only run the given part (1 or 2)
+        #[arg(long)]
+        part: Option<u8>,
+        /// Read the puzzle input from this file instead of <inputs>/<year>/<day>.txt (or stdin)
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Directory of puzzle inputs and per-day configs, laid out as <year>/<day>.txt and
+        /// <year>/<day>.toml [default: inputs in the repository root]
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// List the available solutions
+    List {
+        /// Only list solutions for the given year
+        year: Option<u16>,
+    },
+    /// Check solutions against the recorded answers
+    Verify {
+        /// Only verify the given year
+        year: Option<u16>,
+        /// Only verify the given day
+        day: Option<u8>,
+        /// Expected answers [default: answers.toml in the repository root]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Directory of puzzle inputs, laid out as <year>/<day>.txt [default: inputs in the
+        /// repository root]
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+    },
+    /// Create a new day with part1/part2 stubs
+    New { year: u16, day: u8 },
+    /// Time each solution's parts and write per-year tables (<year>.md) and JSON (<year>.json)
+    Bench {
+        /// Only benchmark the given year
+        year: Option<u16>,
+        /// Only benchmark the given day
+        day: Option<u8>,
+        /// Run each part this many times and keep the fastest
+        #[arg(long, default_value_t = 1)]
+        runs: u32,
+        /// Directory of puzzle inputs, laid out as <year>/<day>.txt [default: inputs in the
+        /// repository root]
+        #[arg(long)]
+        inputs: Option<PathBuf>,
+        /// Where to write the results [default: bench in the repository root]
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// `Part <part>: <answer>` lines
+    Text,
+    /// A JSON array with a `{year, day, part, answer, elapsed}` object per part
+    Json,
+}
+
+/// A part's answer, as printed by `run --format json`.
+#[derive(Serialize)]
+struct PartResult {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: String,
+    /// Milliseconds taken by the part, which includes parsing the input
+    elapsed: f64,
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            inputs,
+            format,
+        } => run(
+            year,
+            day,
+            part,
+            input,
+            &inputs.unwrap_or_else(aoc_common::inputs_dir),
+            format,
+        ),
+        Command::List { year } => {
+            list(year);
+            Ok(())
+        }
+        Command::Verify {
+            year,
+            day,
+            answers,
+            inputs,
+        } => verify::verify(
+            year,
+            day,
+            &answers.unwrap_or_else(|| Path::new(ROOT).join("answers.toml")),
+            &inputs.unwrap_or_else(aoc_common::inputs_dir),
+        ),
+        Command::New { year, day } => scaffold::new_day(Path::new(ROOT), year, day),
+        Command::Bench {
+            year,
+            day,
+            runs,
+            inputs,
+            output,
+        } => bench::bench(
+            year,
+            day,
+            runs,
+            &inputs.unwrap_or_else(aoc_common::inputs_dir),
+            &output.unwrap_or_else(|| Path::new(ROOT).join("bench")),
+        ),
+    }
+}
+
+fn run(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    inputs: &Path,
+    format: Format,
+) -> Result<()> {
+    let solution =
+        registry::find(year, day).with_context(|| format!("No solution for {year} day {day}"))?;
+
+    let parts = match part {
+        Some(part) => match solution.part(part) {
+            Some(run_part) => vec![(part, run_part)],
+            None => bail!("{year} day {day} has no part {part}"),
+        },
+        None => [1, 2]
+            .into_iter()
+            .filter_map(|part| solution.part(part).map(|run_part| (part, run_part)))
+            .collect(),
+    };
+
+    let input = match input {
+        Some(path) => fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+        None => aoc_common::load_input(inputs, year, day)?,
+    };
+    let config = Config::load_from(&aoc_common::config_path(inputs, year, day))?;
+    let mut results = Vec::new();
+    for (part, run_part) in parts {
+        let start = Instant::now();
+        let answer = run_part(&input, &config)?;
+        let elapsed = bench::millis(start.elapsed());
+        // Text answers are printed as they come so a slow part 2 doesn't hold up part 1's
+        if format == Format::Text {
+            aoc_common::print_answer(part, &answer);
+        }
+        results.push(PartResult {
+            year,
+            day,
+            part,
+            answer,
+            elapsed,
+        });
+    }
+    if format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    }
+
+    Ok(())
+}
+
+fn list(year: Option<u16>) {
+    let mut years: Vec<_> = registry::SOLUTIONS.iter().map(|s| s.year).collect();
+    years.dedup();
+
+    for y in years
+        .into_iter()
+        .filter(|&y| year.is_none_or(|year| year == y))
+    {
+        let days: Vec<_> = registry::SOLUTIONS
+            .iter()
+            .filter(|s| s.year == y)
+            .map(|s| s.day.to_string())
+            .collect();
+        println!("{y}: {}", days.join(" "));
+    }
+}
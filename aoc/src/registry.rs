@@ -0,0 +1,383 @@
+use anyhow::Result;
+use aoc_common::Config;
+
+/// A puzzle part: takes the raw puzzle input (and the day's config, for the days with parameters)
+/// and produces the answer to print.
+pub type Part = fn(&str, &Config) -> Result<String>;
+
+/// A day's input parsing on its own, for timing it apart from the parts. Parse errors are left
+/// for the parts to report.
+pub type Parse = fn(&str);
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part1: Part,
+    /// Day 25 (and the odd hand-solved day) only has a first part.
+    pub part2: Option<Part>,
+    /// Days with a `parse_input` that both parts go through.
+    pub parse: Option<Parse>,
+}
+
+impl Solution {
+    const fn with_parse(self, parse: Parse) -> Self {
+        Self {
+            parse: Some(parse),
+            ..self
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<Part> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+/// Registers a day's library crate:
+/// * `solution!(year, day, krate)` - `krate::part1` and `krate::part2`
+/// * `solution!(year, day, krate; part1)` - only `krate::part1`
+/// * `solution!(year, day, |input, config| { part1: expr, part2: expr })` - for days with
+///   parameters, which are read from `config`
+///
+/// followed by `.with_parse(parse!(krate))` for days whose parts start with `krate::parse_input`.
+macro_rules! solution {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part1: |input, _config| Ok($krate::part1(input)?.to_string()),
+            part2: Some(|input, _config| Ok($krate::part2(input)?.to_string())),
+            parse: None,
+        }
+    };
+    ($year:literal, $day:literal, $krate:ident; part1) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part1: |input, _config| Ok($krate::part1(input)?.to_string()),
+            part2: None,
+            parse: None,
+        }
+    };
+    ($year:literal, $day:literal, |$input:ident, $config:ident| {
+        part1: $part1:expr,
+        part2: $part2:expr $(,)?
+    }) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part1: |$input, $config| {
+                let _ = $config;
+                Ok($part1?.to_string())
+            },
+            part2: Some(|$input, $config| {
+                let _ = $config;
+                Ok($part2?.to_string())
+            }),
+            parse: None,
+        }
+    };
+}
+
+macro_rules! parse {
+    ($krate:ident) => {
+        |input| {
+            // Kept so that the parsing isn't optimised away
+            let _ = std::hint::black_box($krate::parse_input(input));
+        }
+    };
+}
+
+pub static SOLUTIONS: &[Solution] = &[
+    solution!(2015, 1, aoc2015_day1),
+    solution!(2015, 2, aoc2015_day2).with_parse(parse!(aoc2015_day2)),
+    solution!(2015, 3, aoc2015_day3),
+    solution!(2015, 4, aoc2015_day4),
+    solution!(2015, 5, aoc2015_day5),
+    solution!(2015, 6, aoc2015_day6),
+    solution!(2015, 7, aoc2015_day7).with_parse(parse!(aoc2015_day7)),
+    solution!(2015, 8, aoc2015_day8),
+    solution!(2015, 9, aoc2015_day9),
+    solution!(2015, 10, aoc2015_day10),
+    solution!(2015, 11, aoc2015_day11),
+    solution!(2015, 12, aoc2015_day12),
+    solution!(2015, 13, aoc2015_day13).with_parse(parse!(aoc2015_day13)),
+    solution!(2015, 14, |input, config| {
+        part1: aoc2015_day14::part1_with(input, config.get_or("total_seconds", aoc2015_day14::TOTAL_SECONDS)?),
+        part2: aoc2015_day14::part2_with(input, config.get_or("total_seconds", aoc2015_day14::TOTAL_SECONDS)?),
+    }).with_parse(parse!(aoc2015_day14)),
+    solution!(2015, 15, aoc2015_day15).with_parse(parse!(aoc2015_day15)),
+    solution!(2015, 16, aoc2015_day16),
+    solution!(2015, 17, |input, config| {
+        part1: aoc2015_day17::part1_with(input, config.get_or("to_store", aoc2015_day17::TO_STORE)?),
+        part2: aoc2015_day17::part2_with(input, config.get_or("to_store", aoc2015_day17::TO_STORE)?),
+    }),
+    solution!(2015, 18, |input, config| {
+        part1: aoc2015_day18::part1_with(input, config.get_or("steps", aoc2015_day18::STEPS)?),
+        part2: aoc2015_day18::part2_with(input, config.get_or("steps", aoc2015_day18::STEPS)?),
+    }).with_parse(parse!(aoc2015_day18)),
+    solution!(2015, 19, aoc2015_day19),
+    solution!(2015, 20, aoc2015_day20),
+    solution!(2015, 21, aoc2015_day21).with_parse(parse!(aoc2015_day21)),
+    solution!(2015, 22, aoc2015_day22),
+    solution!(2015, 23, aoc2015_day23).with_parse(parse!(aoc2015_day23)),
+    solution!(2015, 24, aoc2015_day24).with_parse(parse!(aoc2015_day24)),
+    solution!(2015, 25, aoc2015_day25; part1).with_parse(parse!(aoc2015_day25)),
+    solution!(2016, 1, aoc2016_day1),
+    solution!(2016, 2, aoc2016_day2),
+    solution!(2016, 3, aoc2016_day3),
+    solution!(2016, 4, aoc2016_day4).with_parse(parse!(aoc2016_day4)),
+    solution!(2016, 5, aoc2016_day5),
+    solution!(2016, 6, aoc2016_day6),
+    solution!(2016, 7, aoc2016_day7),
+    solution!(2016, 8, |input, config| {
+        part1: aoc2016_day8::part1_with(input, config.get_or("width", aoc2016_day8::WIDTH)?, config.get_or("height", aoc2016_day8::HEIGHT)?),
+        part2: aoc2016_day8::part2_with(input, config.get_or("width", aoc2016_day8::WIDTH)?, config.get_or("height", aoc2016_day8::HEIGHT)?),
+    }),
+    solution!(2016, 9, aoc2016_day9),
+    solution!(2016, 10, |input, config| {
+        part1: aoc2016_day10::part1_with(input, config.get_or("search_chips", aoc2016_day10::SEARCH_CHIPS)?),
+        part2: aoc2016_day10::part2(input),
+    }),
+    solution!(2016, 11, aoc2016_day11).with_parse(parse!(aoc2016_day11)),
+    solution!(2016, 12, aoc2016_day12).with_parse(parse!(aoc2016_day12)),
+    solution!(2016, 13, |input, config| {
+        part1: aoc2016_day13::part1_with(input, config.get_or("goal", aoc2016_day13::GOAL)?),
+        part2: aoc2016_day13::part2_with(input, config.get_or("goal", aoc2016_day13::GOAL)?),
+    }).with_parse(parse!(aoc2016_day13)),
+    solution!(2016, 14, aoc2016_day14).with_parse(parse!(aoc2016_day14)),
+    solution!(2016, 15, aoc2016_day15).with_parse(parse!(aoc2016_day15)),
+    solution!(2016, 16, aoc2016_day16),
+    solution!(2016, 17, aoc2016_day17).with_parse(parse!(aoc2016_day17)),
+    solution!(2016, 18, aoc2016_day18),
+    solution!(2016, 19, aoc2016_day19).with_parse(parse!(aoc2016_day19)),
+    solution!(2016, 20, aoc2016_day20),
+    solution!(2016, 21, aoc2016_day21).with_parse(parse!(aoc2016_day21)),
+    solution!(2016, 22, aoc2016_day22),
+    solution!(2016, 23, aoc2016_day23),
+    solution!(2016, 24, aoc2016_day24),
+    solution!(2016, 25, aoc2016_day25; part1),
+    solution!(2017, 1, aoc2017_day1),
+    solution!(2017, 2, aoc2017_day2),
+    solution!(2017, 3, aoc2017_day3; part1),
+    solution!(2017, 4, aoc2017_day4),
+    solution!(2017, 5, aoc2017_day5),
+    solution!(2017, 6, aoc2017_day6),
+    solution!(2017, 7, aoc2017_day7).with_parse(parse!(aoc2017_day7)),
+    solution!(2017, 8, aoc2017_day8),
+    solution!(2017, 9, aoc2017_day9),
+    solution!(2017, 10, |input, config| {
+        part1: aoc2017_day10::part1_with(input, config.get_or("size", aoc2017_day10::SIZE)?),
+        part2: aoc2017_day10::part2_with(input, config.get_or("size", aoc2017_day10::SIZE)?),
+    }),
+    solution!(2017, 11, aoc2017_day11),
+    solution!(2017, 12, aoc2017_day12).with_parse(parse!(aoc2017_day12)),
+    solution!(2017, 13, aoc2017_day13).with_parse(parse!(aoc2017_day13)),
+    solution!(2017, 14, aoc2017_day14),
+    solution!(2017, 15, aoc2017_day15).with_parse(parse!(aoc2017_day15)),
+    solution!(2017, 16, |input, config| {
+        part1: aoc2017_day16::part1_with(input, config.get_or("last_dancer", aoc2017_day16::LAST_DANCER)?),
+        part2: aoc2017_day16::part2_with(input, config.get_or("last_dancer", aoc2017_day16::LAST_DANCER)?),
+    }),
+    solution!(2017, 17, aoc2017_day17).with_parse(parse!(aoc2017_day17)),
+    solution!(2017, 18, aoc2017_day18),
+    solution!(2017, 19, aoc2017_day19).with_parse(parse!(aoc2017_day19)),
+    solution!(2017, 20, aoc2017_day20).with_parse(parse!(aoc2017_day20)),
+    solution!(2017, 21, aoc2017_day21).with_parse(parse!(aoc2017_day21)),
+    solution!(2017, 22, aoc2017_day22).with_parse(parse!(aoc2017_day22)),
+    solution!(2017, 23, aoc2017_day23),
+    solution!(2017, 24, aoc2017_day24).with_parse(parse!(aoc2017_day24)),
+    solution!(2017, 25, aoc2017_day25; part1).with_parse(parse!(aoc2017_day25)),
+    solution!(2018, 1, aoc2018_day1).with_parse(parse!(aoc2018_day1)),
+    solution!(2018, 2, aoc2018_day2).with_parse(parse!(aoc2018_day2)),
+    solution!(2018, 3, aoc2018_day3).with_parse(parse!(aoc2018_day3)),
+    solution!(2018, 4, aoc2018_day4).with_parse(parse!(aoc2018_day4)),
+    solution!(2018, 5, aoc2018_day5).with_parse(parse!(aoc2018_day5)),
+    solution!(2018, 6, |input, config| {
+        part1: aoc2018_day6::part1(input),
+        part2: aoc2018_day6::part2_with(input, config.get_or("safe_threshold", aoc2018_day6::SAFE_THRESHOLD)?),
+    }).with_parse(parse!(aoc2018_day6)),
+    solution!(2018, 7, |input, config| {
+        part1: aoc2018_day7::part1(input),
+        part2: aoc2018_day7::part2_with(input, config.get_or("workers", aoc2018_day7::WORKERS)?, config.get_or("step_offset", aoc2018_day7::STEP_OFFSET)?),
+    }).with_parse(parse!(aoc2018_day7)),
+    solution!(2018, 8, aoc2018_day8).with_parse(parse!(aoc2018_day8)),
+    solution!(2018, 9, aoc2018_day9).with_parse(parse!(aoc2018_day9)),
+    solution!(2018, 10, aoc2018_day10).with_parse(parse!(aoc2018_day10)),
+    solution!(2018, 11, aoc2018_day11),
+    solution!(2018, 12, |input, config| {
+        part1: aoc2018_day12::part1_with(input, config.get_or("generations", aoc2018_day12::GENERATIONS)?),
+        part2: aoc2018_day12::part2(input),
+    }),
+    solution!(2018, 13, aoc2018_day13).with_parse(parse!(aoc2018_day13)),
+    solution!(2018, 14, aoc2018_day14),
+    solution!(2018, 15, aoc2018_day15).with_parse(parse!(aoc2018_day15)),
+    solution!(2018, 16, aoc2018_day16).with_parse(parse!(aoc2018_day16)),
+    solution!(2018, 17, aoc2018_day17).with_parse(parse!(aoc2018_day17)),
+    solution!(2018, 18, aoc2018_day18).with_parse(parse!(aoc2018_day18)),
+    solution!(2018, 19, aoc2018_day19).with_parse(parse!(aoc2018_day19)),
+    solution!(2018, 20, aoc2018_day20).with_parse(parse!(aoc2018_day20)),
+    solution!(2018, 21, aoc2018_day21),
+    solution!(2018, 22, aoc2018_day22).with_parse(parse!(aoc2018_day22)),
+    solution!(2018, 23, aoc2018_day23).with_parse(parse!(aoc2018_day23)),
+    solution!(2018, 24, aoc2018_day24),
+    solution!(2018, 25, aoc2018_day25; part1).with_parse(parse!(aoc2018_day25)),
+    solution!(2019, 1, aoc2019_day1).with_parse(parse!(aoc2019_day1)),
+    solution!(2019, 2, aoc2019_day2),
+    solution!(2019, 3, aoc2019_day3).with_parse(parse!(aoc2019_day3)),
+    solution!(2019, 4, aoc2019_day4).with_parse(parse!(aoc2019_day4)),
+    solution!(2019, 5, |input, config| {
+        part1: aoc2019_day5::part1(input),
+        part2: aoc2019_day5::part2_with(input, config.get_or("system_id", aoc2019_day5::SYSTEM_ID)?),
+    }),
+    solution!(2019, 6, aoc2019_day6).with_parse(parse!(aoc2019_day6)),
+    solution!(2019, 7, aoc2019_day7),
+    solution!(2019, 8, |input, config| {
+        part1: aoc2019_day8::part1_with(input, config.get_or("width", aoc2019_day8::WIDTH)?, config.get_or("height", aoc2019_day8::HEIGHT)?),
+        part2: aoc2019_day8::part2_with(input, config.get_or("width", aoc2019_day8::WIDTH)?, config.get_or("height", aoc2019_day8::HEIGHT)?),
+    }).with_parse(parse!(aoc2019_day8)),
+    solution!(2019, 9, aoc2019_day9),
+    solution!(2019, 10, |input, config| {
+        part1: aoc2019_day10::part1(input),
+        part2: aoc2019_day10::part2_with(input, config.get_or("nth_destroyed", aoc2019_day10::NTH_DESTROYED)?),
+    }).with_parse(parse!(aoc2019_day10)),
+    solution!(2019, 11, aoc2019_day11),
+    solution!(2019, 12, |input, config| {
+        part1: aoc2019_day12::part1_with(input, config.get_or("steps", aoc2019_day12::STEPS)?),
+        part2: aoc2019_day12::part2(input),
+    }).with_parse(parse!(aoc2019_day12)),
+    solution!(2019, 13, aoc2019_day13),
+    solution!(2019, 14, aoc2019_day14).with_parse(parse!(aoc2019_day14)),
+    solution!(2019, 15, aoc2019_day15),
+    solution!(2019, 16, |input, config| {
+        part1: aoc2019_day16::part1_with(input, config.get_or("phases", aoc2019_day16::PHASES)?),
+        part2: aoc2019_day16::part2_with(input, config.get_or("phases", aoc2019_day16::PHASES)?),
+    }).with_parse(parse!(aoc2019_day16)),
+    solution!(2019, 17, aoc2019_day17),
+    solution!(2019, 18, aoc2019_day18).with_parse(parse!(aoc2019_day18)),
+    solution!(2019, 19, |input, config| {
+        part1: aoc2019_day19::part1_with(input, config.get_or("grid", aoc2019_day19::GRID)?),
+        part2: aoc2019_day19::part2_with(input, config.get_or("ship", aoc2019_day19::SHIP)?),
+    }),
+    solution!(2019, 20, aoc2019_day20).with_parse(parse!(aoc2019_day20)),
+    solution!(2019, 21, aoc2019_day21),
+    solution!(2019, 22, |input, config| {
+        part1: aoc2019_day22::part1_with(input, config.get_or("deck_size", aoc2019_day22::DECK_SIZE)?),
+        part2: aoc2019_day22::part2(input),
+    }).with_parse(parse!(aoc2019_day22)),
+    solution!(2019, 23, aoc2019_day23),
+    solution!(2019, 24, aoc2019_day24).with_parse(parse!(aoc2019_day24)),
+    solution!(2019, 25, aoc2019_day25; part1),
+    solution!(2020, 1, aoc2020_day1).with_parse(parse!(aoc2020_day1)),
+    solution!(2020, 2, aoc2020_day2).with_parse(parse!(aoc2020_day2)),
+    solution!(2020, 3, aoc2020_day3).with_parse(parse!(aoc2020_day3)),
+    solution!(2020, 4, aoc2020_day4).with_parse(parse!(aoc2020_day4)),
+    solution!(2020, 5, aoc2020_day5),
+    solution!(2020, 6, aoc2020_day6).with_parse(parse!(aoc2020_day6)),
+    solution!(2020, 7, aoc2020_day7).with_parse(parse!(aoc2020_day7)),
+    solution!(2020, 8, aoc2020_day8).with_parse(parse!(aoc2020_day8)),
+    solution!(2020, 9, |input, config| {
+        part1: aoc2020_day9::part1_with(input, config.get_or("preamble", aoc2020_day9::PREAMBLE)?),
+        part2: aoc2020_day9::part2_with(input, config.get_or("preamble", aoc2020_day9::PREAMBLE)?),
+    }).with_parse(parse!(aoc2020_day9)),
+    solution!(2020, 10, aoc2020_day10),
+    solution!(2020, 11, aoc2020_day11).with_parse(parse!(aoc2020_day11)),
+    solution!(2020, 12, aoc2020_day12).with_parse(parse!(aoc2020_day12)),
+    solution!(2020, 13, aoc2020_day13).with_parse(parse!(aoc2020_day13)),
+    solution!(2020, 14, aoc2020_day14).with_parse(parse!(aoc2020_day14)),
+    solution!(2020, 15, aoc2020_day15).with_parse(parse!(aoc2020_day15)),
+    solution!(2020, 16, aoc2020_day16).with_parse(parse!(aoc2020_day16)),
+    solution!(2020, 17, aoc2020_day17).with_parse(parse!(aoc2020_day17)),
+    solution!(2020, 18, aoc2020_day18),
+    solution!(2020, 19, aoc2020_day19).with_parse(parse!(aoc2020_day19)),
+    solution!(2020, 20, aoc2020_day20).with_parse(parse!(aoc2020_day20)),
+    solution!(2020, 21, aoc2020_day21).with_parse(parse!(aoc2020_day21)),
+    solution!(2020, 22, aoc2020_day22).with_parse(parse!(aoc2020_day22)),
+    solution!(2020, 23, |input, config| {
+        part1: aoc2020_day23::part1_with(input, config.get_or("moves", aoc2020_day23::MOVES)?),
+        part2: aoc2020_day23::part2(input),
+    }).with_parse(parse!(aoc2020_day23)),
+    solution!(2020, 24, aoc2020_day24).with_parse(parse!(aoc2020_day24)),
+    solution!(2020, 25, aoc2020_day25; part1).with_parse(parse!(aoc2020_day25)),
+    solution!(2021, 1, aoc2021_day1).with_parse(parse!(aoc2021_day1)),
+    solution!(2021, 2, aoc2021_day2).with_parse(parse!(aoc2021_day2)),
+    solution!(2021, 3, aoc2021_day3).with_parse(parse!(aoc2021_day3)),
+    solution!(2021, 4, aoc2021_day4).with_parse(parse!(aoc2021_day4)),
+    solution!(2021, 5, aoc2021_day5).with_parse(parse!(aoc2021_day5)),
+    solution!(2021, 6, aoc2021_day6).with_parse(parse!(aoc2021_day6)),
+    solution!(2021, 7, aoc2021_day7).with_parse(parse!(aoc2021_day7)),
+    solution!(2021, 8, aoc2021_day8).with_parse(parse!(aoc2021_day8)),
+    solution!(2021, 9, aoc2021_day9).with_parse(parse!(aoc2021_day9)),
+    solution!(2021, 10, aoc2021_day10),
+    solution!(2021, 11, aoc2021_day11).with_parse(parse!(aoc2021_day11)),
+    solution!(2021, 12, aoc2021_day12).with_parse(parse!(aoc2021_day12)),
+    solution!(2021, 13, aoc2021_day13).with_parse(parse!(aoc2021_day13)),
+    solution!(2021, 14, aoc2021_day14).with_parse(parse!(aoc2021_day14)),
+    solution!(2024, 1, aoc2024_day1),
+    solution!(2024, 2, aoc2024_day2),
+    solution!(2024, 3, aoc2024_day3),
+    solution!(2024, 4, aoc2024_day4),
+    solution!(2024, 5, aoc2024_day5),
+    solution!(2024, 6, aoc2024_day6),
+    solution!(2024, 7, aoc2024_day7),
+    solution!(2024, 8, aoc2024_day8),
+    solution!(2024, 9, aoc2024_day9),
+    solution!(2024, 10, aoc2024_day10),
+    solution!(2024, 11, aoc2024_day11),
+    solution!(2024, 12, aoc2024_day12),
+    solution!(2024, 13, aoc2024_day13),
+    solution!(2024, 14, |input, config| {
+        part1: aoc2024_day14::part1_with(input, grid_size(config, aoc2024_day14::GRID_SIZE)?),
+        part2: aoc2024_day14::part2_with(input, grid_size(config, aoc2024_day14::GRID_SIZE)?),
+    }),
+    solution!(2024, 15, aoc2024_day15),
+    solution!(2024, 16, aoc2024_day16),
+    solution!(2024, 17, aoc2024_day17),
+    solution!(2024, 18, |input, config| {
+        part1: aoc2024_day18::part1_with(input, config.get_or("max_dim", aoc2024_day18::MAX_DIM)?, config.get_or("bytes_to_take", aoc2024_day18::BYTES_TO_TAKE)?),
+        part2: aoc2024_day18::part2_with(input, config.get_or("max_dim", aoc2024_day18::MAX_DIM)?),
+    }),
+    solution!(2024, 19, aoc2024_day19),
+    solution!(2024, 20, |input, config| {
+        part1: aoc2024_day20::part1_with(input, config.get_or("to_save", aoc2024_day20::TO_SAVE)?),
+        part2: aoc2024_day20::part2_with(input, config.get_or("to_save", aoc2024_day20::TO_SAVE)?),
+    }),
+    solution!(2024, 21, |input, config| {
+        part1: aoc2024_day21::part1(input),
+        part2: aoc2024_day21::part2_with(input, config.get_or("robot_dirpad_count", aoc2024_day21::ROBOT_DIRPAD_COUNT)?),
+    }),
+    solution!(2024, 22, aoc2024_day22),
+    solution!(2024, 23, aoc2024_day23),
+    solution!(2024, 24, aoc2024_day24),
+    solution!(2024, 25, aoc2024_day25; part1),
+    solution!(2025, 1, aoc2025_day1),
+    solution!(2025, 2, aoc2025_day2),
+    solution!(2025, 3, aoc2025_day3),
+    solution!(2025, 4, aoc2025_day4),
+    solution!(2025, 5, aoc2025_day5).with_parse(parse!(aoc2025_day5)),
+    solution!(2025, 6, aoc2025_day6),
+    solution!(2025, 7, aoc2025_day7),
+    solution!(2025, 8, |input, config| {
+        part1: aoc2025_day8::part1_with(input, config.get_or("connections", aoc2025_day8::CONNECTIONS)?),
+        part2: aoc2025_day8::part2(input),
+    }),
+    solution!(2025, 9, aoc2025_day9),
+    solution!(2025, 10, aoc2025_day10).with_parse(parse!(aoc2025_day10)),
+    solution!(2025, 11, aoc2025_day11),
+    solution!(2025, 12, aoc2025_day12; part1).with_parse(parse!(aoc2025_day12)),
+];
+
+/// 2024 day 14's robots move around a (height, width) grid.
+fn grid_size(config: &Config, (height, width): (usize, usize)) -> Result<(usize, usize)> {
+    Ok((
+        config.get_or("height", height)?,
+        config.get_or("width", width)?,
+    ))
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.year == year && s.day == day)
+}
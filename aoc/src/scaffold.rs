@@ -0,0 +1,257 @@
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::Path;
+
+/// Creates `<year>/day<day>` as a library + thin `main` with `part1`/`part2` stubs and an example
+/// test to fill in, and registers it with the runner in `aoc/Cargo.toml` and `aoc/src/registry.rs`.
+/// A new year gets its `<year>/day*` glob added to the root workspace's members.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("Day must be between 1 and 25");
+    }
+
+    let year_dir = root.join(year.to_string());
+    let day_dir = year_dir.join(format!("day{day}"));
+    if day_dir.exists() {
+        bail!("{} already exists", day_dir.display());
+    }
+
+    let package = format!("aoc{year}-day{day}");
+    let krate = package.replace('-', "_");
+
+    // Work out every edit up front so that a runner file in an unexpected shape stops the scaffold
+    // before anything is written
+    let runner_manifest = root.join("aoc/Cargo.toml");
+    let runner_manifest_contents = add_dependency(&read(&runner_manifest)?, year, day)
+        .with_context(|| format!("Failed to add {package} to {}", runner_manifest.display()))?;
+    let registry = root.join("aoc/src/registry.rs");
+    let registry_contents = add_solution(&read(&registry)?, year, day)
+        .with_context(|| format!("Failed to add {package} to {}", registry.display()))?;
+
+    let manifest = root.join("Cargo.toml");
+    let workspace = read(&manifest)?;
+    let member = format!("{year}/day*");
+    if !has_member(&workspace, &member)? {
+        fs::write(&manifest, add_member(&workspace, &member)?)
+            .with_context(|| format!("Failed to update {}", manifest.display()))?;
+        println!("Added {year} to the workspace");
+    }
+
+    fs::create_dir_all(day_dir.join("src"))?;
+    fs::write(
+        day_dir.join("Cargo.toml"),
+        format!(
+            r#"[package]
+name = "{package}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+anyhow = {{ workspace = true }}
+aoc-common = {{ workspace = true }}
+"#
+        ),
+    )?;
+    fs::write(day_dir.join("src/lib.rs"), LIB)?;
+    fs::write(
+        day_dir.join("src/main.rs"),
+        format!(
+            r#"use anyhow::Result;
+
+fn main() -> Result<()> {{
+    aoc_common::run({krate}::part1, {krate}::part2)
+}}
+"#
+        ),
+    )?;
+
+    fs::write(&runner_manifest, runner_manifest_contents)
+        .with_context(|| format!("Failed to update {}", runner_manifest.display()))?;
+    fs::write(&registry, registry_contents)
+        .with_context(|| format!("Failed to update {}", registry.display()))?;
+
+    println!("Created {}", day_dir.display());
+    println!("Registered {package}, run it with: aoc run {year} {day}");
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+const LIB: &str = r#"use anyhow::Result;
+
+pub fn part1(_input: &str) -> Result<usize> {
+    todo!()
+}
+
+pub fn part2(_input: &str) -> Result<usize> {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(part1(EXAMPLE).unwrap(), 0);
+        assert_eq!(part2(EXAMPLE).unwrap(), 0);
+    }
+}
+"#;
+
+fn members(workspace: &str) -> Result<Vec<String>> {
+    let table: toml::Table = toml::from_str(workspace)?;
+    Ok(table
+        .get("workspace")
+        .and_then(|w| w.get("members"))
+        .context("Workspace has no members list")?
+        .clone()
+        .try_into()?)
+}
+
+fn has_member(workspace: &str, member: &str) -> Result<bool> {
+    Ok(members(workspace)?.iter().any(|m| m == member))
+}
+
+/// Adds `member` to the workspace's single-line `members = [...]`, keeping it sorted.
+fn add_member(workspace: &str, member: &str) -> Result<String> {
+    let mut members = members(workspace)?;
+    members.push(member.to_string());
+    members.sort();
+    members.dedup();
+
+    let members = members
+        .iter()
+        .map(|m| format!("\"{m}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut updated = String::new();
+    for line in workspace.lines() {
+        if line.trim_start().starts_with("members") {
+            updated.push_str(&format!("members = [{members}]"));
+        } else {
+            updated.push_str(line);
+        }
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+/// The (year, day) of a runner dependency line like `aoc2015-day1 = { path = "../2015/day1" }`.
+fn dependency_day(line: &str) -> Option<(u16, u8)> {
+    let (year, day) = line
+        .strip_prefix("aoc")?
+        .split_once(" =")?
+        .0
+        .split_once("-day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Adds the day's path dependency to the runner's manifest, among the other days in date order.
+fn add_dependency(manifest: &str, year: u16, day: u8) -> Result<String> {
+    let line = format!("aoc{year}-day{day} = {{ path = \"../{year}/day{day}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    let days: Vec<(usize, (u16, u8))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, dependency_day(line)?)))
+        .collect();
+    let Some(&(last, _)) = days.last() else {
+        bail!("No aoc<year>-day<day> dependencies to add it next to");
+    };
+    if days.iter().any(|&(_, date)| date == (year, day)) {
+        bail!("It's already a dependency");
+    }
+    let at = days
+        .iter()
+        .find(|&&(_, date)| date >= (year, day))
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// The (year, day) of a registry entry starting `    solution!(2015, 1, ...`.
+fn solution_day(line: &str) -> Option<(u16, u8)> {
+    let mut args = line.strip_prefix("    solution!(")?.split(", ");
+    Some((args.next()?.parse().ok()?, args.next()?.parse().ok()?))
+}
+
+/// Adds a `solution!` entry for the day to the registry's `SOLUTIONS`, in date order.
+fn add_solution(registry: &str, year: u16, day: u8) -> Result<String> {
+    let entry = format!("    solution!({year}, {day}, aoc{year}_day{day}),");
+    let mut lines: Vec<&str> = registry.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub static SOLUTIONS"))
+        .context("No SOLUTIONS list")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "];")
+            .context("SOLUTIONS list isn't closed")?;
+    let mut at = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+        match solution_day(line) {
+            Some(date) if date == (year, day) => bail!("It's already registered"),
+            Some(date) if date > (year, day) => {
+                at = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member() {
+        let workspace =
+            "[workspace]\nmembers = [\"2015/day*\", \"aoc\"]\n\n[workspace.dependencies]\n";
+        assert!(has_member(workspace, "2015/day*").unwrap());
+        assert!(!has_member(workspace, "2016/day*").unwrap());
+        assert_eq!(
+            add_member(workspace, "2016/day*").unwrap(),
+            "[workspace]\nmembers = [\"2015/day*\", \"2016/day*\", \"aoc\"]\n\n[workspace.dependencies]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\nanyhow = { workspace = true }\n\naoc2015-day1 = { path = \"../2015/day1\" }\naoc2015-day10 = { path = \"../2015/day10\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 2015, 2).unwrap(),
+            "[dependencies]\nanyhow = { workspace = true }\n\naoc2015-day1 = { path = \"../2015/day1\" }\naoc2015-day2 = { path = \"../2015/day2\" }\naoc2015-day10 = { path = \"../2015/day10\" }\n"
+        );
+        assert!(add_dependency(manifest, 2016, 1).unwrap().ends_with(
+            "aoc2015-day10 = { path = \"../2015/day10\" }\naoc2016-day1 = { path = \"../2016/day1\" }\n"
+        ));
+        assert!(add_dependency(manifest, 2015, 10).is_err());
+        assert!(add_dependency("[dependencies]\n", 2015, 1).is_err());
+    }
+
+    #[test]
+    fn test_add_solution() {
+        let registry = "pub static SOLUTIONS: &[Solution] = &[\n    solution!(2015, 1, aoc2015_day1),\n    solution!(2015, 3, |input, config| {\n        part1: aoc2015_day3::part1(input),\n        part2: aoc2015_day3::part2(input),\n    }),\n];\n\nfn find() {}\n";
+        assert_eq!(
+            add_solution(registry, 2015, 2).unwrap(),
+            "pub static SOLUTIONS: &[Solution] = &[\n    solution!(2015, 1, aoc2015_day1),\n    solution!(2015, 2, aoc2015_day2),\n    solution!(2015, 3, |input, config| {\n        part1: aoc2015_day3::part1(input),\n        part2: aoc2015_day3::part2(input),\n    }),\n];\n\nfn find() {}\n"
+        );
+        assert!(
+            add_solution(registry, 2015, 4)
+                .unwrap()
+                .contains("    }),\n    solution!(2015, 4, aoc2015_day4),\n];")
+        );
+        assert!(add_solution(registry, 2015, 1).is_err());
+        assert!(add_solution("fn find() {}\n", 2015, 1).is_err());
+    }
+}
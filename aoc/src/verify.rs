@@ -0,0 +1,184 @@
+use crate::registry::{self, Solution};
+use anyhow::{Context, Result, anyhow, bail};
+use aoc_common::Config;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+/// Expected answers keyed by year then day, e.g.
+/// ```toml
+/// [2018.15]
+/// part1 = 12345
+/// part2 = "GGLZLHCE"
+/// ```
+type Answers = HashMap<String, HashMap<String, DayAnswers>>;
+
+#[derive(Deserialize)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(anyhow::Error),
+    Missing(&'static str),
+}
+
+pub fn verify(year: Option<u16>, day: Option<u8>, answers: &Path, inputs: &Path) -> Result<()> {
+    let answers: Answers = match fs::read_to_string(answers) {
+        Ok(contents) => toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse {}", answers.display()))?,
+        Err(_) => HashMap::new(),
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let selected = registry::SOLUTIONS
+        .iter()
+        .filter(|s| year.is_none_or(|y| y == s.year) && day.is_none_or(|d| d == s.day));
+    for solution in selected {
+        for (part, outcome) in check(solution, &answers, inputs) {
+            let (year, day) = (solution.year, solution.day);
+            match outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Fail { expected, actual } => {
+                    failed += 1;
+                    println!(
+                        "FAIL    {year} day {day} part {part}: expected {expected}, got {actual}"
+                    );
+                }
+                Outcome::Error(e) => {
+                    failed += 1;
+                    println!("FAIL    {year} day {day} part {part}: {e:#}");
+                }
+                Outcome::Missing(reason) => {
+                    missing += 1;
+                    println!("MISSING {year} day {day} part {part}: {reason}");
+                }
+            }
+        }
+    }
+
+    println!("{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        bail!("{failed} answer(s) did not match");
+    }
+    Ok(())
+}
+
+fn check(solution: &Solution, answers: &Answers, inputs: &Path) -> Vec<(u8, Outcome)> {
+    let expected = answers
+        .get(&solution.year.to_string())
+        .and_then(|days| days.get(&solution.day.to_string()));
+    let input = fs::read_to_string(aoc_common::input_path(inputs, solution.year, solution.day));
+    let config = Config::load_from(&aoc_common::config_path(
+        inputs,
+        solution.year,
+        solution.day,
+    ));
+
+    [1, 2]
+        .into_iter()
+        .filter_map(|part| solution.part(part).map(|run_part| (part, run_part)))
+        .map(|(part, run_part)| {
+            let expected = expected.and_then(|answers| match part {
+                1 => answers.part1.as_ref(),
+                _ => answers.part2.as_ref(),
+            });
+            let outcome = match (expected, &input, &config) {
+                (None, _, _) => Outcome::Missing("no recorded answer"),
+                (_, Err(_), _) => Outcome::Missing("no input file"),
+                (_, _, Err(e)) => Outcome::Error(anyhow!("{e:#}")),
+                (Some(expected), Ok(input), Ok(config)) => match run_part(input, config) {
+                    // ASCII-art answers start on a fresh line, which multi-line TOML strings drop
+                    Ok(actual)
+                        if actual.trim_matches('\n') == expected.to_string().trim_matches('\n') =>
+                    {
+                        Outcome::Pass
+                    }
+                    Ok(actual) => Outcome::Fail {
+                        expected: expected.to_string(),
+                        actual,
+                    },
+                    Err(e) => Outcome::Error(e),
+                },
+            };
+            (part, outcome)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+[2018.1]
+part1 = 3
+part2 = \"ABC\"
+";
+
+    fn solution(day: u8) -> Solution {
+        Solution {
+            year: 2018,
+            day,
+            part1: |input, _config| Ok(input.lines().count().to_string()),
+            part2: Some(|input, _config| Ok(input.replace('\n', ""))),
+            parse: None,
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let inputs = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        fs::create_dir_all(inputs.join("2018")).unwrap();
+        fs::write(inputs.join("2018/1.txt"), "a\nb\nc\n").unwrap();
+        fs::write(inputs.join("2018/2.txt"), "a\n").unwrap();
+        let answers: Answers = toml::from_str(ANSWERS).unwrap();
+
+        let outcomes = check(&solution(1), &answers, &inputs);
+        assert!(matches!(outcomes[0], (1, Outcome::Pass)));
+        assert!(matches!(
+            &outcomes[1],
+            (2, Outcome::Fail { expected, actual }) if expected == "ABC" && actual == "abc"
+        ));
+
+        let outcomes = check(&solution(2), &answers, &inputs);
+        assert!(matches!(
+            outcomes[0],
+            (1, Outcome::Missing("no recorded answer"))
+        ));
+        assert!(matches!(
+            outcomes[1],
+            (2, Outcome::Missing("no recorded answer"))
+        ));
+
+        fs::remove_file(inputs.join("2018/1.txt")).unwrap();
+        let outcomes = check(&solution(1), &answers, &inputs);
+        assert!(matches!(
+            outcomes[0],
+            (1, Outcome::Missing("no input file"))
+        ));
+
+        fs::remove_dir_all(&inputs).unwrap();
+    }
+}